@@ -14,6 +14,8 @@ pub struct CacheEntry {
     pub check_id: Uuid,
     pub name: String,
     pub cached_at: DateTime<Utc>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl CacheEntry {
@@ -96,6 +98,29 @@ impl CheckCache {
         })
     }
 
+    /// All cached entries for a project (including stale ones), keyed by slug
+    pub fn entries(&self, project_id: &str) -> impl Iterator<Item = (&String, &CacheEntry)> {
+        self.projects.get(project_id).into_iter().flatten()
+    }
+
+    /// Find a check by public_id in any project/org (stale entries included)
+    ///
+    /// Returns the slug and entry. Used for hints like tags, where a stale value
+    /// is better than none.
+    pub fn find_by_public_id(&self, public_id: &Uuid) -> Option<(&str, &CacheEntry)> {
+        self.projects.values().find_map(|project| {
+            project
+                .iter()
+                .find(|(_, entry)| &entry.public_id == public_id)
+                .map(|(slug, entry)| (slug.as_str(), entry))
+        })
+    }
+
+    /// Find a check by slug in any project/org (stale entries included)
+    pub fn find_by_slug(&self, slug: &str) -> Option<&CacheEntry> {
+        self.projects.values().find_map(|project| project.get(slug))
+    }
+
     /// Update cache with a single check
     pub fn set(
         &mut self,
@@ -113,6 +138,7 @@ impl CheckCache {
                 check_id,
                 name,
                 cached_at: Utc::now(),
+                tags: Vec::new(),
             },
         );
     }
@@ -137,6 +163,7 @@ impl CheckCache {
                     check_id: check.id(),
                     name: check.name().to_string(),
                     cached_at: Utc::now(),
+                    tags: check.tags().to_vec(),
                 },
             );
         }
@@ -161,6 +188,9 @@ pub trait CheckLike {
     fn public_id(&self) -> Uuid;
     fn slug(&self) -> &str;
     fn name(&self) -> &str;
+    fn tags(&self) -> &[String] {
+        &[]
+    }
 }
 
 #[cfg(test)]
@@ -200,6 +230,7 @@ mod tests {
             check_id: Uuid::new_v4(),
             name: "test".to_string(),
             cached_at: Utc::now(),
+            tags: Vec::new(),
         };
         assert!(!fresh.is_stale());
 
@@ -208,6 +239,7 @@ mod tests {
             check_id: Uuid::new_v4(),
             name: "test".to_string(),
             cached_at: Utc::now() - Duration::hours(25),
+            tags: Vec::new(),
         };
         assert!(stale.is_stale());
     }
@@ -286,6 +318,7 @@ mod tests {
             check_id: Uuid::new_v4(),
             name: "Stale Check".to_string(),
             cached_at: Utc::now() - Duration::hours(25), // 25 hours ago = stale
+            tags: Vec::new(),
         };
 
         cache.projects.insert(project_id.to_string(), {
@@ -330,6 +363,7 @@ mod tests {
             check_id,
             name: "Stale".to_string(),
             cached_at: Utc::now() - Duration::hours(25),
+            tags: Vec::new(),
         };
 
         cache.projects.insert(project_id.to_string(), {
@@ -378,6 +412,7 @@ mod tests {
             check_id: Uuid::new_v4(),
             name: "Stale".to_string(),
             cached_at: Utc::now() - Duration::hours(25),
+            tags: Vec::new(),
         };
 
         cache.projects.insert(project_id.to_string(), {
@@ -502,4 +537,34 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result, Err(CliError::Json(_))));
     }
+
+    #[test]
+    fn test_cache_tags_and_find() {
+        let public_id = Uuid::new_v4();
+        // Entries written before tags were cached still load
+        let json = format!(
+            r#"{{"projects":{{"org-1":{{"backup-db":{{"public_id":"{}","check_id":"{}","name":"Backup","cached_at":"2020-01-01T00:00:00Z"}}}}}}}}"#,
+            public_id,
+            Uuid::new_v4()
+        );
+        let mut cache: CheckCache = serde_json::from_str(&json).unwrap();
+        assert!(cache.find_by_slug("backup-db").unwrap().tags.is_empty());
+
+        // Stale entries are still returned by find_*
+        let (slug, _) = cache.find_by_public_id(&public_id).unwrap();
+        assert_eq!(slug, "backup-db");
+
+        cache.update_from_checks(
+            "org-1",
+            vec![MockCheck {
+                id: Uuid::new_v4(),
+                public_id,
+                slug: "backup-db".to_string(),
+                name: "Backup".to_string(),
+            }],
+        );
+        // MockCheck uses the default (empty) tags
+        assert!(cache.find_by_slug("backup-db").unwrap().tags.is_empty());
+        assert!(cache.find_by_slug("missing").is_none());
+    }
 }
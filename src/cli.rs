@@ -1,5 +1,6 @@
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
+use std::path::PathBuf;
 use uuid::Uuid;
 
 #[derive(Parser)]
@@ -90,6 +91,14 @@ pub enum Commands {
     #[command(subcommand)]
     Check(CheckCommands),
 
+    /// Silence alerts for checks during planned downtime
+    #[command(subcommand)]
+    Maintenance(MaintenanceCommands),
+
+    /// Alert channels (email, Slack, webhooks) for the organization
+    #[command(subcommand)]
+    Alert(AlertCommands),
+
     /// Send a ping to a check
     Ping(PingArgs),
 
@@ -104,6 +113,44 @@ pub enum Commands {
     #[command(subcommand)]
     Auth(AuthCommands),
 
+    /// Inspect and test external monitor configuration (external_monitors.toml)
+    #[command(subcommand)]
+    External(ExternalCommands),
+
+    /// Import checks from other monitoring services
+    #[command(subcommand)]
+    Import(ImportCommands),
+
+    /// Create checks from crontab entries and wrap them with `pakyas monitor`
+    #[command(subcommand)]
+    Crontab(CrontabCommands),
+
+    /// Create checks from systemd timers and instrument their services
+    #[command(subcommand)]
+    Systemd(SystemdCommands),
+
+    /// Create checks for Kubernetes CronJobs and wrap their containers
+    #[command(subcommand)]
+    K8s(K8sCommands),
+
+    /// Compare this host's crontab and systemd schedules with their checks
+    Drift(DriftArgs),
+
+    /// Uptime and reliability report for checks over a time range
+    Report(ReportArgs),
+
+    /// Stream timeline events from several checks, interleaved by time
+    Tail(TailArgs),
+
+    /// Show what `apply` would change to match a checks manifest
+    Plan(PlanArgs),
+
+    /// Create, update, and delete checks to match a checks manifest
+    Apply(ApplyArgs),
+
+    /// Export existing checks as a manifest for `plan`/`apply`
+    Export(ExportArgs),
+
     /// Generate shell completions
     Completion {
         /// Shell to generate completions for
@@ -122,6 +169,66 @@ pub struct UpdateArgs {
     pub check: bool,
 }
 
+#[derive(Args, Clone)]
+pub struct PlanArgs {
+    /// Manifest file (YAML or TOML; "-" reads YAML from stdin)
+    #[arg(long, short = 'f', value_name = "PATH")]
+    pub file: PathBuf,
+
+    /// Plan deletion of checks in manifest projects that the manifest doesn't list
+    #[arg(long)]
+    pub prune: bool,
+}
+
+#[derive(Args, Clone)]
+pub struct ApplyArgs {
+    /// Manifest file (YAML or TOML; "-" reads YAML from stdin)
+    #[arg(long, short = 'f', value_name = "PATH")]
+    pub file: PathBuf,
+
+    /// Delete checks in manifest projects that the manifest doesn't list
+    #[arg(long)]
+    pub prune: bool,
+
+    /// Skip confirmation prompt
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
+#[derive(Args, Clone)]
+pub struct ExportArgs {
+    /// Only export checks in this project (ID, slug, or name)
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Only export checks with this tag
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    /// Write to this file (YAML, or TOML for .toml) instead of stdout
+    #[arg(long, short = 'o', value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Clone)]
+pub struct DriftArgs {
+    /// Crontab file to scan (repeatable) [default: the user's crontab, /etc/crontab, /etc/cron.d]
+    #[arg(long = "crontab", value_name = "PATH")]
+    pub crontabs: Vec<PathBuf>,
+
+    /// Directory of systemd timers to scan (repeatable) [default: /etc/systemd/system]
+    #[arg(long = "unit-dir", value_name = "DIR")]
+    pub unit_dirs: Vec<PathBuf>,
+
+    /// Only report unreferenced checks with this tag
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    /// Only report unreferenced checks whose slug matches this glob (e.g. "web-1-*")
+    #[arg(long = "match", value_name = "PATTERN")]
+    pub pattern: Option<String>,
+}
+
 #[derive(Parser, Clone)]
 pub struct LoginArgs {
     /// Login with API key directly (skip browser auth)
@@ -186,6 +293,43 @@ pub enum FailOnSeverity {
     Info,
 }
 
+/// Selector flags that switch pause/resume/delete/update to bulk mode
+const SELECTOR_ARGS: [&str; 4] = ["tag", "project", "status", "pattern"];
+
+/// Select several checks for a bulk operation (all given filters must match)
+#[derive(Args, Clone, Debug, Default)]
+pub struct CheckSelector {
+    /// Only checks with this tag
+    #[arg(long)]
+    pub tag: Option<String>,
+
+    /// Only checks in this project (by ID, name, or slug)
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Only checks with this status (e.g., up, down, late, paused)
+    #[arg(long)]
+    pub status: Option<String>,
+
+    /// Only checks whose slug matches this glob (e.g., 'legacy-*')
+    #[arg(long = "match", value_name = "GLOB")]
+    pub pattern: Option<String>,
+
+    /// Maximum number of checks changed at the same time
+    #[arg(long, default_value = "8", value_parser = clap::value_parser!(u16).range(1..=64))]
+    pub concurrency: u16,
+}
+
+impl CheckSelector {
+    /// Whether any filter flag was given
+    pub fn is_active(&self) -> bool {
+        self.tag.is_some()
+            || self.project.is_some()
+            || self.status.is_some()
+            || self.pattern.is_some()
+    }
+}
+
 #[derive(Subcommand, Clone)]
 pub enum CheckCommands {
     /// List all checks in the organization (filter with --project)
@@ -249,6 +393,9 @@ pub enum CheckCommands {
         #[arg(long, value_name = "DURATION")]
         max_runtime: Option<String>,
 
+        #[command(flatten)]
+        alerts: AlertToggles,
+
         /// Output as JSON (compact, for scripting)
         #[arg(long)]
         json: bool,
@@ -272,22 +419,42 @@ pub enum CheckCommands {
         slug: String,
     },
 
-    /// Pause a check (stops monitoring)
+    /// Pause checks (stops monitoring)
     Pause {
-        /// Check slug or ID
-        slug: String,
+        /// Check slug or ID ("-" reads slugs from stdin)
+        #[arg(required_unless_present_any = SELECTOR_ARGS)]
+        slug: Option<String>,
+
+        #[command(flatten)]
+        selector: CheckSelector,
+
+        /// Skip confirmation prompt (when selecting several checks)
+        #[arg(long, short)]
+        yes: bool,
     },
 
-    /// Resume a paused check
+    /// Resume paused checks
     Resume {
-        /// Check slug or ID
-        slug: String,
+        /// Check slug or ID ("-" reads slugs from stdin)
+        #[arg(required_unless_present_any = SELECTOR_ARGS)]
+        slug: Option<String>,
+
+        #[command(flatten)]
+        selector: CheckSelector,
+
+        /// Skip confirmation prompt (when selecting several checks)
+        #[arg(long, short)]
+        yes: bool,
     },
 
-    /// Delete a check
+    /// Delete checks
     Delete {
-        /// Check slug or ID
-        slug: String,
+        /// Check slug or ID ("-" reads slugs from stdin)
+        #[arg(required_unless_present_any = SELECTOR_ARGS)]
+        slug: Option<String>,
+
+        #[command(flatten)]
+        selector: CheckSelector,
 
         /// Skip confirmation prompt
         #[arg(long, short)]
@@ -299,9 +466,61 @@ pub enum CheckCommands {
         /// Check slug or ID
         slug: String,
 
-        /// Number of pings to show (default: 50)
+        /// Number of pings to show (0 pages through all matching pings)
         #[arg(long, default_value = "50")]
         limit: i32,
+
+        /// Only pings after this time (e.g., "30m", "2d", "2024-01-01T00:00:00Z")
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only pings before this time (e.g., "1h", "2024-01-02T00:00:00Z")
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Ping types to show: success, fail, start (comma-separated)
+        #[arg(long = "type", value_name = "TYPES")]
+        types: Option<String>,
+
+        /// Only pings from this run (the ID passed with `ping --run` or generated by `monitor`).
+        /// Use `check runs` for one row per run
+        #[arg(long, value_name = "RUN_ID")]
+        run: Option<String>,
+
+        /// Print the captured request body (e.g., stderr sent by `monitor`) after each ping
+        #[arg(long)]
+        show_body: bool,
+    },
+
+    /// Show one row per run, pairing start and completion pings
+    Runs {
+        /// Check slug or ID
+        slug: String,
+
+        /// Only runs since this time (e.g., "6h", "7d", "2024-01-01T00:00:00Z")
+        #[arg(long, default_value = "24h")]
+        since: String,
+
+        /// Only failed runs
+        #[arg(long)]
+        failed: bool,
+
+        /// Only runs that took (or have been running for) at least this long (e.g., 10m)
+        #[arg(long, value_name = "DURATION")]
+        slower_than: Option<String>,
+
+        /// Maximum number of runs to show (0 = all)
+        #[arg(long, default_value = "50")]
+        limit: usize,
+    },
+
+    /// Print the captured body of one ping (see the ID column of `check logs`)
+    LogBody {
+        /// Check slug or ID
+        slug: String,
+
+        /// Ping ID
+        ping_id: i64,
     },
 
     /// Force refresh the local check cache
@@ -347,12 +566,19 @@ pub enum CheckCommands {
         /// Number of events to fetch per request
         #[arg(long, default_value = "50")]
         limit: i64,
+
+        #[command(flatten)]
+        hooks: EventHookArgs,
     },
 
-    /// Update a check's configuration
+    /// Update the configuration of checks
     Update {
-        /// Check slug or ID
-        slug: String,
+        /// Check slug or ID ("-" reads slugs from stdin)
+        #[arg(required_unless_present_any = SELECTOR_ARGS)]
+        slug: Option<String>,
+
+        #[command(flatten)]
+        selector: CheckSelector,
 
         /// New check name
         #[arg(long)]
@@ -394,10 +620,54 @@ pub enum CheckCommands {
         #[arg(long)]
         max_runtime: Option<String>,
 
+        #[command(flatten)]
+        alerts: AlertToggles,
+
         /// Skip confirmation prompt
         #[arg(long, short = 'y')]
         yes: bool,
     },
+
+    /// Show or change where and when a check alerts
+    Alerts {
+        /// Check slug or ID
+        slug: String,
+
+        /// Send this check's alerts to a channel (name or ID, repeatable)
+        #[arg(long, value_name = "CHANNEL")]
+        add_channel: Vec<String>,
+
+        /// Stop sending this check's alerts to a channel (name or ID, repeatable)
+        #[arg(long, value_name = "CHANNEL")]
+        remove_channel: Vec<String>,
+
+        #[command(flatten)]
+        toggles: AlertToggles,
+    },
+}
+
+/// Per-check alert toggles (unset toggles keep their current value)
+#[derive(Args, Clone, Debug, Default)]
+pub struct AlertToggles {
+    /// Alert when the check goes down
+    #[arg(long, value_name = "BOOL")]
+    pub alert_on_down: Option<bool>,
+
+    /// Alert when a ping is late
+    #[arg(long, value_name = "BOOL")]
+    pub alert_on_late: Option<bool>,
+
+    /// Alert when a run exceeds --max-runtime
+    #[arg(long, value_name = "BOOL")]
+    pub alert_on_overrun: Option<bool>,
+
+    /// Alert on duration and failure-rate anomalies
+    #[arg(long, value_name = "BOOL")]
+    pub alert_on_anomaly: Option<bool>,
+
+    /// Notify when the check recovers
+    #[arg(long, value_name = "BOOL")]
+    pub notify_on_recovery: Option<bool>,
 }
 
 #[derive(Args, Clone)]
@@ -439,6 +709,14 @@ pub struct PingArgs {
     /// Timeout for external monitor requests in milliseconds
     #[arg(long, default_value = "5000", env = "PAKYAS_EXTERNAL_TIMEOUT_MS")]
     pub external_timeout_ms: u64,
+
+    /// Save external events that still fail after retries for `pakyas external replay`
+    #[arg(long, env = "PAKYAS_EXTERNAL_SPOOL")]
+    pub external_spool: bool,
+
+    /// Exit with code 4 when an external monitor could not be reached
+    #[arg(long, env = "PAKYAS_EXTERNAL_STRICT")]
+    pub external_strict: bool,
 }
 
 #[derive(Args, Clone)]
@@ -463,6 +741,14 @@ pub struct MonitorArgs {
     #[arg(long, default_value = "5000", env = "PAKYAS_EXTERNAL_TIMEOUT_MS")]
     pub external_timeout_ms: u64,
 
+    /// Save external events that still fail after retries for `pakyas external replay`
+    #[arg(long, env = "PAKYAS_EXTERNAL_SPOOL")]
+    pub external_spool: bool,
+
+    /// Exit with code 4 when an external monitor could not be reached
+    #[arg(long, env = "PAKYAS_EXTERNAL_STRICT")]
+    pub external_strict: bool,
+
     /// Migration mode: allow external success to override pakyas failure
     #[arg(long, env = "PAKYAS_MIGRATION_MODE")]
     pub migration_mode: bool,
@@ -492,6 +778,421 @@ pub struct MonitorArgs {
     pub webhook_url: Vec<String>,
 }
 
+#[derive(Subcommand, Clone)]
+pub enum ExternalCommands {
+    /// Show effective external targets per check and which config file is used
+    List {
+        /// Only show targets for this check key (slug or public ID)
+        check: Option<String>,
+    },
+
+    /// Validate external_monitors.toml (schema, unknown checks, missing credentials)
+    Validate {
+        /// Skip checking configured keys against your checks (no API calls)
+        #[arg(long)]
+        offline: bool,
+    },
+
+    /// Send synthetic events to a check's external targets and report the results
+    Test {
+        /// Check key (slug or public ID) as used in external_monitors.toml
+        check: String,
+
+        /// Event(s) to send, in order (can be specified multiple times)
+        #[arg(long = "event", value_enum, default_value = "success", action = clap::ArgAction::Append)]
+        events: Vec<ExternalTestEvent>,
+
+        /// Timeout for external monitor requests in milliseconds
+        #[arg(long, default_value = "5000", env = "PAKYAS_EXTERNAL_TIMEOUT_MS")]
+        external_timeout_ms: u64,
+    },
+
+    /// Resend external events saved with --external-spool
+    Replay {
+        /// Only replay events for this check key
+        #[arg(long)]
+        check: Option<String>,
+
+        /// List pending events without sending them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Timeout for external monitor requests in milliseconds
+        #[arg(long, default_value = "5000", env = "PAKYAS_EXTERNAL_TIMEOUT_MS")]
+        external_timeout_ms: u64,
+    },
+
+    /// Summarize logged external dispatch outcomes (success rate, latency, disagreements)
+    Report {
+        /// Only include outcomes since this time (e.g., "24h", "7d", "2024-01-01T00:00:00Z")
+        #[arg(long, default_value = "7d")]
+        since: String,
+
+        /// Only include outcomes for this check key
+        #[arg(long)]
+        check: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum ImportCommands {
+    /// Import checks from Healthchecks.io (or a self-hosted instance)
+    Healthchecks {
+        /// Healthchecks project API key (read-only keys can't map ping UUIDs)
+        #[arg(long, env = "HEALTHCHECKS_API_KEY")]
+        api_key: String,
+
+        /// Healthchecks site URL
+        #[arg(long, default_value = "https://healthchecks.io")]
+        endpoint: String,
+
+        /// Show the import plan without creating checks or writing config
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Don't add per-check targets to external_monitors.toml
+        #[arg(long)]
+        no_external_config: bool,
+    },
+
+    /// Import job monitors from Cronitor (API or exported YAML)
+    Cronitor {
+        /// Cronitor API key (not needed with --file)
+        #[arg(long, env = "CRONITOR_API_KEY", required_unless_present = "file")]
+        api_key: Option<String>,
+
+        /// Read monitors from an exported cronitor.yaml instead of the API
+        #[arg(long, value_name = "PATH")]
+        file: Option<PathBuf>,
+
+        /// Cronitor API URL
+        #[arg(long, default_value = "https://cronitor.io")]
+        endpoint: String,
+
+        /// Show the import plan without creating checks or writing config
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Don't add per-check targets to external_monitors.toml
+        #[arg(long)]
+        no_external_config: bool,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum MaintenanceCommands {
+    /// Start a maintenance window now
+    Start {
+        /// Check slug or ID ("-" reads slugs from stdin)
+        #[arg(required_unless_present_any = SELECTOR_ARGS)]
+        slug: Option<String>,
+
+        #[command(flatten)]
+        selector: CheckSelector,
+
+        /// How long the window lasts (e.g., 30m, 2h)
+        #[arg(long = "for", value_name = "DURATION")]
+        duration: String,
+
+        /// Why the checks are in maintenance (shown in `inspect` and the dashboard)
+        #[arg(long)]
+        reason: Option<String>,
+
+        /// Skip confirmation prompt (when selecting several checks)
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// End active maintenance windows early
+    Stop {
+        /// Check slug or ID ("-" reads slugs from stdin)
+        #[arg(required_unless_present_any = SELECTOR_ARGS)]
+        slug: Option<String>,
+
+        #[command(flatten)]
+        selector: CheckSelector,
+
+        /// Also cancel scheduled and recurring windows
+        #[arg(long)]
+        all: bool,
+
+        /// Skip confirmation prompt (when selecting several checks)
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// Schedule a maintenance window at a later time or on a cron schedule
+    Schedule {
+        /// Check slug or ID ("-" reads slugs from stdin)
+        #[arg(required_unless_present_any = SELECTOR_ARGS)]
+        slug: Option<String>,
+
+        #[command(flatten)]
+        selector: CheckSelector,
+
+        /// Recurring window start (5-field cron, e.g., "0 2 * * SUN")
+        #[arg(long, required_unless_present = "at", conflicts_with = "at")]
+        cron: Option<String>,
+
+        /// One-off window start (RFC 3339, or "YYYY-MM-DD HH:MM" in --tz or local time)
+        #[arg(long)]
+        at: Option<String>,
+
+        /// How long each window lasts (e.g., 30m, 2h)
+        #[arg(long = "for", value_name = "DURATION")]
+        duration: String,
+
+        /// Timezone for --cron and --at (IANA format, e.g., Europe/Berlin)
+        #[arg(long)]
+        tz: Option<String>,
+
+        /// Why the checks are in maintenance (shown in `inspect` and the dashboard)
+        #[arg(long)]
+        reason: Option<String>,
+
+        /// Skip confirmation prompt (when selecting several checks)
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// List active and scheduled maintenance windows
+    List {
+        /// Only windows for this check (slug or ID)
+        check: Option<String>,
+    },
+
+    /// Cancel a scheduled window by ID (see `maintenance list`)
+    Cancel {
+        /// Maintenance window ID
+        id: Uuid,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum AlertCommands {
+    /// Manage alert channels
+    #[command(subcommand)]
+    Channel(AlertChannelCommands),
+}
+
+#[derive(Subcommand, Clone)]
+pub enum AlertChannelCommands {
+    /// List alert channels in the active organization
+    List,
+
+    /// Create an alert channel
+    Create {
+        /// Channel name (e.g., "ops-email", "#alerts")
+        name: String,
+
+        /// Channel type
+        #[arg(long = "type", value_enum)]
+        kind: AlertChannelKind,
+
+        /// Where alerts go: an email address, webhook URL, or PagerDuty routing key
+        #[arg(long)]
+        target: String,
+    },
+
+    /// Delete an alert channel (detaches it from all checks)
+    Delete {
+        /// Channel name or ID
+        channel: String,
+
+        /// Skip confirmation prompt
+        #[arg(long, short)]
+        yes: bool,
+    },
+}
+
+/// Where an alert channel delivers alerts
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
+pub enum AlertChannelKind {
+    Email,
+    Slack,
+    Discord,
+    Webhook,
+    Pagerduty,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum CrontabCommands {
+    /// Create a check in the active project for each crontab entry
+    Import {
+        /// Crontab file to read (e.g., /etc/crontab or a file in /etc/cron.d)
+        #[arg(long, value_name = "PATH", conflicts_with = "user")]
+        file: Option<PathBuf>,
+
+        /// Read the current user's crontab (`crontab -l`; the default)
+        #[arg(long)]
+        user: bool,
+
+        /// Lines have a user field (detected for /etc/crontab and /etc/cron.d)
+        #[arg(long)]
+        system: bool,
+
+        /// Prefix for proposed slugs (e.g., the host name, to keep hosts apart)
+        #[arg(long)]
+        prefix: Option<String>,
+
+        /// Rewrite imported lines to run through `pakyas monitor` (a backup is kept)
+        #[arg(long)]
+        wrap: bool,
+
+        /// Show the proposed checks and rewrites without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the confirmation before rewriting the crontab
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum SystemdCommands {
+    /// Create a check in the active project for each timer unit
+    Import {
+        /// Timer unit files or directories to scan [default: /etc/systemd/system]
+        #[arg(value_name = "PATH")]
+        paths: Vec<PathBuf>,
+
+        /// Prefix for check slugs (e.g., the host name, to keep hosts apart)
+        #[arg(long)]
+        prefix: Option<String>,
+
+        /// Write drop-ins that report each run of the timer's service
+        #[arg(long, value_enum)]
+        instrument: Option<SystemdInstrument>,
+
+        /// Directory for generated drop-ins
+        #[arg(long, value_name = "DIR", default_value = "/etc/systemd/system")]
+        unit_dir: PathBuf,
+
+        /// Show the proposed checks and drop-ins without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the confirmation before writing drop-ins
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum K8sCommands {
+    /// Create or match a check per CronJob and write the manifest with wrapped containers
+    Instrument {
+        /// Manifest file with CronJobs (multi-document YAML or a List; "-" reads stdin)
+        #[arg(long, short = 'f', value_name = "PATH")]
+        file: PathBuf,
+
+        /// Write the patched manifest here instead of stdout
+        #[arg(long, short = 'o', value_name = "PATH")]
+        output: Option<PathBuf>,
+
+        /// Prefix for check slugs (e.g., the cluster name, to keep clusters apart)
+        #[arg(long)]
+        prefix: Option<String>,
+
+        /// Container to wrap in CronJobs with several containers
+        #[arg(long, value_name = "NAME")]
+        container: Option<String>,
+
+        /// Path of the pakyas binary inside the container image
+        #[arg(long, value_name = "PATH", default_value = "pakyas")]
+        pakyas_bin: String,
+
+        /// Show the proposed checks without creating them or writing a manifest
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Args, Clone)]
+pub struct TailArgs {
+    /// Check slugs or IDs (optional with selectors)
+    #[arg(required_unless_present_any = SELECTOR_ARGS)]
+    pub slugs: Vec<String>,
+
+    #[command(flatten)]
+    pub selector: CheckSelector,
+
+    /// Show events since this time (e.g., "30m", "1h", "2024-01-01T00:00:00Z")
+    #[arg(long, default_value = "30m")]
+    pub since: String,
+
+    /// Event types to show: signal, state, alert (comma-separated)
+    #[arg(long)]
+    pub types: Option<String>,
+
+    /// Follow mode: continuously poll for new events
+    #[arg(short, long)]
+    pub follow: bool,
+
+    /// Number of events to fetch per check and request
+    #[arg(long, default_value = "50")]
+    pub limit: i64,
+
+    #[command(flatten)]
+    pub hooks: EventHookArgs,
+}
+
+/// Local commands run for new events while following
+#[derive(Args, Clone, Debug, Default)]
+pub struct EventHookArgs {
+    /// Run a command for every new event (event JSON on stdin, PAKYAS_EVENT_* env vars)
+    #[arg(long = "exec", value_name = "CMD", requires = "follow")]
+    pub exec: Vec<String>,
+
+    /// Run a command for one event type or category (e.g., status_changed=./handler.sh)
+    #[arg(long = "on", value_name = "TYPE=CMD", requires = "follow")]
+    pub on: Vec<String>,
+
+    /// Kill a hook that runs longer than this many milliseconds
+    #[arg(long, value_name = "MS", default_value = "30000")]
+    pub hook_timeout_ms: u64,
+}
+
+#[derive(Args, Clone)]
+pub struct ReportArgs {
+    #[command(flatten)]
+    pub selector: CheckSelector,
+
+    /// Report period (e.g., "30d", "7d", "2024-01-01T00:00:00Z")
+    #[arg(long, default_value = "30d")]
+    pub since: String,
+
+    /// Write CSV, or a Markdown or HTML summary, instead of the --format output
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub export: Option<ReportExport>,
+}
+
+/// Document formats for `pakyas report --export`
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
+pub enum ReportExport {
+    Csv,
+    Markdown,
+    Html,
+}
+
+/// How `pakyas systemd import` instruments a service
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
+pub enum SystemdInstrument {
+    /// Replace ExecStart with `pakyas monitor <slug> -- <original>`
+    Wrap,
+    /// Ping from ExecStartPre/ExecStartPost and report failures via OnFailure
+    Hooks,
+}
+
+/// Synthetic event type for `pakyas external test`.
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
+pub enum ExternalTestEvent {
+    Start,
+    Success,
+    Fail,
+}
+
 #[derive(Clone, Copy, Debug, Default, ValueEnum, PartialEq, Eq)]
 pub enum OutputFormat {
     /// Display as formatted table
@@ -0,0 +1,342 @@
+//! Alert channels: where check alerts are delivered (email, Slack, webhooks).
+
+use crate::cli::{AlertChannelCommands, AlertChannelKind, AlertCommands, OutputFormat};
+use crate::client::ApiClient;
+use crate::config::Context;
+use crate::error::CliError;
+use crate::output::{print_info, print_output, print_single, print_success, print_warning};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use dialoguer::Confirm;
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+use uuid::Uuid;
+
+// ============================================================================
+// API Types
+// ============================================================================
+
+/// Alert channel in the organization
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlertChannel {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub target: String,
+    /// Number of checks that send alerts to this channel
+    #[serde(default)]
+    pub check_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AlertChannel {
+    /// The channel with its target's secret parts hidden, for output
+    pub fn masked(mut self) -> Self {
+        self.target = display_target(&self.kind, &self.target);
+        self
+    }
+}
+
+/// Request body for creating an alert channel
+#[derive(Debug, Serialize)]
+struct CreateAlertChannelRequest {
+    org_id: Uuid,
+    name: String,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    target: String,
+}
+
+// ============================================================================
+// Display Types
+// ============================================================================
+
+#[derive(Debug, Tabled, Serialize)]
+pub struct AlertChannelRow {
+    #[tabled(rename = "NAME")]
+    name: String,
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "TYPE")]
+    kind: String,
+    #[tabled(rename = "TARGET")]
+    target: String,
+    #[tabled(rename = "CHECKS")]
+    checks: i64,
+}
+
+impl From<&AlertChannel> for AlertChannelRow {
+    fn from(channel: &AlertChannel) -> Self {
+        Self {
+            name: channel.name.clone(),
+            id: channel.id.to_string(),
+            kind: channel.kind.clone(),
+            target: display_target(&channel.kind, &channel.target),
+            checks: channel.check_count,
+        }
+    }
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Handle alert subcommands
+pub async fn handle(ctx: &Context, command: AlertCommands, verbose: bool) -> Result<()> {
+    match command {
+        AlertCommands::Channel(command) => match command {
+            AlertChannelCommands::List => list(ctx, verbose).await,
+            AlertChannelCommands::Create { name, kind, target } => {
+                create(ctx, name, kind, target, verbose).await
+            }
+            AlertChannelCommands::Delete { channel, yes } => {
+                delete(ctx, &channel, yes, verbose).await
+            }
+        },
+    }
+}
+
+/// List alert channels in the active organization
+async fn list(ctx: &Context, verbose: bool) -> Result<()> {
+    let org_id = ctx.require_org()?;
+    let client = ApiClient::new(ctx)?.with_verbose(verbose);
+    let channels = list_channels(&client, org_id).await?;
+
+    if channels.is_empty() && ctx.output_format() == OutputFormat::Table {
+        print_info("No alert channels. Create one with 'pakyas alert channel create'");
+        return Ok(());
+    }
+    print_output(ctx, channels.iter().map(AlertChannelRow::from).collect())
+}
+
+/// Create an alert channel
+async fn create(
+    ctx: &Context,
+    name: String,
+    kind: AlertChannelKind,
+    target: String,
+    verbose: bool,
+) -> Result<()> {
+    let org_id = ctx.require_org()?;
+    let org_uuid = Uuid::parse_str(org_id)
+        .map_err(|_| CliError::Other("Invalid organization ID".to_string()))?;
+    validate_target(kind, &target).map_err(CliError::Other)?;
+
+    let client = ApiClient::new(ctx)?.with_verbose(verbose);
+    let req = CreateAlertChannelRequest {
+        org_id: org_uuid,
+        name,
+        kind: kind_name(kind),
+        target,
+    };
+    let channel: AlertChannel = client.post("/api/v1/alert-channels", &req).await?;
+
+    if ctx.output_format() != OutputFormat::Table {
+        return print_single(ctx, &channel.masked());
+    }
+    print_success(&format!(
+        "Created {} channel: {} ({})",
+        channel.kind, channel.name, channel.id
+    ));
+    println!(
+        "Attach it with 'pakyas check alerts <slug> --add-channel {}'",
+        channel.name
+    );
+    Ok(())
+}
+
+/// Delete an alert channel by name or ID
+async fn delete(ctx: &Context, name_or_id: &str, skip_confirm: bool, verbose: bool) -> Result<()> {
+    let org_id = ctx.require_org()?;
+    let client = ApiClient::new(ctx)?.with_verbose(verbose);
+    let channels = list_channels(&client, org_id).await?;
+    let channel = find_channel(&channels, name_or_id)?;
+
+    if !skip_confirm {
+        let prompt = match channel.check_count {
+            0 => format!("Delete alert channel '{}'?", channel.name),
+            n => format!(
+                "Delete alert channel '{}'? {} check(s) will stop alerting there",
+                channel.name, n
+            ),
+        };
+        let confirm = Confirm::new()
+            .with_prompt(prompt)
+            .default(false)
+            .interact()?;
+        if !confirm {
+            print_warning("Cancelled");
+            return Ok(());
+        }
+    }
+
+    client
+        .delete(&format!("/api/v1/alert-channels/{}", channel.id))
+        .await?;
+    print_success(&format!("Deleted alert channel: {}", channel.name));
+    Ok(())
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Fetch all alert channels in an organization
+pub async fn list_channels(client: &ApiClient, org_id: &str) -> Result<Vec<AlertChannel>> {
+    client
+        .get(&format!("/api/v1/alert-channels?org_id={}", org_id))
+        .await
+}
+
+/// Find a channel by ID or exact name
+pub fn find_channel<'a>(
+    channels: &'a [AlertChannel],
+    name_or_id: &str,
+) -> Result<&'a AlertChannel> {
+    channels
+        .iter()
+        .find(|c| c.id.to_string() == name_or_id)
+        .or_else(|| channels.iter().find(|c| c.name == name_or_id))
+        .ok_or_else(|| {
+            CliError::Other(format!(
+                "Alert channel '{}' not found. Run 'pakyas alert channel list' to see channels",
+                name_or_id
+            ))
+            .into()
+        })
+}
+
+/// Type name the API expects
+fn kind_name(kind: AlertChannelKind) -> &'static str {
+    match kind {
+        AlertChannelKind::Email => "email",
+        AlertChannelKind::Slack => "slack",
+        AlertChannelKind::Discord => "discord",
+        AlertChannelKind::Webhook => "webhook",
+        AlertChannelKind::Pagerduty => "pagerduty",
+    }
+}
+
+/// Catch targets that can't work for the channel type before the API does
+fn validate_target(kind: AlertChannelKind, target: &str) -> Result<(), String> {
+    if target.trim().is_empty() || target.chars().any(char::is_whitespace) {
+        return Err(format!("Invalid --target '{}'", target));
+    }
+    match kind {
+        AlertChannelKind::Email => match target.split_once('@') {
+            Some((user, domain)) if !user.is_empty() && domain.contains('.') => Ok(()),
+            _ => Err(format!("'{}' is not an email address", target)),
+        },
+        AlertChannelKind::Slack | AlertChannelKind::Discord => {
+            if target.starts_with("https://") {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{} channels need an https:// webhook URL",
+                    kind_name(kind)
+                ))
+            }
+        }
+        AlertChannelKind::Webhook => {
+            if target.starts_with("https://") || target.starts_with("http://") {
+                Ok(())
+            } else {
+                Err("Webhook channels need an http(s):// URL".to_string())
+            }
+        }
+        AlertChannelKind::Pagerduty => {
+            if target.chars().all(|c| c.is_ascii_alphanumeric()) {
+                Ok(())
+            } else {
+                Err("PagerDuty channels need an integration routing key".to_string())
+            }
+        }
+    }
+}
+
+/// Hide the secret part of a target: webhook URLs carry it in the path (show only scheme
+/// and host), routing keys are secrets as a whole (show only the first characters).
+/// Email addresses are shown as they are.
+fn display_target(kind: &str, target: &str) -> String {
+    if kind == "email" {
+        return target.to_string();
+    }
+    let Some((scheme, rest)) = target.split_once("://") else {
+        let prefix: String = target.chars().take(4).collect();
+        return format!("{}...", prefix);
+    };
+    match rest.split_once('/') {
+        Some((host, path)) if !path.is_empty() => format!("{}://{}/...", scheme, host),
+        _ => target.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(name: &str) -> AlertChannel {
+        AlertChannel {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            kind: "email".to_string(),
+            target: "ops@example.com".to_string(),
+            check_count: 0,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_find_channel() {
+        let channels = vec![channel("ops-email"), channel("#alerts")];
+        let id = channels[1].id.to_string();
+
+        assert_eq!(
+            find_channel(&channels, "ops-email").unwrap().name,
+            "ops-email"
+        );
+        assert_eq!(find_channel(&channels, &id).unwrap().name, "#alerts");
+        assert!(find_channel(&channels, "ops").is_err());
+    }
+
+    #[test]
+    fn test_validate_target() {
+        use AlertChannelKind::*;
+        assert!(validate_target(Email, "ops@example.com").is_ok());
+        assert!(validate_target(Email, "ops").is_err());
+        assert!(validate_target(Slack, "https://hooks.slack.com/services/T0/B0/x").is_ok());
+        assert!(validate_target(Slack, "#alerts").is_err());
+        assert!(validate_target(Webhook, "http://10.0.0.5:8080/hook").is_ok());
+        assert!(validate_target(Pagerduty, "R0UT1NGK3Y").is_ok());
+        assert!(validate_target(Pagerduty, "https://events.pagerduty.com").is_err());
+        assert!(validate_target(Webhook, "https://example.com/a b").is_err());
+    }
+
+    #[test]
+    fn test_display_target() {
+        assert_eq!(
+            display_target("slack", "https://hooks.slack.com/services/T0/B0/secret"),
+            "https://hooks.slack.com/..."
+        );
+        assert_eq!(
+            display_target("webhook", "https://example.com"),
+            "https://example.com"
+        );
+        assert_eq!(
+            display_target("email", "ops@example.com"),
+            "ops@example.com"
+        );
+        assert_eq!(display_target("pagerduty", "R0UT1NGK3Y"), "R0UT...");
+    }
+
+    #[test]
+    fn test_masked_channel() {
+        let mut slack = channel("#alerts");
+        slack.kind = "slack".to_string();
+        slack.target = "https://hooks.slack.com/services/T0/B0/secret".to_string();
+        let json = serde_json::to_string(&slack.masked()).unwrap();
+        assert!(json.contains("https://hooks.slack.com/..."));
+        assert!(!json.contains("secret"));
+    }
+}
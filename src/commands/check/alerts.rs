@@ -0,0 +1,145 @@
+//! Per-check alert routing: attached channels and alert toggles.
+
+use crate::cli::OutputFormat;
+use crate::client::ApiClient;
+use crate::commands::alert::{AlertChannel, AlertChannelRow, find_channel, list_channels};
+use crate::config::Context;
+use crate::error::CliError;
+use crate::output::{print_info, print_output, print_single, print_success};
+use anyhow::Result;
+use serde::Serialize;
+use serde::de::IgnoredAny;
+use uuid::Uuid;
+
+use super::helpers::{format_toggle, resolve_check_by_org};
+use super::types::{AlertSettings, Check, UpdateCheckRequest};
+
+#[derive(Debug, Serialize)]
+struct AttachChannelRequest {
+    channel_id: Uuid,
+}
+
+/// Alert configuration of one check (JSON/YAML output of `check alerts`)
+#[derive(Debug, Serialize)]
+struct CheckAlerts {
+    check: String,
+    alert_on_down: Option<bool>,
+    alert_on_late: Option<bool>,
+    alert_on_overrun: Option<bool>,
+    alert_on_anomaly: Option<bool>,
+    notify_on_recovery: Option<bool>,
+    channels: Vec<AlertChannel>,
+}
+
+/// Show a check's alert configuration, or attach/detach channels and set toggles
+pub async fn alerts(
+    ctx: &Context,
+    slug_or_id: &str,
+    add: &[String],
+    remove: &[String],
+    settings: AlertSettings,
+    verbose: bool,
+) -> Result<()> {
+    let org_id = ctx.require_org()?;
+    let check = resolve_check_by_org(ctx, org_id, slug_or_id).await?;
+    let client = ApiClient::new(ctx)?.with_verbose(verbose);
+    let url = format!("/api/v1/checks/{}", check.id);
+
+    if add.is_empty() && remove.is_empty() && settings.is_empty() {
+        return show(ctx, &client, &check).await;
+    }
+
+    // Resolve every channel first so a typo doesn't leave a half-applied change
+    let channels = list_channels(&client, org_id).await?;
+    let to_add = add
+        .iter()
+        .map(|c| find_channel(&channels, c))
+        .collect::<Result<Vec<_>>>()?;
+    let to_remove = remove
+        .iter()
+        .map(|c| find_channel(&channels, c))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(channel) = to_add
+        .iter()
+        .find(|a| to_remove.iter().any(|r| r.id == a.id))
+    {
+        return Err(CliError::Other(format!(
+            "Channel '{}' is both added and removed",
+            channel.name
+        ))
+        .into());
+    }
+
+    for channel in to_add {
+        let req = AttachChannelRequest {
+            channel_id: channel.id,
+        };
+        client
+            .post::<IgnoredAny, _>(&format!("{}/alert-channels", url), &req)
+            .await?;
+        print_success(&format!(
+            "{} now alerts to {} ({})",
+            check.name, channel.name, channel.kind
+        ));
+    }
+    for channel in to_remove {
+        client
+            .delete(&format!("{}/alert-channels/{}", url, channel.id))
+            .await?;
+        print_success(&format!(
+            "{} no longer alerts to {}",
+            check.name, channel.name
+        ));
+    }
+    if !settings.is_empty() {
+        let req = UpdateCheckRequest {
+            alerts: settings,
+            ..Default::default()
+        };
+        client.put_no_response(&url, &req).await?;
+        print_success(&format!("Updated alert toggles for {}", check.name));
+    }
+    Ok(())
+}
+
+async fn show(ctx: &Context, client: &ApiClient, check: &Check) -> Result<()> {
+    let channels: Vec<AlertChannel> = client
+        .get(&format!("/api/v1/checks/{}/alert-channels", check.id))
+        .await?;
+
+    if ctx.output_format() != OutputFormat::Table {
+        return print_single(
+            ctx,
+            &CheckAlerts {
+                check: check.slug.clone(),
+                alert_on_down: check.alert_on_down,
+                alert_on_late: check.alert_on_late,
+                alert_on_overrun: check.alert_on_overrun,
+                alert_on_anomaly: check.alert_on_anomaly,
+                notify_on_recovery: check.notify_on_recovery,
+                channels: channels.into_iter().map(AlertChannel::masked).collect(),
+            },
+        );
+    }
+
+    println!("Alerts for {}:\n", check.name);
+    for (label, value) in [
+        ("down", check.alert_on_down),
+        ("late", check.alert_on_late),
+        ("overrun", check.alert_on_overrun),
+        ("anomaly", check.alert_on_anomaly),
+        ("recovery", check.notify_on_recovery),
+    ] {
+        println!("  {:<10} {}", label, format_toggle(value));
+    }
+    println!();
+
+    if channels.is_empty() {
+        print_info(&format!(
+            "No alert channels attached. Add one with 'pakyas check alerts {} --add-channel <CHANNEL>'",
+            check.slug
+        ));
+        return Ok(());
+    }
+    print_output(ctx, channels.iter().map(AlertChannelRow::from).collect())
+}
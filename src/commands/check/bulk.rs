@@ -0,0 +1,395 @@
+//! Bulk pause/resume/delete/update for checks picked by selectors.
+
+use crate::cache::CheckCache;
+use crate::cli::{CheckSelector, OutputFormat};
+use crate::client::ApiClient;
+use crate::config::Context;
+use crate::error::CliError;
+use crate::exit_codes;
+use crate::glob::glob_match;
+use crate::output::{format_status, print_output, print_success, print_warning};
+use anyhow::Result;
+use dialoguer::Confirm;
+use futures_util::stream::{self, StreamExt};
+use serde::Serialize;
+use serde::de::IgnoredAny;
+use tabled::Tabled;
+
+use super::types::{CheckWithProject, MaintenanceRequest, UpdateCheckRequest};
+
+/// Operation applied to every selected check
+pub enum BulkOp {
+    Pause,
+    Resume,
+    Delete,
+    Update(UpdateCheckRequest),
+    StartMaintenance(MaintenanceRequest),
+    ScheduleMaintenance(MaintenanceRequest),
+    /// End active windows; with `all`, also cancel scheduled ones
+    StopMaintenance {
+        all: bool,
+    },
+}
+
+impl BulkOp {
+    fn verb(&self) -> &'static str {
+        match self {
+            BulkOp::Pause => "Pause",
+            BulkOp::Resume => "Resume",
+            BulkOp::Delete => "Delete",
+            BulkOp::Update(_) => "Update",
+            BulkOp::StartMaintenance(_) => "Start maintenance for",
+            BulkOp::ScheduleMaintenance(_) => "Schedule maintenance for",
+            BulkOp::StopMaintenance { .. } => "Stop maintenance for",
+        }
+    }
+
+    fn done(&self) -> &'static str {
+        match self {
+            BulkOp::Pause => "paused",
+            BulkOp::Resume => "resumed",
+            BulkOp::Delete => "deleted",
+            BulkOp::Update(_) => "updated",
+            BulkOp::StartMaintenance(_) => "maintenance started",
+            BulkOp::ScheduleMaintenance(_) => "maintenance scheduled",
+            BulkOp::StopMaintenance { .. } => "maintenance stopped",
+        }
+    }
+
+    fn summary(&self, total: usize) -> String {
+        match self {
+            BulkOp::StartMaintenance(_)
+            | BulkOp::ScheduleMaintenance(_)
+            | BulkOp::StopMaintenance { .. } => {
+                format!("{} for {} check(s)", capitalize(self.done()), total)
+            }
+            _ => format!("{} {} check(s)", capitalize(self.done()), total),
+        }
+    }
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct SelectedRow {
+    #[tabled(rename = "PROJECT")]
+    project: String,
+    #[tabled(rename = "SLUG")]
+    slug: String,
+    #[tabled(rename = "NAME")]
+    name: String,
+    #[tabled(rename = "STATUS")]
+    status: String,
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct BulkRow {
+    #[tabled(rename = "PROJECT")]
+    project: String,
+    #[tabled(rename = "SLUG")]
+    slug: String,
+    #[tabled(rename = "RESULT")]
+    result: String,
+}
+
+/// Whether bulk mode applies: any selector flag, or slugs read from stdin
+pub fn is_bulk(slug: Option<&str>, selector: &CheckSelector) -> bool {
+    selector.is_active() || slug == Some("-")
+}
+
+/// Resolve the selection, confirm it, and run `op` on each check with bounded concurrency
+pub async fn run(
+    ctx: &Context,
+    op: BulkOp,
+    slug: Option<&str>,
+    selector: &CheckSelector,
+    skip_confirm: bool,
+    verbose: bool,
+) -> Result<()> {
+    let slugs = match slug {
+        // stdin holds the slugs, so there's nothing left to read an answer from
+        Some("-") if !skip_confirm => {
+            return Err(CliError::Other(
+                "Reading slugs from stdin needs --yes to skip the confirmation".to_string(),
+            )
+            .into());
+        }
+        Some("-") => Some(read_stdin_slugs()?),
+        Some(slug) => Some(vec![slug.to_string()]),
+        None => None,
+    };
+
+    let checks = fetch_checks(ctx, selector.project.as_deref(), verbose).await?;
+    let missing: Vec<String> = slugs
+        .iter()
+        .flatten()
+        .filter(|s| !checks.iter().any(|c| c.check.slug == **s))
+        .cloned()
+        .collect();
+    let selected: Vec<CheckWithProject> = checks
+        .into_iter()
+        .filter(|c| matches_selector(c, selector, slugs.as_deref()))
+        .collect();
+
+    if selected.is_empty() && missing.is_empty() {
+        print_warning("No checks match the selection");
+        return Ok(());
+    }
+
+    if !selected.is_empty() && !skip_confirm {
+        let rows: Vec<SelectedRow> = selected
+            .iter()
+            .map(|c| SelectedRow {
+                project: c.project_name.clone(),
+                slug: c.check.slug.clone(),
+                name: c.check.name.clone(),
+                status: format_status(&c.check.status),
+            })
+            .collect();
+        print_output(ctx, rows)?;
+        println!();
+
+        let suffix = if matches!(op, BulkOp::Delete) {
+            ". This cannot be undone"
+        } else {
+            ""
+        };
+        let confirm = Confirm::new()
+            .with_prompt(format!(
+                "{} {} check(s){}?",
+                op.verb(),
+                selected.len(),
+                suffix
+            ))
+            .default(false)
+            .interact()?;
+        if !confirm {
+            print_warning("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let client = ApiClient::new(ctx)?;
+    let results: Vec<(CheckWithProject, Result<()>)> = stream::iter(selected)
+        .map(|c| {
+            let (client, op) = (&client, &op);
+            async move {
+                if verbose {
+                    eprintln!("[verbose] {} {}", op.verb(), c.check.slug);
+                }
+                let result = apply(client, op, &c).await;
+                (c, result)
+            }
+        })
+        .buffered(selector.concurrency as usize)
+        .collect()
+        .await;
+
+    if matches!(op, BulkOp::Delete) {
+        let org_id = ctx.require_org()?;
+        let mut cache = CheckCache::load()?;
+        for (c, _) in results.iter().filter(|(_, r)| r.is_ok()) {
+            cache.invalidate(org_id, &c.check.slug);
+        }
+        cache.save()?;
+    }
+
+    let mut failed = missing.len();
+    let mut rows: Vec<BulkRow> = results
+        .into_iter()
+        .map(|(c, result)| BulkRow {
+            project: c.project_name,
+            slug: c.check.slug,
+            result: match result {
+                Ok(()) => op.done().to_string(),
+                Err(e) => {
+                    failed += 1;
+                    format!("failed: {}", e)
+                }
+            },
+        })
+        .collect();
+    rows.extend(missing.into_iter().map(|slug| BulkRow {
+        project: "-".to_string(),
+        slug,
+        result: "failed: check not found".to_string(),
+    }));
+
+    let total = rows.len();
+    print_output(ctx, rows)?;
+
+    if ctx.output_format() == OutputFormat::Table {
+        if failed == 0 {
+            print_success(&op.summary(total));
+        } else {
+            print_warning(&format!("{} of {} check(s) failed", failed, total));
+        }
+    }
+
+    if failed > 0 {
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+async fn apply(client: &ApiClient, op: &BulkOp, c: &CheckWithProject) -> Result<()> {
+    let url = format!("/api/v1/checks/{}", c.check.id);
+    match op {
+        BulkOp::Pause => client.patch_no_response(&format!("{}/pause", url)).await,
+        BulkOp::Resume => client.patch_no_response(&format!("{}/resume", url)).await,
+        BulkOp::Delete => client.delete(&url).await,
+        BulkOp::Update(req) => client.put_no_response(&url, req).await,
+        BulkOp::StartMaintenance(req) | BulkOp::ScheduleMaintenance(req) => client
+            .post::<IgnoredAny, _>(&format!("{}/maintenance", url), req)
+            .await
+            .map(|_| ()),
+        BulkOp::StopMaintenance { all } => client.delete(&maintenance_url(&url, *all)).await,
+    }
+}
+
+/// Endpoint that ends a check's active window (and cancels scheduled ones with `all`)
+pub(super) fn maintenance_url(check_url: &str, all: bool) -> String {
+    if all {
+        format!("{}/maintenance?all=true", check_url)
+    } else {
+        format!("{}/maintenance", check_url)
+    }
+}
+
+/// Fetch candidate checks for the project filter (or the whole organization)
+pub(super) async fn fetch_checks(
+    ctx: &Context,
+    project: Option<&str>,
+    verbose: bool,
+) -> Result<Vec<CheckWithProject>> {
+    use crate::commands::project::resolve_project;
+
+    let org_id = ctx.require_org()?;
+    let client = ApiClient::new(ctx)?;
+    let url = match project {
+        Some(identifier) => {
+            let project = resolve_project(ctx, identifier).await?;
+            format!("/api/v1/checks?project_id={}", project.id)
+        }
+        None => format!("/api/v1/checks?org_id={}", org_id),
+    };
+    if verbose {
+        eprintln!("[verbose] Fetching checks from: {}", url);
+    }
+    let checks: Vec<CheckWithProject> = client.get(&url).await?;
+    Ok(checks
+        .into_iter()
+        .filter(|c| !c.check.soft_deleted)
+        .collect())
+}
+
+/// Read one slug per line from stdin, skipping blank lines and `#` comments
+fn read_stdin_slugs() -> Result<Vec<String>> {
+    let input = std::io::read_to_string(std::io::stdin())
+        .map_err(|e| CliError::Other(format!("Failed to read slugs from stdin: {}", e)))?;
+    Ok(parse_slugs(&input))
+}
+
+fn parse_slugs(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether a check passes every given filter (project is applied when fetching)
+pub(super) fn matches_selector(
+    c: &CheckWithProject,
+    selector: &CheckSelector,
+    slugs: Option<&[String]>,
+) -> bool {
+    let check = &c.check;
+    slugs.is_none_or(|s| s.contains(&check.slug))
+        && selector.tag.as_ref().is_none_or(|t| check.tags.contains(t))
+        && selector
+            .status
+            .as_ref()
+            .is_none_or(|s| check.status.eq_ignore_ascii_case(s))
+        && selector
+            .pattern
+            .as_ref()
+            .is_none_or(|p| glob_match(p, &check.slug))
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(slug: &str, tags: &[&str], status: &str) -> CheckWithProject {
+        serde_json::from_value(serde_json::json!({
+            "id": uuid::Uuid::nil(),
+            "public_id": uuid::Uuid::nil(),
+            "project_id": uuid::Uuid::nil(),
+            "project_name": "Infra",
+            "name": slug,
+            "slug": slug,
+            "tags": tags,
+            "description": null,
+            "period_seconds": 300,
+            "missing_after_seconds": 60,
+            "status": status,
+            "last_ping_at": null,
+            "next_ping_expected_at": null,
+            "created_at": "2026-01-01T00:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_matches_selector() {
+        let db = check("legacy-db", &["db"], "down");
+        let web = check("web", &["web"], "up");
+        let selector = CheckSelector {
+            tag: Some("db".to_string()),
+            status: Some("DOWN".to_string()),
+            pattern: Some("legacy-*".to_string()),
+            ..Default::default()
+        };
+
+        assert!(matches_selector(&db, &selector, None));
+        assert!(!matches_selector(&web, &selector, None));
+        assert!(!matches_selector(
+            &db,
+            &selector,
+            Some(&["web".to_string()])
+        ));
+        assert!(matches_selector(
+            &web,
+            &CheckSelector::default(),
+            Some(&["web".to_string()])
+        ));
+    }
+
+    #[test]
+    fn test_parse_slugs() {
+        assert_eq!(
+            parse_slugs("backup\n\n  # old ones\nlegacy-sync  \n"),
+            vec!["backup", "legacy-sync"]
+        );
+    }
+
+    #[test]
+    fn test_is_bulk() {
+        assert!(!is_bulk(Some("backup"), &CheckSelector::default()));
+        assert!(is_bulk(Some("-"), &CheckSelector::default()));
+        let selector = CheckSelector {
+            tag: Some("db".to_string()),
+            ..Default::default()
+        };
+        assert!(is_bulk(None, &selector));
+    }
+}
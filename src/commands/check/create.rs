@@ -13,7 +13,7 @@ use super::helpers::{
     format_duration, parse_duration, print_check_created, print_check_json, print_dry_run,
     slug_to_title, smart_grace, validate_cron_cli, validate_slug, validate_timezone,
 };
-use super::types::{Check, CreateCheckRequest};
+use super::types::{AlertSettings, Check, CreateCheckRequest};
 
 /// Create a new check
 #[allow(clippy::too_many_arguments)]
@@ -30,6 +30,7 @@ pub async fn create(
     alert_after_miss_pings: Option<i32>,
     alert_after_fail_pings: Option<i32>,
     max_runtime: Option<String>,
+    alerts: AlertSettings,
     json_output: bool,
     quiet: bool,
     dry_run: bool,
@@ -154,22 +155,10 @@ pub async fn create(
         alert_after_miss_pings,
         alert_after_fail_pings,
         max_runtime_seconds,
+        alerts,
     };
 
-    // Create check
-    let client = ApiClient::new(ctx)?;
-    let check: Check = client.post("/api/v1/checks", &req).await?;
-
-    // Update cache
-    let mut cache = CheckCache::load()?;
-    cache.set(
-        project_id,
-        &check.slug,
-        check.id,
-        check.public_id,
-        check.name.clone(),
-    );
-    cache.save()?;
+    let check = create_check(ctx, &req).await?;
 
     // Output
     let ping_url = format!("{}/{}", ctx.ping_url(), check.public_id);
@@ -185,6 +174,19 @@ pub async fn create(
     Ok(())
 }
 
+/// Create a check from a prepared request and add it to the cache
+pub async fn create_check(ctx: &Context, req: &CreateCheckRequest) -> Result<Check> {
+    let client = ApiClient::new(ctx)?;
+    let check: Check = client.post("/api/v1/checks", req).await?;
+
+    // Update cache (includes tags, which external monitor rules match on)
+    let mut cache = CheckCache::load()?;
+    cache.update_from_checks(&req.project_id.to_string(), std::iter::once(check.clone()));
+    cache.save()?;
+
+    Ok(check)
+}
+
 /// Interactive check creation mode
 pub async fn create_interactive(
     ctx: &Context,
@@ -291,21 +293,10 @@ pub async fn create_interactive(
         alert_after_miss_pings: None,
         alert_after_fail_pings: None,
         max_runtime_seconds: None,
+        alerts: Default::default(),
     };
 
-    let client = ApiClient::new(ctx)?;
-    let check: Check = client.post("/api/v1/checks", &req).await?;
-
-    // Update cache
-    let mut cache = CheckCache::load()?;
-    cache.set(
-        project_id,
-        &check.slug,
-        check.id,
-        check.public_id,
-        check.name.clone(),
-    );
-    cache.save()?;
+    let check = create_check(ctx, &req).await?;
 
     let ping_url = format!("{}/{}", ctx.ping_url(), check.public_id);
     print_check_created(ctx, &check, &ping_url, grace_auto);
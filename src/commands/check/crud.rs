@@ -1,4 +1,4 @@
-//! Basic CRUD operations: list, show, pause, resume, delete, logs, sync.
+//! Basic CRUD operations: list, show, pause, resume, delete, sync.
 
 use crate::cache::CheckCache;
 use crate::client::ApiClient;
@@ -7,12 +7,8 @@ use crate::output::{format_status, print_output, print_single, print_success, pr
 use anyhow::Result;
 use dialoguer::Confirm;
 
-use super::helpers::{
-    format_duration, format_ping_type, format_relative_time, resolve_check_by_org,
-};
-use super::types::{
-    CheckDetail, CheckRowWithProject, CheckWithProject, PingHistoryResponse, PingRow,
-};
+use super::helpers::{format_duration, format_relative_time, resolve_check_by_org};
+use super::types::{CheckDetail, CheckRowWithProject, CheckWithProject};
 
 /// List all checks in the organization (optionally filtered by project)
 pub async fn list(ctx: &Context, project_filter: Option<&str>, verbose: bool) -> Result<()> {
@@ -162,39 +158,6 @@ pub async fn delete(
     Ok(())
 }
 
-/// Show ping history for a check
-pub async fn logs(ctx: &Context, slug_or_id: &str, limit: i32, _verbose: bool) -> Result<()> {
-    let org_id = ctx.require_org()?;
-    let check = resolve_check_by_org(ctx, org_id, slug_or_id).await?;
-    let client = ApiClient::new(ctx)?;
-
-    let url = format!("/api/v1/checks/{}/pings?limit={}", check.id, limit);
-    let response: PingHistoryResponse = client.get(&url).await?;
-
-    let rows: Vec<PingRow> = response
-        .pings
-        .into_iter()
-        .map(|p| PingRow {
-            time: format_relative_time(Some(p.created_at)),
-            ping_type: format_ping_type(&p.ping_type),
-            duration: p
-                .duration_ms
-                .map(|d| format!("{}ms", d))
-                .unwrap_or_else(|| "-".to_string()),
-            source: p.source_ip.unwrap_or_else(|| "-".to_string()),
-        })
-        .collect();
-
-    if rows.is_empty() {
-        print_warning("No pings recorded yet");
-    } else {
-        print_output(ctx, rows)?;
-        println!("\nTotal: {} pings", response.total);
-    }
-
-    Ok(())
-}
-
 /// Force refresh the local check cache for the organization
 pub async fn sync(ctx: &Context, _verbose: bool) -> Result<()> {
     let org_id = ctx.require_org()?;
@@ -5,6 +5,7 @@ use crate::client::ApiClient;
 use crate::config::Context;
 use crate::cron::{next_cron_times_in_tz, validate_cron_expression};
 use crate::error::CliError;
+use crate::external_monitors::CheckMeta;
 use anyhow::{Result, anyhow};
 use chrono::{DateTime, Utc};
 use regex::Regex;
@@ -108,6 +109,15 @@ pub fn parse_duration(s: &str) -> Result<i32> {
     ))
 }
 
+/// Format an alert toggle; unset toggles follow the server default
+pub fn format_toggle(value: Option<bool>) -> String {
+    match value {
+        Some(true) => "on".to_string(),
+        Some(false) => "off".to_string(),
+        None => "default".to_string(),
+    }
+}
+
 /// Format seconds as human-readable duration
 pub fn format_duration(seconds: i32) -> String {
     if seconds < 60 {
@@ -294,6 +304,60 @@ pub async fn resolve_check_by_org(ctx: &Context, org_id: &str, slug_or_id: &str)
         .ok_or_else(|| CliError::CheckNotFound(slug_or_id.to_string()).into())
 }
 
+/// Resolve a check by slug or ID with smart context: project first, then org
+pub async fn resolve_check_smart(ctx: &Context, slug_or_id: &str) -> Result<Check> {
+    if let Some(project_id) = ctx.active_project_id() {
+        return resolve_check(ctx, project_id, slug_or_id).await;
+    }
+    let org_id = ctx.require_org()?;
+    resolve_check_by_org(ctx, org_id, slug_or_id).await
+}
+
+/// External monitor rule metadata for a check, fetching the check when it isn't cached
+///
+/// Only slug keys can be fetched. A public_id that isn't cached gets no tags, so
+/// tag rules don't apply to it.
+pub async fn resolve_check_meta(
+    ctx: &Context,
+    key: &str,
+    public_id: Option<&Uuid>,
+    verbose: bool,
+) -> CheckMeta {
+    if let Some(meta) = CheckMeta::cached(key, public_id) {
+        return meta;
+    }
+
+    let mut meta = CheckMeta::from_key(key);
+    if Uuid::parse_str(key).is_ok() {
+        if verbose {
+            eprintln!(
+                "[verbose] '{}' is not in the check cache; tag rules can't be evaluated for it",
+                key
+            );
+        }
+        return meta;
+    }
+    if validate_slug(key).is_err() {
+        return meta;
+    }
+
+    match resolve_check_smart(ctx, key).await {
+        Ok(check) => {
+            meta.slug = Some(check.slug);
+            meta.tags = check.tags;
+        }
+        Err(e) => {
+            if verbose {
+                eprintln!(
+                    "[verbose] Could not fetch '{}' ({}); tag rules can't be evaluated for it",
+                    key, e
+                );
+            }
+        }
+    }
+    meta
+}
+
 /// Resolve a check's public_id by slug (for ping commands)
 pub async fn resolve_public_id(ctx: &Context, project_id: &str, slug: &str) -> Result<Uuid> {
     // Try cache first
@@ -0,0 +1,288 @@
+//! Event hooks: local commands run for new events while `tail --follow` is running.
+
+use crate::cli::EventHookArgs;
+use crate::error::CliError;
+use crate::external_ping::shell_command;
+use crate::output::print_error;
+use anyhow::Result;
+use serde::Serialize;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+use super::tail::{EventItem, event_category};
+
+/// Event types accepted by --on
+const EVENT_TYPES: [&str; 4] = [
+    "run_started",
+    "run_finished",
+    "status_changed",
+    "alert_decision",
+];
+
+/// Event categories accepted by --on (as shown by `tail` and accepted by --types)
+const EVENT_CATEGORIES: [&str; 3] = ["signal", "state", "alert"];
+
+/// Hooks to run for each new event
+#[derive(Debug)]
+pub(super) struct EventHooks {
+    hooks: Vec<Hook>,
+    timeout_ms: u64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Hook {
+    /// Event type or category to react to; `None` matches every event
+    filter: Option<String>,
+    command: String,
+}
+
+/// JSON written to a hook's stdin
+#[derive(Serialize)]
+struct HookPayload<'a> {
+    check: &'a str,
+    #[serde(flatten)]
+    event: &'a EventItem,
+}
+
+impl EventHooks {
+    /// Validate --exec and --on
+    pub(super) fn parse(args: &EventHookArgs) -> Result<Self> {
+        let mut hooks = Vec::new();
+        for command in &args.exec {
+            hooks.push(Hook {
+                filter: None,
+                command: parse_command("--exec", command)?,
+            });
+        }
+        for spec in &args.on {
+            let Some((filter, command)) = spec.split_once('=') else {
+                return Err(CliError::Other(format!(
+                    "Invalid --on '{}'. Use TYPE=CMD (e.g., status_changed=./handler.sh)",
+                    spec
+                ))
+                .into());
+            };
+            let filter = filter.trim().to_lowercase();
+            if !EVENT_TYPES.contains(&filter.as_str())
+                && !EVENT_CATEGORIES.contains(&filter.as_str())
+            {
+                return Err(CliError::Other(format!(
+                    "Unknown event type '{}' in --on. Valid types: {}, {}",
+                    filter,
+                    EVENT_TYPES.join(", "),
+                    EVENT_CATEGORIES.join(", ")
+                ))
+                .into());
+            }
+            hooks.push(Hook {
+                filter: Some(filter),
+                command: parse_command("--on", command)?,
+            });
+        }
+        Ok(Self {
+            hooks,
+            timeout_ms: args.hook_timeout_ms,
+        })
+    }
+
+    /// Commands to run for an event, in the order they were given
+    fn matching<'a>(&'a self, event: &'a EventItem) -> impl Iterator<Item = &'a str> {
+        self.hooks
+            .iter()
+            .filter(|h| match &h.filter {
+                None => true,
+                Some(filter) => {
+                    *filter == event.event_type
+                        || event_category(&event.event_type) == Some(filter.as_str())
+                }
+            })
+            .map(|h| h.command.as_str())
+    }
+
+    /// Run the matching hooks one after another.
+    /// A failing hook is reported and doesn't stop the tail.
+    pub(super) async fn run(&self, check: &str, event: &EventItem) {
+        for command in self.matching(event) {
+            if let Err(e) = run_hook(command, self.timeout_ms, check, event).await {
+                print_error(&format!(
+                    "Hook '{}' failed for event {}: {}",
+                    command, event.id, e
+                ));
+            }
+        }
+    }
+}
+
+fn parse_command(flag: &str, command: &str) -> Result<String> {
+    let command = command.trim();
+    if command.is_empty() {
+        return Err(CliError::Other(format!("{} needs a command", flag)).into());
+    }
+    Ok(command.to_string())
+}
+
+/// Build the PAKYAS_EVENT_* environment variables for an event hook
+fn hook_env(check: &str, event: &EventItem) -> Vec<(&'static str, String)> {
+    let mut env = vec![
+        ("PAKYAS_EVENT_CHECK", check.to_string()),
+        ("PAKYAS_EVENT_ID", event.id.to_string()),
+        ("PAKYAS_EVENT_TYPE", event.event_type.clone()),
+        ("PAKYAS_EVENT_TIMESTAMP", event.occurred_at.to_rfc3339()),
+        ("PAKYAS_EVENT_SOURCE", event.source.clone()),
+        ("PAKYAS_EVENT_SUMMARY", event.summary.clone()),
+    ];
+    if let Some(category) = event_category(&event.event_type) {
+        env.push(("PAKYAS_EVENT_CATEGORY", category.to_string()));
+    }
+    if let Some(from) = &event.from_status {
+        env.push(("PAKYAS_EVENT_FROM_STATUS", from.clone()));
+    }
+    if let Some(to) = &event.to_status {
+        env.push(("PAKYAS_EVENT_TO_STATUS", to.clone()));
+    }
+    env
+}
+
+/// Run one hook with the event as JSON on stdin.
+///
+/// Hook output goes to stderr so it can't corrupt JSON streamed on stdout.
+async fn run_hook(command: &str, timeout_ms: u64, check: &str, event: &EventItem) -> Result<()> {
+    let payload = serde_json::to_vec(&HookPayload { check, event })?;
+
+    let mut child = shell_command(command)
+        .envs(hook_env(check, event))
+        .stdin(Stdio::piped())
+        .stdout(std::io::stderr())
+        .stderr(Stdio::inherit())
+        .kill_on_drop(true)
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores stdin may exit before reading it; that's not a failure
+        let _ = stdin.write_all(&payload).await;
+    }
+
+    let status = tokio::time::timeout(Duration::from_millis(timeout_ms), child.wait())
+        .await
+        .map_err(|_| anyhow::anyhow!("timed out after {}ms", timeout_ms))??;
+    if status.success() {
+        Ok(())
+    } else {
+        anyhow::bail!("exited with {}", status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(exec: &[&str], on: &[&str]) -> EventHookArgs {
+        EventHookArgs {
+            exec: exec.iter().map(|s| s.to_string()).collect(),
+            on: on.iter().map(|s| s.to_string()).collect(),
+            hook_timeout_ms: 5000,
+        }
+    }
+
+    fn event(event_type: &str) -> EventItem {
+        serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "event_type": event_type,
+            "occurred_at": "2026-10-18T12:00:00Z",
+            "source": "scheduler",
+            "payload": {},
+            "from_status": "up",
+            "to_status": "down",
+            "summary": "up -> down",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_hooks() {
+        let hooks = EventHooks::parse(&args(
+            &["notify-send tail"],
+            &["Status_Changed=./handler.sh --flag=1", "alert= ./page.sh"],
+        ))
+        .unwrap();
+        assert_eq!(
+            hooks.hooks,
+            [
+                Hook {
+                    filter: None,
+                    command: "notify-send tail".to_string()
+                },
+                Hook {
+                    filter: Some("status_changed".to_string()),
+                    command: "./handler.sh --flag=1".to_string()
+                },
+                Hook {
+                    filter: Some("alert".to_string()),
+                    command: "./page.sh".to_string()
+                },
+            ]
+        );
+        assert!(EventHooks::parse(&args(&[], &[])).unwrap().hooks.is_empty());
+
+        assert!(EventHooks::parse(&args(&[], &["./handler.sh"])).is_err());
+        assert!(EventHooks::parse(&args(&[], &["status=./handler.sh"])).is_err());
+        assert!(EventHooks::parse(&args(&[], &["state="])).is_err());
+        assert!(EventHooks::parse(&args(&[" "], &[])).is_err());
+    }
+
+    #[test]
+    fn test_matching_by_type_and_category() {
+        let hooks = EventHooks::parse(&args(
+            &["all"],
+            &["status_changed=changed", "signal=signal", "alert=alert"],
+        ))
+        .unwrap();
+        let matched = |t: &str| -> Vec<String> {
+            let event = event(t);
+            hooks.matching(&event).map(str::to_string).collect()
+        };
+        assert_eq!(matched("status_changed"), ["all", "changed"]);
+        assert_eq!(matched("run_finished"), ["all", "signal"]);
+        assert_eq!(matched("alert_decision"), ["all", "alert"]);
+        assert_eq!(matched("annotation"), ["all"]);
+    }
+
+    #[test]
+    fn test_hook_env() {
+        let env = hook_env("backup", &event("status_changed"));
+        let get = |key: &str| env.iter().find(|(k, _)| *k == key).map(|(_, v)| v.as_str());
+        assert_eq!(get("PAKYAS_EVENT_CHECK"), Some("backup"));
+        assert_eq!(get("PAKYAS_EVENT_ID"), Some("7"));
+        assert_eq!(get("PAKYAS_EVENT_CATEGORY"), Some("state"));
+        assert_eq!(get("PAKYAS_EVENT_TO_STATUS"), Some("down"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_hook_receives_stdin_and_env() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let out = temp_dir.path().join("event.json");
+        let command = format!(
+            "cat > {} && test \"$PAKYAS_EVENT_TO_STATUS\" = down",
+            out.display()
+        );
+        run_hook(&command, 5000, "backup", &event("status_changed"))
+            .await
+            .unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&out).unwrap()).unwrap();
+        assert_eq!(written["check"], "backup");
+        assert_eq!(written["event_type"], "status_changed");
+
+        let err = run_hook("exit 3", 5000, "backup", &event("run_started"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("exited with"));
+        let err = run_hook("sleep 5", 50, "backup", &event("run_started"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("timed out"));
+    }
+}
@@ -0,0 +1,372 @@
+//! Ping history: paginated `check logs` and `check log-body`.
+
+use crate::cli::OutputFormat;
+use crate::client::ApiClient;
+use crate::config::Context;
+use crate::error::CliError;
+use crate::output::{print_ndjson_stream, print_output, print_single, print_warning};
+use anyhow::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
+use console::style;
+
+use super::helpers::{
+    format_ping_type, format_relative_time, parse_duration, resolve_check_by_org,
+};
+use super::types::{Check, PingHistoryResponse, PingLog, PingRow};
+
+/// Pings fetched per request when paging through history
+const PAGE_SIZE: i32 = 100;
+
+/// Ping types accepted by --type
+const PING_TYPES: [&str; 3] = ["success", "fail", "start"];
+
+/// Filters for `check logs`
+#[derive(Debug, Default)]
+pub struct LogFilter {
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub types: Option<String>,
+    pub run: Option<String>,
+    pub show_body: bool,
+}
+
+impl LogFilter {
+    fn is_active(&self) -> bool {
+        self.since.is_some() || self.until.is_some() || self.types.is_some() || self.run.is_some()
+    }
+}
+
+/// Show ping history for a check, paging through results up to `limit` (0 = all)
+pub async fn logs(
+    ctx: &Context,
+    slug_or_id: &str,
+    limit: i32,
+    filter: &LogFilter,
+    verbose: bool,
+) -> Result<()> {
+    if limit < 0 {
+        return Err(CliError::Other("--limit can't be negative".to_string()).into());
+    }
+    let query = build_query(filter, Utc::now())?;
+    let org_id = ctx.require_org()?;
+    let check = resolve_check_by_org(ctx, org_id, slug_or_id).await?;
+    let client = ApiClient::new(ctx)?;
+    let is_ndjson = ctx.output_format() == OutputFormat::Ndjson;
+
+    let mut pings: Vec<PingLog> = Vec::new();
+    let total = fetch_pings(&client, &check, limit, &query, verbose, |page| {
+        // Stream large exports page by page instead of holding them in memory
+        if is_ndjson {
+            print_ndjson_stream(page.iter().map(|p| ping_row(p, filter.show_body)))
+        } else {
+            pings.extend(page);
+            Ok(())
+        }
+    })
+    .await?;
+
+    match ctx.output_format() {
+        OutputFormat::Ndjson => return Ok(()),
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let rows = pings.iter().map(|p| ping_row(p, filter.show_body));
+            return print_output(ctx, rows.collect());
+        }
+        OutputFormat::Table => {}
+    }
+
+    if pings.is_empty() {
+        if filter.is_active() {
+            print_warning("No pings match these filters");
+        } else {
+            print_warning("No pings recorded yet");
+        }
+        return Ok(());
+    }
+
+    let rows: Vec<PingRow> = pings.iter().map(|p| ping_row(p, false)).collect();
+    print_output(ctx, rows)?;
+    println!("\nShowing {} of {} pings", pings.len(), total);
+
+    if filter.show_body {
+        for ping in pings.iter().filter(|p| has_body(p)) {
+            println!(
+                "\n{}",
+                style(format!(
+                    "--- ping {} ({}, {}) ---",
+                    ping.id,
+                    ping.ping_type,
+                    format_relative_time(Some(ping.created_at))
+                ))
+                .dim()
+            );
+            print_body(ping.body.as_deref().unwrap_or_default());
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the captured body of a single ping
+pub async fn log_body(ctx: &Context, slug_or_id: &str, ping_id: i64, verbose: bool) -> Result<()> {
+    let org_id = ctx.require_org()?;
+    let check = resolve_check_by_org(ctx, org_id, slug_or_id).await?;
+    let client = ApiClient::new(ctx)?;
+
+    let url = format!("/api/v1/checks/{}/pings/{}", check.id, ping_id);
+    if verbose {
+        eprintln!("[verbose] Fetching ping from: {}", url);
+    }
+    let ping: PingLog = client.get(&url).await?;
+
+    if ctx.output_format() != OutputFormat::Table {
+        return print_single(ctx, &ping);
+    }
+    if !has_body(&ping) {
+        print_warning(&format!("Ping {} has no captured body", ping_id));
+        return Ok(());
+    }
+    // Raw body on stdout so it can be piped or redirected
+    print_body(ping.body.as_deref().unwrap_or_default());
+    Ok(())
+}
+
+/// Page through a check's pings (newest first) up to `limit` (0 = all), returning the total
+pub(super) async fn fetch_pings(
+    client: &ApiClient,
+    check: &Check,
+    limit: i32,
+    query: &str,
+    verbose: bool,
+    mut on_page: impl FnMut(Vec<PingLog>) -> Result<()>,
+) -> Result<i64> {
+    let mut offset = 0;
+    let mut total = 0;
+    loop {
+        let page_size = next_page_size(limit, offset);
+        if page_size == 0 {
+            break;
+        }
+        let url = format!(
+            "/api/v1/checks/{}/pings?limit={}&offset={}{}",
+            check.id, page_size, offset, query
+        );
+        if verbose {
+            eprintln!("[verbose] Fetching pings from: {}", url);
+        }
+        let page: PingHistoryResponse = client.get(&url).await?;
+        let count = page.pings.len() as i32;
+        total = page.total;
+        offset += count;
+        on_page(page.pings)?;
+        if count < page_size || i64::from(offset) >= total {
+            break;
+        }
+    }
+    Ok(total)
+}
+
+/// Display row for a ping (also the json/yaml/ndjson schema of `check logs`)
+fn ping_row(ping: &PingLog, with_body: bool) -> PingRow {
+    PingRow {
+        id: ping.id,
+        time: format_relative_time(Some(ping.created_at)),
+        ping_type: format_ping_type(&ping.ping_type),
+        duration: ping
+            .duration_ms
+            .map(|d| format!("{}ms", d))
+            .unwrap_or_else(|| "-".to_string()),
+        run: ping.run_id.clone().unwrap_or_else(|| "-".to_string()),
+        source: ping.source_ip.clone().unwrap_or_else(|| "-".to_string()),
+        body: if with_body { ping.body.clone() } else { None },
+    }
+}
+
+fn has_body(ping: &PingLog) -> bool {
+    ping.body.as_deref().is_some_and(|b| !b.is_empty())
+}
+
+fn print_body(body: &str) {
+    if body.ends_with('\n') {
+        print!("{}", body);
+    } else {
+        println!("{}", body);
+    }
+}
+
+/// How many pings to ask for next; 0 when `limit` has been reached
+fn next_page_size(limit: i32, fetched: i32) -> i32 {
+    if limit == 0 {
+        PAGE_SIZE
+    } else {
+        (limit - fetched).clamp(0, PAGE_SIZE)
+    }
+}
+
+/// Query string for the ping filters (starting with '&', or empty)
+pub(super) fn build_query(filter: &LogFilter, now: DateTime<Utc>) -> Result<String> {
+    let mut query = String::new();
+
+    let since = filter
+        .since
+        .as_deref()
+        .map(|s| parse_time_bound("--since", s, now))
+        .transpose()?;
+    let until = filter
+        .until
+        .as_deref()
+        .map(|s| parse_time_bound("--until", s, now))
+        .transpose()?;
+    if let (Some(since), Some(until)) = (since, until) {
+        if since >= until {
+            return Err(CliError::Other("--since must be before --until".to_string()).into());
+        }
+    }
+    for (name, bound) in [("since", since), ("until", until)] {
+        if let Some(bound) = bound {
+            query.push_str(&format!(
+                "&{}={}",
+                name,
+                bound.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ));
+        }
+    }
+
+    if let Some(types) = &filter.types {
+        let types = parse_types(types)?;
+        query.push_str(&format!("&type={}", types.join(",")));
+    }
+    if let Some(run) = &filter.run {
+        query.push_str(&format!("&run_id={}", urlencoding::encode(run)));
+    }
+    if filter.show_body {
+        query.push_str("&include_body=true");
+    }
+    Ok(query)
+}
+
+/// Parse a relative duration ("30m" ago) or an RFC 3339 timestamp
+pub(super) fn parse_time_bound(
+    flag: &str,
+    value: &str,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+    let seconds = parse_duration(value).map_err(|_| {
+        CliError::Other(format!(
+            "Invalid {} '{}'. Use a duration (e.g., 30m, 2d) or an RFC 3339 timestamp",
+            flag, value
+        ))
+    })?;
+    Ok(now - chrono::Duration::seconds(i64::from(seconds)))
+}
+
+/// Split and validate --type
+fn parse_types(types: &str) -> Result<Vec<String>> {
+    let types: Vec<String> = types
+        .split(',')
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if types.is_empty() {
+        return Err(CliError::Other("--type needs at least one ping type".to_string()).into());
+    }
+    if let Some(unknown) = types.iter().find(|t| !PING_TYPES.contains(&t.as_str())) {
+        return Err(CliError::Other(format!(
+            "Unknown ping type '{}'. Valid types: {}",
+            unknown,
+            PING_TYPES.join(", ")
+        ))
+        .into());
+    }
+    Ok(types)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-18T12:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn test_next_page_size() {
+        assert_eq!(next_page_size(50, 0), 50);
+        assert_eq!(next_page_size(250, 200), 50);
+        assert_eq!(next_page_size(250, 0), PAGE_SIZE);
+        assert_eq!(next_page_size(50, 50), 0);
+        assert_eq!(next_page_size(0, 10_000), PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_ping_row_schema() {
+        let ping: PingLog = serde_json::from_value(serde_json::json!({
+            "id": 12,
+            "type": "success",
+            "created_at": "2026-10-18T11:00:00Z",
+            "duration_ms": 250,
+            "source_ip": "10.0.0.1",
+            "run_id": "nightly-1",
+            "body": "done",
+        }))
+        .unwrap();
+
+        let row = serde_json::to_value(ping_row(&ping, false)).unwrap();
+        let keys: Vec<&str> = row
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            keys,
+            ["duration", "id", "ping_type", "run", "source", "time"]
+        );
+        assert_eq!(row["run"], "nightly-1");
+
+        let with_body = serde_json::to_value(ping_row(&ping, true)).unwrap();
+        assert_eq!(with_body["body"], "done");
+    }
+
+    #[test]
+    fn test_build_query() {
+        assert_eq!(build_query(&LogFilter::default(), now()).unwrap(), "");
+
+        let filter = LogFilter {
+            since: Some("2h".to_string()),
+            until: Some("2026-10-18T13:30:00+02:00".to_string()),
+            types: Some("Fail, start".to_string()),
+            run: Some("nightly 42".to_string()),
+            show_body: true,
+        };
+        assert_eq!(
+            build_query(&filter, now()).unwrap(),
+            "&since=2026-10-18T10:00:00Z&until=2026-10-18T11:30:00Z\
+             &type=fail,start&run_id=nightly%2042&include_body=true"
+        );
+    }
+
+    #[test]
+    fn test_build_query_errors() {
+        let inverted = LogFilter {
+            since: Some("1h".to_string()),
+            until: Some("2h".to_string()),
+            ..Default::default()
+        };
+        assert!(build_query(&inverted, now()).is_err());
+
+        let bad_type = LogFilter {
+            types: Some("success,ok".to_string()),
+            ..Default::default()
+        };
+        assert!(build_query(&bad_type, now()).is_err());
+
+        let bad_since = LogFilter {
+            since: Some("yesterday".to_string()),
+            ..Default::default()
+        };
+        assert!(build_query(&bad_since, now()).is_err());
+    }
+}
@@ -0,0 +1,371 @@
+//! Maintenance windows: silence alerts for checks during planned downtime.
+
+use crate::cli::{CheckSelector, MaintenanceCommands, OutputFormat};
+use crate::client::ApiClient;
+use crate::config::Context;
+use crate::cron::effective_period_from_cron;
+use crate::error::CliError;
+use crate::output::{
+    OutputConfig, format_timestamp, print_info, print_output, print_single, print_success,
+};
+use anyhow::Result;
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::bulk::{self, BulkOp, maintenance_url};
+use super::helpers::{
+    format_duration, parse_duration, resolve_check_by_org, validate_cron_cli, validate_timezone,
+};
+use super::types::{MaintenanceRequest, MaintenanceWindow};
+
+#[derive(Debug, Tabled, Serialize)]
+struct MaintenanceRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "CHECK")]
+    check: String,
+    #[tabled(rename = "STATE")]
+    state: String,
+    #[tabled(rename = "WINDOW")]
+    window: String,
+    #[tabled(rename = "REASON")]
+    reason: String,
+}
+
+/// Handle maintenance subcommands
+pub async fn handle(ctx: &Context, command: MaintenanceCommands, verbose: bool) -> Result<()> {
+    match command {
+        MaintenanceCommands::Start {
+            slug,
+            selector,
+            duration,
+            reason,
+            yes,
+        } => {
+            let req = MaintenanceRequest {
+                reason,
+                starts_at: None,
+                duration_seconds: parse_window_duration(&duration)?,
+                cron_expression: None,
+                timezone: None,
+            };
+            run(
+                ctx,
+                BulkOp::StartMaintenance(req),
+                slug,
+                &selector,
+                yes,
+                verbose,
+            )
+            .await
+        }
+        MaintenanceCommands::Stop {
+            slug,
+            selector,
+            all,
+            yes,
+        } => {
+            run(
+                ctx,
+                BulkOp::StopMaintenance { all },
+                slug,
+                &selector,
+                yes,
+                verbose,
+            )
+            .await
+        }
+        MaintenanceCommands::Schedule {
+            slug,
+            selector,
+            cron,
+            at,
+            duration,
+            tz,
+            reason,
+            yes,
+        } => {
+            let req = schedule_request(cron, at.as_deref(), &duration, tz, reason, Utc::now())?;
+            run(
+                ctx,
+                BulkOp::ScheduleMaintenance(req),
+                slug,
+                &selector,
+                yes,
+                verbose,
+            )
+            .await
+        }
+        MaintenanceCommands::List { check } => list(ctx, check.as_deref(), verbose).await,
+        MaintenanceCommands::Cancel { id } => {
+            let client = ApiClient::new(ctx)?;
+            client
+                .delete(&format!("/api/v1/maintenance/{}", id))
+                .await?;
+            print_success(&format!("Cancelled maintenance window {}", id));
+            Ok(())
+        }
+    }
+}
+
+/// One check goes straight to the API; selectors and stdin go through the bulk runner
+async fn run(
+    ctx: &Context,
+    op: BulkOp,
+    slug: Option<String>,
+    selector: &CheckSelector,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    match slug {
+        Some(slug) if !bulk::is_bulk(Some(&slug), selector) => {
+            single(ctx, op, &slug, verbose).await
+        }
+        slug => bulk::run(ctx, op, slug.as_deref(), selector, yes, verbose).await,
+    }
+}
+
+async fn single(ctx: &Context, op: BulkOp, slug_or_id: &str, verbose: bool) -> Result<()> {
+    let org_id = ctx.require_org()?;
+    let check = resolve_check_by_org(ctx, org_id, slug_or_id).await?;
+    let client = ApiClient::new(ctx)?;
+    let url = format!("/api/v1/checks/{}", check.id);
+
+    let req = match op {
+        BulkOp::StartMaintenance(req) | BulkOp::ScheduleMaintenance(req) => req,
+        BulkOp::StopMaintenance { all } => {
+            client.delete(&maintenance_url(&url, all)).await?;
+            print_success(&format!("Stopped maintenance for check: {}", check.name));
+            return Ok(());
+        }
+        _ => unreachable!("only maintenance operations are dispatched here"),
+    };
+
+    if verbose {
+        eprintln!(
+            "[verbose] Creating maintenance window for check: {}",
+            check.id
+        );
+    }
+    let window: MaintenanceWindow = client.post(&format!("{}/maintenance", url), &req).await?;
+
+    if ctx.output_format() != OutputFormat::Table {
+        return print_single(ctx, &window);
+    }
+    let config = OutputConfig::from_context(ctx);
+    let message = match window.ends_at {
+        _ if !window.active => format!(
+            "Scheduled maintenance for {}: {}",
+            check.name,
+            describe(&window, &config)
+        ),
+        Some(ends) => format!(
+            "{} is in maintenance until {}",
+            check.name,
+            format_timestamp(ends, &config)
+        ),
+        None => format!("{} is in maintenance", check.name),
+    };
+    print_success(&message);
+    Ok(())
+}
+
+/// List active and upcoming windows in the organization (or for one check)
+async fn list(ctx: &Context, check: Option<&str>, verbose: bool) -> Result<()> {
+    let org_id = ctx.require_org()?;
+    let client = ApiClient::new(ctx)?;
+    let mut url = format!("/api/v1/maintenance?org_id={}", org_id);
+    if let Some(check) = check {
+        let check = resolve_check_by_org(ctx, org_id, check).await?;
+        url.push_str(&format!("&check_id={}", check.id));
+    }
+    if verbose {
+        eprintln!("[verbose] Fetching maintenance windows from: {}", url);
+    }
+    let windows: Vec<MaintenanceWindow> = client.get(&url).await?;
+
+    if windows.is_empty() && ctx.output_format() == OutputFormat::Table {
+        print_info("No active or scheduled maintenance windows");
+        return Ok(());
+    }
+
+    let config = OutputConfig::from_context(ctx);
+    let rows: Vec<MaintenanceRow> = windows
+        .iter()
+        .map(|w| MaintenanceRow {
+            id: w.id.to_string(),
+            check: w.check_slug.clone(),
+            state: if w.active { "active" } else { "scheduled" }.to_string(),
+            window: describe(w, &config),
+            reason: w.reason.clone().unwrap_or_default(),
+        })
+        .collect();
+    print_output(ctx, rows)
+}
+
+/// When a window runs, e.g. "until 2026-10-18 14:00:00 UTC" or "0 2 * * SUN (Europe/Riga) for 2h"
+fn describe(window: &MaintenanceWindow, config: &OutputConfig) -> String {
+    let duration = format_duration(window.duration_seconds);
+    match (&window.cron_expression, window.starts_at, window.ends_at) {
+        (Some(cron), _, _) => match &window.timezone {
+            Some(tz) => format!("{} ({}) for {}", cron, tz, duration),
+            None => format!("{} for {}", cron, duration),
+        },
+        (None, _, Some(ends)) if window.active => {
+            format!("until {}", format_timestamp(ends, config))
+        }
+        (None, Some(starts), _) => {
+            format!("{} for {}", format_timestamp(starts, config), duration)
+        }
+        (None, None, _) => format!("for {}", duration),
+    }
+}
+
+fn parse_window_duration(duration: &str) -> Result<i32> {
+    let seconds = parse_duration(duration)?;
+    if seconds <= 0 {
+        return Err(CliError::Other("Maintenance duration must be positive".to_string()).into());
+    }
+    Ok(seconds)
+}
+
+/// Build the request for `maintenance schedule` from --cron or --at
+fn schedule_request(
+    cron: Option<String>,
+    at: Option<&str>,
+    duration: &str,
+    tz: Option<String>,
+    reason: Option<String>,
+    now: DateTime<Utc>,
+) -> Result<MaintenanceRequest> {
+    let duration_seconds = parse_window_duration(duration)?;
+    let parsed_tz = tz.as_deref().map(validate_timezone).transpose()?;
+
+    let starts_at = match at {
+        Some(at) => {
+            let starts = parse_at(at, parsed_tz)?;
+            if starts <= now {
+                return Err(CliError::Other(format!(
+                    "--at {} is in the past; use 'maintenance start' for a window that starts now",
+                    at
+                ))
+                .into());
+            }
+            Some(starts)
+        }
+        None => None,
+    };
+    if let Some(cron) = &cron {
+        validate_cron_cli(cron)?;
+        // Windows that last as long as the gap between starts would never end
+        if effective_period_from_cron(cron).is_some_and(|period| duration_seconds >= period) {
+            return Err(CliError::Other(format!(
+                "Windows of {} would overlap with '{}'; use a shorter --for",
+                format_duration(duration_seconds),
+                cron
+            ))
+            .into());
+        }
+    }
+
+    Ok(MaintenanceRequest {
+        reason,
+        starts_at,
+        duration_seconds,
+        cron_expression: cron,
+        timezone: tz,
+    })
+}
+
+/// Parse --at as RFC 3339, or as a local date and time in `tz` (or this machine's timezone)
+fn parse_at(at: &str, tz: Option<chrono_tz::Tz>) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(at) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+    let naive = ["%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(at, format).ok())
+        .ok_or_else(|| {
+            CliError::Other(format!(
+                "Invalid --at '{}'. Use RFC 3339 or 'YYYY-MM-DD HH:MM'",
+                at
+            ))
+        })?;
+    let starts = match tz {
+        Some(tz) => tz
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc)),
+        None => Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc)),
+    };
+    starts.ok_or_else(|| {
+        CliError::Other(format!("--at '{}' doesn't exist in that timezone", at)).into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_at() {
+        let riga = Some(chrono_tz::Europe::Riga);
+        assert_eq!(
+            parse_at("2026-10-20T02:00:00Z", None).unwrap().to_rfc3339(),
+            "2026-10-20T02:00:00+00:00"
+        );
+        // Riga is UTC+3 in October
+        assert_eq!(
+            parse_at("2026-10-20 05:00", riga).unwrap().to_rfc3339(),
+            "2026-10-20T02:00:00+00:00"
+        );
+        assert!(parse_at("next tuesday", riga).is_err());
+    }
+
+    #[test]
+    fn test_schedule_request() {
+        let now = "2026-10-18T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let weekly = schedule_request(
+            Some("0 2 * * SUN".to_string()),
+            None,
+            "2h",
+            Some("Europe/Riga".to_string()),
+            Some("db upgrade".to_string()),
+            now,
+        )
+        .unwrap();
+        assert_eq!(weekly.duration_seconds, 7200);
+        assert_eq!(weekly.cron_expression.as_deref(), Some("0 2 * * SUN"));
+        assert!(weekly.starts_at.is_none());
+
+        let once =
+            schedule_request(None, Some("2026-10-20T02:00:00Z"), "30m", None, None, now).unwrap();
+        assert_eq!(once.duration_seconds, 1800);
+        assert!(once.starts_at.is_some());
+
+        // In the past
+        assert!(
+            schedule_request(None, Some("2026-10-01T02:00:00Z"), "30m", None, None, now).is_err()
+        );
+        // Hourly windows that last two hours
+        assert!(
+            schedule_request(Some("0 * * * *".to_string()), None, "2h", None, None, now).is_err()
+        );
+        assert!(
+            schedule_request(
+                Some("0 2 * * SUN".to_string()),
+                None,
+                "2h",
+                Some("Mars/Base".to_string()),
+                None,
+                now
+            )
+            .is_err()
+        );
+    }
+}
@@ -2,22 +2,39 @@
 //!
 //! This module provides commands for creating, listing, updating, and monitoring checks.
 
+mod alerts;
+mod bulk;
 mod create;
 mod crud;
 mod doctor;
 mod helpers;
+mod hooks;
 mod inspect;
+mod logs;
+mod maintenance;
+mod report;
+mod runs;
 mod tail;
 mod types;
 mod update;
 
-// Re-export public API used by other modules (ping.rs, monitor.rs)
-pub use helpers::{resolve_public_id, resolve_public_id_smart, resolve_public_id_verbose};
-pub use types::{Check, CheckWithProject};
+// Re-export public API used by other modules (ping.rs, monitor.rs, import, manifest)
+pub use create::create_check;
+pub use helpers::{
+    format_duration, parse_duration, resolve_check_meta, resolve_public_id,
+    resolve_public_id_smart, resolve_public_id_verbose, slug_to_title, smart_grace,
+    validate_cron_cli, validate_slug, validate_timezone,
+};
+pub use maintenance::handle as handle_maintenance;
+pub use report::report;
+pub use tail::tail_many;
+pub use types::{AlertSettings, Check, CheckWithProject, CreateCheckRequest, UpdateCheckRequest};
 
 use crate::cli::CheckCommands;
 use crate::config::Context;
+use crate::error::CliError;
 use anyhow::Result;
+use bulk::BulkOp;
 
 /// Handle check subcommands
 pub async fn handle(ctx: &Context, command: CheckCommands, verbose: bool) -> Result<()> {
@@ -42,6 +59,7 @@ pub async fn handle(ctx: &Context, command: CheckCommands, verbose: bool) -> Res
             alert_after_miss_pings,
             alert_after_fail_pings,
             max_runtime,
+            alerts,
             json,
             quiet,
             dry_run,
@@ -60,6 +78,7 @@ pub async fn handle(ctx: &Context, command: CheckCommands, verbose: bool) -> Res
                 alert_after_miss_pings,
                 alert_after_fail_pings,
                 max_runtime,
+                alerts.into(),
                 json,
                 quiet,
                 dry_run,
@@ -69,13 +88,150 @@ pub async fn handle(ctx: &Context, command: CheckCommands, verbose: bool) -> Res
             .await
         }
         CheckCommands::Show { slug } => crud::show(ctx, &slug, verbose).await,
-        CheckCommands::Pause { slug } => crud::pause(ctx, &slug, verbose).await,
-        CheckCommands::Resume { slug } => crud::resume(ctx, &slug, verbose).await,
-        CheckCommands::Delete { slug, yes } => crud::delete(ctx, &slug, yes, verbose).await,
-        CheckCommands::Logs { slug, limit } => crud::logs(ctx, &slug, limit, verbose).await,
+        CheckCommands::Pause {
+            slug,
+            selector,
+            yes,
+        } => match slug {
+            Some(slug) if !bulk::is_bulk(Some(&slug), &selector) => {
+                crud::pause(ctx, &slug, verbose).await
+            }
+            slug => bulk::run(ctx, BulkOp::Pause, slug.as_deref(), &selector, yes, verbose).await,
+        },
+        CheckCommands::Resume {
+            slug,
+            selector,
+            yes,
+        } => match slug {
+            Some(slug) if !bulk::is_bulk(Some(&slug), &selector) => {
+                crud::resume(ctx, &slug, verbose).await
+            }
+            slug => {
+                bulk::run(
+                    ctx,
+                    BulkOp::Resume,
+                    slug.as_deref(),
+                    &selector,
+                    yes,
+                    verbose,
+                )
+                .await
+            }
+        },
+        CheckCommands::Delete {
+            slug,
+            selector,
+            yes,
+        } => match slug {
+            Some(slug) if !bulk::is_bulk(Some(&slug), &selector) => {
+                crud::delete(ctx, &slug, yes, verbose).await
+            }
+            slug => {
+                bulk::run(
+                    ctx,
+                    BulkOp::Delete,
+                    slug.as_deref(),
+                    &selector,
+                    yes,
+                    verbose,
+                )
+                .await
+            }
+        },
+        CheckCommands::Logs {
+            slug,
+            limit,
+            since,
+            until,
+            types,
+            run,
+            show_body,
+        } => {
+            let filter = logs::LogFilter {
+                since,
+                until,
+                types,
+                run,
+                show_body,
+            };
+            logs::logs(ctx, &slug, limit, &filter, verbose).await
+        }
+        CheckCommands::Runs {
+            slug,
+            since,
+            failed,
+            slower_than,
+            limit,
+        } => {
+            runs::runs(
+                ctx,
+                &slug,
+                &since,
+                failed,
+                slower_than.as_deref(),
+                limit,
+                verbose,
+            )
+            .await
+        }
+        CheckCommands::LogBody { slug, ping_id } => {
+            logs::log_body(ctx, &slug, ping_id, verbose).await
+        }
         CheckCommands::Sync => crud::sync(ctx, verbose).await,
         CheckCommands::Update {
             slug,
+            selector,
+            name,
+            description,
+            cron,
+            tz,
+            every,
+            missing_after,
+            tags,
+            alert_after_miss_pings,
+            alert_after_fail_pings,
+            max_runtime,
+            alerts,
+            yes,
+        } if bulk::is_bulk(slug.as_deref(), &selector) => {
+            if name.is_some() {
+                return Err(CliError::Other(
+                    "--name can't be set on several checks at once".to_string(),
+                )
+                .into());
+            }
+            let req = update::build_update_request_from_options(
+                name,
+                description,
+                cron,
+                tz,
+                every,
+                missing_after,
+                tags,
+                alert_after_miss_pings,
+                alert_after_fail_pings,
+                max_runtime,
+                alerts.into(),
+            )?;
+            if update::is_empty_request(&req) {
+                return Err(CliError::Other(
+                    "Bulk update needs at least one option to change".to_string(),
+                )
+                .into());
+            }
+            bulk::run(
+                ctx,
+                BulkOp::Update(req),
+                slug.as_deref(),
+                &selector,
+                yes,
+                verbose,
+            )
+            .await
+        }
+        CheckCommands::Update {
+            slug,
+            selector: _,
             name,
             description,
             cron,
@@ -86,11 +242,12 @@ pub async fn handle(ctx: &Context, command: CheckCommands, verbose: bool) -> Res
             alert_after_miss_pings,
             alert_after_fail_pings,
             max_runtime,
+            alerts,
             yes,
         } => {
             update::update(
                 ctx,
-                &slug,
+                slug.as_deref().unwrap_or_default(),
                 name,
                 description,
                 cron,
@@ -101,11 +258,28 @@ pub async fn handle(ctx: &Context, command: CheckCommands, verbose: bool) -> Res
                 alert_after_miss_pings,
                 alert_after_fail_pings,
                 max_runtime,
+                alerts.into(),
                 yes,
                 verbose,
             )
             .await
         }
+        CheckCommands::Alerts {
+            slug,
+            add_channel,
+            remove_channel,
+            toggles,
+        } => {
+            alerts::alerts(
+                ctx,
+                &slug,
+                &add_channel,
+                &remove_channel,
+                toggles.into(),
+                verbose,
+            )
+            .await
+        }
         CheckCommands::Inspect { slug } => inspect::inspect(ctx, &slug, verbose).await,
         CheckCommands::Doctor {
             slug,
@@ -118,6 +292,20 @@ pub async fn handle(ctx: &Context, command: CheckCommands, verbose: bool) -> Res
             types,
             follow,
             limit,
-        } => tail::tail(ctx, &slug, &since, types.as_deref(), follow, limit, verbose).await,
+            hooks,
+        } => {
+            let hooks = hooks::EventHooks::parse(&hooks)?;
+            tail::tail(
+                ctx,
+                &slug,
+                &since,
+                types.as_deref(),
+                follow,
+                limit,
+                &hooks,
+                verbose,
+            )
+            .await
+        }
     }
 }
@@ -0,0 +1,543 @@
+//! Uptime and reliability report over a time range, from ping history and the events timeline.
+
+use crate::cli::{OutputFormat, ReportArgs, ReportExport};
+use crate::client::ApiClient;
+use crate::config::Context;
+use crate::cron::next_cron_times_in_tz_after;
+use crate::output::{print_error, print_ndjson_stream, print_output, print_single, print_warning};
+use anyhow::Result;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use futures_util::stream::{self, StreamExt};
+use serde::Serialize;
+use tabled::Tabled;
+
+use super::bulk::{fetch_checks, matches_selector};
+use super::helpers::format_duration;
+use super::logs::{LogFilter, build_query, fetch_pings, parse_time_bound};
+use super::runs::{Outcome, Run, expected_runtime, format_run_duration, pair_runs};
+use super::tail::{EventItem, fetch_events};
+use super::types::{Check, CheckWithProject};
+
+/// Statuses that count as an outage
+const OUTAGE_STATUSES: [&str; 2] = ["down", "missing"];
+
+/// Upper bound on cron occurrences counted per check (a minutely job over 60 days)
+const MAX_EXPECTED_RUNS: usize = 86_400;
+
+/// Reliability figures for one check
+#[derive(Debug, Serialize)]
+struct CheckReport {
+    project: String,
+    slug: String,
+    name: String,
+    uptime_percent: f64,
+    /// Successful share of completed runs (None without completed runs)
+    success_rate_percent: Option<f64>,
+    runs: usize,
+    failures: usize,
+    missed: usize,
+    mean_duration_ms: Option<i64>,
+    p95_duration_ms: Option<i64>,
+    longest_outage_seconds: Option<i64>,
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct ReportRow {
+    #[tabled(rename = "CHECK")]
+    check: String,
+    #[tabled(rename = "PROJECT")]
+    project: String,
+    #[tabled(rename = "UPTIME")]
+    uptime: String,
+    #[tabled(rename = "SUCCESS")]
+    success_rate: String,
+    #[tabled(rename = "RUNS")]
+    runs: usize,
+    #[tabled(rename = "FAILED")]
+    failures: usize,
+    #[tabled(rename = "MISSED")]
+    missed: usize,
+    #[tabled(rename = "MEAN")]
+    mean: String,
+    #[tabled(rename = "P95")]
+    p95: String,
+    #[tabled(rename = "LONGEST OUTAGE")]
+    longest_outage: String,
+}
+
+impl From<&CheckReport> for ReportRow {
+    fn from(r: &CheckReport) -> Self {
+        Self {
+            check: r.slug.clone(),
+            project: r.project.clone(),
+            uptime: format_percent(Some(r.uptime_percent)),
+            success_rate: format_percent(r.success_rate_percent),
+            runs: r.runs,
+            failures: r.failures,
+            missed: r.missed,
+            mean: r
+                .mean_duration_ms
+                .map(format_run_duration)
+                .unwrap_or_else(|| "-".to_string()),
+            p95: r
+                .p95_duration_ms
+                .map(format_run_duration)
+                .unwrap_or_else(|| "-".to_string()),
+            longest_outage: format_outage(r.longest_outage_seconds),
+        }
+    }
+}
+
+/// Build the report for the selected checks
+pub async fn report(ctx: &Context, args: ReportArgs, verbose: bool) -> Result<()> {
+    let now = Utc::now();
+    // One resolved start time, so every check covers the same period
+    let since = parse_time_bound("--since", &args.since, now)?;
+    let filter = LogFilter {
+        since: Some(since.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        ..Default::default()
+    };
+    let query = build_query(&filter, now)?;
+
+    let checks: Vec<CheckWithProject> =
+        fetch_checks(ctx, args.selector.project.as_deref(), verbose)
+            .await?
+            .into_iter()
+            .filter(|c| matches_selector(c, &args.selector, None))
+            .collect();
+    if checks.is_empty() {
+        print_warning("No checks match the selection");
+        return Ok(());
+    }
+
+    let org_tz = ctx
+        .config
+        .active_org_timezone
+        .as_deref()
+        .and_then(|tz| tz.parse().ok())
+        .unwrap_or(chrono_tz::UTC);
+    let client = ApiClient::new(ctx)?;
+    let results: Vec<(CheckWithProject, Result<CheckReport>)> = stream::iter(checks)
+        .map(|c| {
+            let (client, query) = (&client, &query);
+            async move {
+                let result = check_report(client, &c, query, since, now, org_tz, verbose).await;
+                (c, result)
+            }
+        })
+        .buffered(args.selector.concurrency as usize)
+        .collect()
+        .await;
+
+    let mut reports = Vec::new();
+    for (c, result) in results {
+        match result {
+            Ok(report) => reports.push(report),
+            // stderr, so a partial export on stdout stays parseable
+            Err(e) => print_error(&format!("Skipped {}: {}", c.check.slug, e)),
+        }
+    }
+
+    match args.export {
+        Some(ReportExport::Csv) => print!("{}", to_csv(&reports)),
+        Some(ReportExport::Markdown) => {
+            print!("{}", to_markdown(&reports, &args.since, since, now))
+        }
+        Some(ReportExport::Html) => print!("{}", to_html(&reports, &args.since, since, now)),
+        None => match ctx.output_format() {
+            OutputFormat::Table => {
+                println!(
+                    "Report for the last {} ({} to {})\n",
+                    args.since,
+                    since.format("%Y-%m-%d %H:%M UTC"),
+                    now.format("%Y-%m-%d %H:%M UTC")
+                );
+                print_output(ctx, reports.iter().map(ReportRow::from).collect())?;
+            }
+            OutputFormat::Ndjson => print_ndjson_stream(&reports)?,
+            OutputFormat::Json | OutputFormat::Yaml => print_single(ctx, &reports)?,
+        },
+    }
+    Ok(())
+}
+
+/// Fetch one check's pings and state changes and compute its figures
+async fn check_report(
+    client: &ApiClient,
+    c: &CheckWithProject,
+    query: &str,
+    since: DateTime<Utc>,
+    now: DateTime<Utc>,
+    org_tz: chrono_tz::Tz,
+    verbose: bool,
+) -> Result<CheckReport> {
+    let check = &c.check;
+    // Checks created during the period are measured from their creation
+    let from = since.max(check.created_at);
+
+    let mut pings = Vec::new();
+    fetch_pings(client, check, 0, query, verbose, |page| {
+        pings.extend(page);
+        Ok(())
+    })
+    .await?;
+    let events = fetch_events(
+        client,
+        check.id,
+        &from.to_rfc3339_opts(SecondsFormat::Secs, true),
+        Some("state"),
+        verbose,
+    )
+    .await?;
+
+    let runs = pair_runs(pings, expected_runtime(check), now);
+    let tz = check
+        .timezone
+        .as_deref()
+        .and_then(|tz| tz.parse().ok())
+        .unwrap_or(org_tz);
+    let expected = expected_runs(check, tz, from, now);
+    let (downtime, longest) = outages(&events, from, now);
+    let (mean, p95) = duration_stats(&runs);
+
+    let failures = runs.iter().filter(|r| r.outcome == Outcome::Fail).count();
+    let completed = runs
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Success | Outcome::Fail))
+        .count();
+    let window = (now - from).num_seconds().max(1) as f64;
+    let uptime = 100.0 * (1.0 - downtime.num_seconds() as f64 / window);
+
+    Ok(CheckReport {
+        project: c.project_name.clone(),
+        slug: check.slug.clone(),
+        name: check.name.clone(),
+        uptime_percent: uptime.clamp(0.0, 100.0),
+        success_rate_percent: (completed > 0)
+            .then(|| 100.0 * (completed - failures) as f64 / completed as f64),
+        runs: runs.len(),
+        failures,
+        missed: expected.saturating_sub(runs.len()),
+        mean_duration_ms: mean,
+        p95_duration_ms: p95,
+        longest_outage_seconds: longest.map(|d| d.num_seconds()),
+    })
+}
+
+/// How many runs the schedule called for between `from` and `to`
+fn expected_runs(
+    check: &Check,
+    tz: chrono_tz::Tz,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> usize {
+    match check.cron_expression.as_deref().filter(|c| !c.is_empty()) {
+        Some(cron) => next_cron_times_in_tz_after(cron, tz, from, MAX_EXPECTED_RUNS)
+            .into_iter()
+            .take_while(|t| *t <= to)
+            .count(),
+        None if check.period_seconds > 0 => {
+            ((to - from).num_seconds() / i64::from(check.period_seconds)) as usize
+        }
+        None => 0,
+    }
+}
+
+/// Total downtime and longest outage, from status changes (oldest first)
+fn outages(
+    events: &[EventItem],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> (Duration, Option<Duration>) {
+    let is_outage = |s: &Option<String>| s.as_deref().is_some_and(|s| OUTAGE_STATUSES.contains(&s));
+    let changes: Vec<&EventItem> = events
+        .iter()
+        .filter(|e| e.event_type == "status_changed")
+        .collect();
+
+    // An outage that started before the period shows up as a first change away from it
+    let mut down_since = changes
+        .first()
+        .filter(|e| is_outage(&e.from_status))
+        .map(|_| from);
+    let mut total = Duration::zero();
+    let mut longest: Option<Duration> = None;
+    let mut close = |start: DateTime<Utc>, end: DateTime<Utc>| {
+        let length = end - start.max(from);
+        total += length;
+        longest = Some(longest.map_or(length, |l| l.max(length)));
+    };
+
+    for event in changes {
+        match (down_since, is_outage(&event.to_status)) {
+            (None, true) => down_since = Some(event.occurred_at),
+            (Some(start), false) => {
+                close(start, event.occurred_at);
+                down_since = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = down_since {
+        close(start, to);
+    }
+    (total, longest)
+}
+
+/// Mean and 95th percentile (nearest rank) of completed run durations
+fn duration_stats(runs: &[Run]) -> (Option<i64>, Option<i64>) {
+    let mut durations: Vec<i64> = runs
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Success | Outcome::Fail))
+        .filter_map(|r| r.duration_ms)
+        .collect();
+    if durations.is_empty() {
+        return (None, None);
+    }
+    durations.sort_unstable();
+    let mean = durations.iter().sum::<i64>() / durations.len() as i64;
+    let rank = (durations.len() * 95).div_ceil(100);
+    (Some(mean), Some(durations[rank.saturating_sub(1)]))
+}
+
+fn format_percent(value: Option<f64>) -> String {
+    value
+        .map(|v| format!("{:.2}%", v))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_outage(seconds: Option<i64>) -> String {
+    match seconds {
+        Some(s) => format_duration(s.min(i64::from(i32::MAX)) as i32),
+        None => "none".to_string(),
+    }
+}
+
+fn to_csv(reports: &[CheckReport]) -> String {
+    fn field<T: ToString>(value: Option<T>) -> String {
+        value.map(|v| v.to_string()).unwrap_or_default()
+    }
+    fn quote(s: &str) -> String {
+        if s.contains([',', '"', '\n']) {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        } else {
+            s.to_string()
+        }
+    }
+
+    let mut out = String::from(
+        "project,slug,name,uptime_percent,success_rate_percent,runs,failures,missed,\
+         mean_duration_ms,p95_duration_ms,longest_outage_seconds\n",
+    );
+    for r in reports {
+        out.push_str(&format!(
+            "{},{},{},{:.2},{},{},{},{},{},{},{}\n",
+            quote(&r.project),
+            quote(&r.slug),
+            quote(&r.name),
+            r.uptime_percent,
+            field(r.success_rate_percent.map(|v| format!("{:.2}", v))),
+            r.runs,
+            r.failures,
+            r.missed,
+            field(r.mean_duration_ms),
+            field(r.p95_duration_ms),
+            field(r.longest_outage_seconds),
+        ));
+    }
+    out
+}
+
+const SUMMARY_HEADERS: [&str; 10] = [
+    "Check",
+    "Project",
+    "Uptime",
+    "Success",
+    "Runs",
+    "Failed",
+    "Missed",
+    "Mean",
+    "p95",
+    "Longest outage",
+];
+
+fn summary_cells(row: ReportRow) -> [String; 10] {
+    [
+        row.check,
+        row.project,
+        row.uptime,
+        row.success_rate,
+        row.runs.to_string(),
+        row.failures.to_string(),
+        row.missed.to_string(),
+        row.mean,
+        row.p95,
+        row.longest_outage,
+    ]
+}
+
+fn period_line(label: &str, since: DateTime<Utc>, now: DateTime<Utc>, checks: usize) -> String {
+    format!(
+        "Last {}: {} to {}, {} check(s)",
+        label,
+        since.format("%Y-%m-%d"),
+        now.format("%Y-%m-%d"),
+        checks
+    )
+}
+
+fn to_markdown(
+    reports: &[CheckReport],
+    label: &str,
+    since: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> String {
+    let mut out = format!(
+        "## Check reliability\n\n{}\n\n| {} |\n|{}\n",
+        period_line(label, since, now, reports.len()),
+        SUMMARY_HEADERS.join(" | "),
+        "---|".repeat(SUMMARY_HEADERS.len())
+    );
+    for r in reports {
+        let cells = summary_cells(ReportRow::from(r)).map(|c| c.replace('|', "\\|"));
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    out
+}
+
+fn to_html(
+    reports: &[CheckReport],
+    label: &str,
+    since: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> String {
+    fn escape(s: &str) -> String {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    let mut out = format!(
+        "<h2>Check reliability</h2>\n<p>{}</p>\n<table>\n  <thead>\n    <tr>",
+        escape(&period_line(label, since, now, reports.len()))
+    );
+    for header in SUMMARY_HEADERS {
+        out.push_str(&format!("<th>{}</th>", header));
+    }
+    out.push_str("</tr>\n  </thead>\n  <tbody>\n");
+    for r in reports {
+        out.push_str("    <tr>");
+        for cell in summary_cells(ReportRow::from(r)) {
+            out.push_str(&format!("<td>{}</td>", escape(&cell)));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("  </tbody>\n</table>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn change(time: &str, from: &str, to: &str) -> EventItem {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "event_type": "status_changed",
+            "occurred_at": time,
+            "source": "scheduler",
+            "payload": {},
+            "from_status": from,
+            "to_status": to,
+            "summary": format!("{} -> {}", from, to),
+        }))
+        .unwrap()
+    }
+
+    fn report(slug: &str) -> CheckReport {
+        CheckReport {
+            project: "infra".to_string(),
+            slug: slug.to_string(),
+            name: "Nightly, \"full\" backup".to_string(),
+            uptime_percent: 99.5,
+            success_rate_percent: Some(96.666),
+            runs: 30,
+            failures: 1,
+            missed: 0,
+            mean_duration_ms: Some(61_000),
+            p95_duration_ms: Some(95_000),
+            longest_outage_seconds: Some(3600),
+        }
+    }
+
+    #[test]
+    fn test_outages() {
+        let from = at("2026-10-01T00:00:00Z");
+        let to = at("2026-10-02T00:00:00Z");
+        let events = vec![
+            // Down since before the period
+            change("2026-10-01T01:00:00Z", "down", "up"),
+            change("2026-10-01T10:00:00Z", "up", "late"),
+            change("2026-10-01T10:30:00Z", "late", "missing"),
+            change("2026-10-01T12:30:00Z", "missing", "up"),
+            change("2026-10-01T23:00:00Z", "up", "down"),
+        ];
+        let (total, longest) = outages(&events, from, to);
+        assert_eq!(total, Duration::hours(4));
+        assert_eq!(longest, Some(Duration::hours(2)));
+
+        assert_eq!(outages(&[], from, to), (Duration::zero(), None));
+    }
+
+    #[test]
+    fn test_expected_runs() {
+        let mut check: Check = serde_json::from_value(serde_json::json!({
+            "id": "00000000-0000-0000-0000-000000000001",
+            "public_id": "00000000-0000-0000-0000-000000000002",
+            "project_id": "00000000-0000-0000-0000-000000000003",
+            "name": "Backup",
+            "slug": "backup",
+            "description": null,
+            "period_seconds": 3600,
+            "missing_after_seconds": 300,
+            "status": "up",
+            "last_ping_at": null,
+            "next_ping_expected_at": null,
+            "created_at": "2026-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        let from = at("2026-10-05T00:00:00Z");
+        let to = at("2026-10-12T00:00:00Z");
+        assert_eq!(expected_runs(&check, chrono_tz::UTC, from, to), 168);
+
+        check.cron_expression = Some("0 2 * * MON-FRI".to_string());
+        assert_eq!(expected_runs(&check, chrono_tz::UTC, from, to), 5);
+    }
+
+    #[test]
+    fn test_exports() {
+        let reports = vec![report("backup")];
+        let since = at("2026-09-18T00:00:00Z");
+        let now = at("2026-10-18T00:00:00Z");
+
+        let csv = to_csv(&reports);
+        assert_eq!(
+            csv.lines().nth(1).unwrap(),
+            "infra,backup,\"Nightly, \"\"full\"\" backup\",99.50,96.67,30,1,0,61000,95000,3600"
+        );
+
+        let markdown = to_markdown(&reports, "30d", since, now);
+        assert!(markdown.contains("Last 30d: 2026-09-18 to 2026-10-18, 1 check(s)"));
+        assert!(
+            markdown.contains("| backup | infra | 99.50% | 96.67% | 30 | 1 | 0 | 1m | 1m | 1h |")
+        );
+
+        let html = to_html(&reports, "30d", since, now);
+        assert!(html.contains("<td>backup</td><td>infra</td><td>99.50%</td>"));
+    }
+}
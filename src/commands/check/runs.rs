@@ -0,0 +1,382 @@
+//! Run-centric view: pair start and completion pings into one row per run.
+
+use crate::cli::OutputFormat;
+use crate::client::ApiClient;
+use crate::config::Context;
+use crate::error::CliError;
+use crate::output::{
+    OutputConfig, format_timestamp, print_output, print_single, print_warning, status_style,
+};
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use tabled::Tabled;
+
+use super::helpers::{format_duration, parse_duration, resolve_check_by_org};
+use super::logs::{LogFilter, build_query, fetch_pings};
+use super::types::{Check, PingLog};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum Outcome {
+    Success,
+    Fail,
+    /// Started and still within the expected runtime
+    Running,
+    /// Started but never completed (superseded, or past the expected runtime)
+    Orphaned,
+}
+
+impl Outcome {
+    /// Colored like the check status each outcome leads to
+    fn label(self) -> String {
+        let (name, status) = match self {
+            Outcome::Success => ("success", "up"),
+            Outcome::Fail => ("fail", "down"),
+            Outcome::Running => ("running", "running"),
+            Outcome::Orphaned => ("orphaned", "late"),
+        };
+        status_style(status).apply_to(name).to_string()
+    }
+}
+
+/// One job run built from its start and completion pings
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct Run {
+    run_id: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    finished_at: Option<DateTime<Utc>>,
+    /// Reported or measured runtime; elapsed time so far for running runs
+    pub(super) duration_ms: Option<i64>,
+    pub(super) outcome: Outcome,
+    source_ip: Option<String>,
+    exit_code: Option<i32>,
+}
+
+impl Run {
+    fn from_start(start: &PingLog) -> Self {
+        Self {
+            run_id: start.run_id.clone(),
+            started_at: Some(start.created_at),
+            finished_at: None,
+            duration_ms: None,
+            outcome: Outcome::Running,
+            source_ip: start.source_ip.clone(),
+            exit_code: None,
+        }
+    }
+
+    fn finish(&mut self, end: &PingLog) {
+        let measured = self
+            .started_at
+            .map(|s| (end.created_at - s).num_milliseconds());
+        self.finished_at = Some(end.created_at);
+        self.duration_ms = end.duration_ms.map(i64::from).or(measured);
+        self.outcome = if end.ping_type == "fail" {
+            Outcome::Fail
+        } else {
+            Outcome::Success
+        };
+        self.exit_code = end.exit_code;
+        if self.source_ip.is_none() {
+            self.source_ip.clone_from(&end.source_ip);
+        }
+        if self.started_at.is_none() {
+            self.started_at = self
+                .duration_ms
+                .map(|ms| end.created_at - Duration::milliseconds(ms));
+        }
+    }
+
+    /// When the run started (or finished, if the start is unknown), for ordering
+    fn sort_key(&self) -> Option<DateTime<Utc>> {
+        self.started_at.or(self.finished_at)
+    }
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct RunRow {
+    #[tabled(rename = "RUN")]
+    run: String,
+    #[tabled(rename = "STARTED")]
+    started: String,
+    #[tabled(rename = "FINISHED")]
+    finished: String,
+    #[tabled(rename = "DURATION")]
+    duration: String,
+    #[tabled(rename = "OUTCOME")]
+    outcome: String,
+    #[tabled(rename = "SOURCE")]
+    source: String,
+    #[tabled(rename = "EXIT")]
+    exit_code: String,
+}
+
+/// Show one row per run for a check
+pub async fn runs(
+    ctx: &Context,
+    slug_or_id: &str,
+    since: &str,
+    failed: bool,
+    slower_than: Option<&str>,
+    limit: usize,
+    verbose: bool,
+) -> Result<()> {
+    let now = Utc::now();
+    let filter = LogFilter {
+        since: Some(since.to_string()),
+        ..Default::default()
+    };
+    let query = build_query(&filter, now)?;
+    let slower_than_ms = slower_than
+        .map(|s| parse_duration(s).map(|secs| i64::from(secs) * 1000))
+        .transpose()?;
+    if slower_than_ms.is_some_and(|ms| ms <= 0) {
+        return Err(CliError::Other("--slower-than must be positive".to_string()).into());
+    }
+
+    let org_id = ctx.require_org()?;
+    let check = resolve_check_by_org(ctx, org_id, slug_or_id).await?;
+    let client = ApiClient::new(ctx)?;
+
+    let mut pings: Vec<PingLog> = Vec::new();
+    fetch_pings(&client, &check, 0, &query, verbose, |page| {
+        pings.extend(page);
+        Ok(())
+    })
+    .await?;
+
+    let mut runs: Vec<Run> = pair_runs(pings, expected_runtime(&check), now)
+        .into_iter()
+        .filter(|r| !failed || r.outcome == Outcome::Fail)
+        .filter(|r| slower_than_ms.is_none_or(|min| r.duration_ms.is_some_and(|d| d >= min)))
+        .collect();
+    if limit > 0 {
+        runs.truncate(limit);
+    }
+
+    if ctx.output_format() != OutputFormat::Table {
+        return print_single(ctx, &runs);
+    }
+    if runs.is_empty() {
+        print_warning(&format!("No matching runs since {}", since));
+        return Ok(());
+    }
+
+    let config = OutputConfig::from_context(ctx);
+    let rows: Vec<RunRow> = runs
+        .iter()
+        .map(|r| RunRow {
+            run: r.run_id.clone().unwrap_or_else(|| "-".to_string()),
+            started: r
+                .started_at
+                .map(|t| format_timestamp(t, &config))
+                .unwrap_or_else(|| "-".to_string()),
+            finished: r
+                .finished_at
+                .map(|t| format_timestamp(t, &config))
+                .unwrap_or_else(|| "-".to_string()),
+            duration: r
+                .duration_ms
+                .map(format_run_duration)
+                .unwrap_or_else(|| "-".to_string()),
+            outcome: r.outcome.label(),
+            source: r.source_ip.clone().unwrap_or_else(|| "-".to_string()),
+            exit_code: r
+                .exit_code
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        })
+        .collect();
+    print_output(ctx, rows)
+}
+
+/// How long a start may go without completion before it counts as orphaned
+pub(super) fn expected_runtime(check: &Check) -> Duration {
+    let seconds = check
+        .max_runtime_seconds
+        .unwrap_or(check.period_seconds + check.missing_after_seconds);
+    Duration::seconds(i64::from(seconds))
+}
+
+/// Pair pings into runs, newest first.
+///
+/// Pings with a run ID are matched by ID, so overlapping runs stay apart. Pings without one
+/// pair each completion with the latest unmatched start; a start followed by another start
+/// is orphaned.
+pub(super) fn pair_runs(
+    mut pings: Vec<PingLog>,
+    expected: Duration,
+    now: DateTime<Utc>,
+) -> Vec<Run> {
+    pings.sort_by_key(|p| (p.created_at, p.id));
+
+    let mut runs: Vec<Run> = Vec::new();
+    let mut open_by_id: HashMap<String, usize> = HashMap::new();
+    let mut open_anonymous: Option<usize> = None;
+
+    for ping in &pings {
+        let is_start = match ping.ping_type.as_str() {
+            "start" => true,
+            "success" | "fail" => false,
+            _ => continue,
+        };
+        match (&ping.run_id, is_start) {
+            (Some(id), true) => {
+                if let Some(previous) = open_by_id.insert(id.clone(), runs.len()) {
+                    runs[previous].outcome = Outcome::Orphaned;
+                }
+                runs.push(Run::from_start(ping));
+            }
+            (Some(id), false) => match open_by_id.remove(id) {
+                Some(index) => runs[index].finish(ping),
+                None => runs.push(completion_only(ping)),
+            },
+            (None, true) => {
+                if let Some(previous) = open_anonymous.replace(runs.len()) {
+                    runs[previous].outcome = Outcome::Orphaned;
+                }
+                runs.push(Run::from_start(ping));
+            }
+            (None, false) => match open_anonymous.take() {
+                Some(index) => runs[index].finish(ping),
+                None => runs.push(completion_only(ping)),
+            },
+        }
+    }
+
+    for run in runs.iter_mut().filter(|r| r.finished_at.is_none()) {
+        if let Some(started) = run.started_at {
+            let elapsed = now - started;
+            if elapsed > expected {
+                run.outcome = Outcome::Orphaned;
+            }
+            if run.outcome == Outcome::Running {
+                run.duration_ms = Some(elapsed.num_milliseconds());
+            }
+        }
+    }
+
+    runs.sort_by_key(|r| std::cmp::Reverse(r.sort_key()));
+    runs
+}
+
+/// A completion whose start ping is missing or outside the time range
+fn completion_only(end: &PingLog) -> Run {
+    let mut run = Run {
+        run_id: end.run_id.clone(),
+        started_at: None,
+        finished_at: None,
+        duration_ms: None,
+        outcome: Outcome::Success,
+        source_ip: None,
+        exit_code: None,
+    };
+    run.finish(end);
+    run
+}
+
+/// Format a run duration, keeping sub-minute precision
+pub(super) fn format_run_duration(ms: i64) -> String {
+    if ms < 1000 {
+        format!("{}ms", ms)
+    } else if ms < 60_000 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else {
+        format_duration((ms / 1000).min(i64::from(i32::MAX)) as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ping(id: i64, ping_type: &str, minute: u32, run: Option<&str>) -> PingLog {
+        PingLog {
+            id,
+            ping_type: ping_type.to_string(),
+            created_at: format!("2026-10-18T12:{:02}:00Z", minute).parse().unwrap(),
+            duration_ms: None,
+            source_ip: Some("10.0.0.1".to_string()),
+            run_id: run.map(str::to_string),
+            exit_code: None,
+            body: None,
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-18T12:59:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn test_pair_overlapping_runs_by_id() {
+        let mut fail = ping(4, "fail", 20, Some("a"));
+        fail.exit_code = Some(2);
+        let pings = vec![
+            ping(1, "start", 0, Some("a")),
+            ping(2, "start", 5, Some("b")),
+            ping(3, "success", 10, Some("b")),
+            fail,
+        ];
+        let runs = pair_runs(pings, Duration::hours(1), now());
+
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].run_id.as_deref(), Some("b"));
+        assert_eq!(runs[0].outcome, Outcome::Success);
+        assert_eq!(runs[0].duration_ms, Some(5 * 60_000));
+        assert_eq!(runs[1].run_id.as_deref(), Some("a"));
+        assert_eq!(runs[1].outcome, Outcome::Fail);
+        assert_eq!(runs[1].duration_ms, Some(20 * 60_000));
+        assert_eq!(runs[1].exit_code, Some(2));
+    }
+
+    #[test]
+    fn test_pair_anonymous_runs() {
+        let mut reported = ping(5, "success", 40, None);
+        reported.duration_ms = Some(90_000);
+        let pings = vec![
+            ping(1, "start", 0, None),
+            ping(2, "start", 10, None),
+            ping(3, "success", 15, None),
+            reported,
+            ping(6, "start", 50, None),
+        ];
+        let runs = pair_runs(pings, Duration::hours(1), now());
+
+        let outcomes: Vec<Outcome> = runs.iter().map(|r| r.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                Outcome::Running,
+                Outcome::Success,
+                Outcome::Success,
+                Outcome::Orphaned
+            ]
+        );
+        // Completion without a start: start derived from the reported duration
+        assert_eq!(
+            runs[1].started_at.unwrap().to_rfc3339(),
+            "2026-10-18T12:38:30+00:00"
+        );
+        assert_eq!(runs[2].duration_ms, Some(5 * 60_000));
+        assert_eq!(runs[0].duration_ms, Some(9 * 60_000));
+    }
+
+    #[test]
+    fn test_stale_start_is_orphaned() {
+        let runs = pair_runs(
+            vec![ping(1, "start", 0, Some("a"))],
+            Duration::minutes(30),
+            now(),
+        );
+        assert_eq!(runs[0].outcome, Outcome::Orphaned);
+    }
+
+    #[test]
+    fn test_format_run_duration() {
+        assert_eq!(format_run_duration(850), "850ms");
+        assert_eq!(format_run_duration(12_345), "12.3s");
+        assert_eq!(format_run_duration(600_000), "10m");
+    }
+}
@@ -1,16 +1,23 @@
-//! Tail command for streaming timeline events.
+//! Tail command for streaming timeline events from one or several checks.
 
-use crate::cli::OutputFormat;
+use crate::cli::{OutputFormat, TailArgs};
 use crate::client::ApiClient;
 use crate::config::Context;
-use crate::output::{OutputConfig, print_ndjson, print_single};
+use crate::error::CliError;
+use crate::output::{
+    OutputConfig, print_error, print_ndjson, print_ndjson_stream, print_single, print_warning,
+};
 use anyhow::Result;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 use console::style;
+use futures_util::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use uuid::Uuid;
 
+use super::bulk::{fetch_checks, matches_selector};
 use super::helpers::resolve_check_by_org;
+use super::hooks::EventHooks;
 
 // ============================================================================
 // Response Types
@@ -24,17 +31,17 @@ struct EventsResponse {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-struct EventItem {
-    id: i64,
-    event_type: String,
-    occurred_at: DateTime<Utc>,
+pub(super) struct EventItem {
+    pub(super) id: i64,
+    pub(super) event_type: String,
+    pub(super) occurred_at: DateTime<Utc>,
     #[serde(default)]
     effective_at: Option<DateTime<Utc>>,
-    source: String,
+    pub(super) source: String,
     payload: serde_json::Value,
-    from_status: Option<String>,
-    to_status: Option<String>,
-    summary: String,
+    pub(super) from_status: Option<String>,
+    pub(super) to_status: Option<String>,
+    pub(super) summary: String,
 }
 
 // ============================================================================
@@ -42,6 +49,7 @@ struct EventItem {
 // ============================================================================
 
 /// Stream timeline events for a check
+#[allow(clippy::too_many_arguments)]
 pub async fn tail(
     ctx: &Context,
     slug_or_id: &str,
@@ -49,6 +57,7 @@ pub async fn tail(
     types: Option<&str>,
     follow: bool,
     limit: i64,
+    hooks: &EventHooks,
     verbose: bool,
 ) -> Result<()> {
     let org_id = ctx.require_org()?;
@@ -119,7 +128,14 @@ pub async fn tail(
             } else {
                 // Human-readable output
                 for event in &new_events {
-                    print_event_line(event, &config);
+                    print_event_line(event, None, &config);
+                }
+            }
+
+            // Hooks react to what happens from now on, not to the backlog
+            if !first_batch {
+                for event in &new_events {
+                    hooks.run(&check.slug, event).await;
                 }
             }
         }
@@ -154,43 +170,309 @@ pub async fn tail(
     Ok(())
 }
 
+/// Stream events from several checks, interleaved by time
+pub async fn tail_many(ctx: &Context, args: TailArgs, verbose: bool) -> Result<()> {
+    let hooks = EventHooks::parse(&args.hooks)?;
+    let slugs = (!args.slugs.is_empty()).then_some(args.slugs.as_slice());
+    let checks = fetch_checks(ctx, args.selector.project.as_deref(), verbose).await?;
+    let missing: Vec<&str> = slugs
+        .into_iter()
+        .flatten()
+        .filter(|s| {
+            !checks
+                .iter()
+                .any(|c| c.check.slug == **s || c.check.id.to_string() == **s)
+        })
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(CliError::Other(format!("Check not found: {}", missing.join(", "))).into());
+    }
+    let mut streams: Vec<CheckStream> = checks
+        .into_iter()
+        .filter(|c| {
+            let by_id = slugs.is_some_and(|s| s.contains(&c.check.id.to_string()));
+            by_id || matches_selector(c, &args.selector, slugs)
+        })
+        .map(|c| CheckStream::new(c.check.slug, c.check.id, &args.since))
+        .collect();
+    if streams.is_empty() {
+        print_warning("No checks match the selection");
+        return Ok(());
+    }
+
+    let client = ApiClient::new(ctx)?;
+    let config = OutputConfig::from_context(ctx);
+    let is_json = matches!(ctx.output_format(), OutputFormat::Json | OutputFormat::Yaml);
+    // JSON can't be closed while following, so follow mode streams NDJSON instead
+    let is_stream = matches!(ctx.output_format(), OutputFormat::Ndjson) || (is_json && args.follow);
+    let width = streams.iter().map(|s| s.slug.len()).max().unwrap_or(0);
+    let poll = Poll {
+        client: &client,
+        limit: args.limit,
+        types: args.types.as_deref(),
+        concurrency: args.selector.concurrency as usize,
+        verbose,
+    };
+
+    if !is_stream && !is_json && !args.follow {
+        println!(
+            "{}  {} checks  since={}",
+            style("TAIL").bold(),
+            streams.len(),
+            args.since
+        );
+        println!();
+    }
+
+    let mut first_batch = true;
+    loop {
+        let events = poll.round(&mut streams).await;
+
+        if is_stream {
+            print_ndjson_stream(&events)?;
+        } else if is_json {
+            print_single(ctx, &events)?;
+        } else {
+            for e in &events {
+                print_event_line(&e.event, Some(&format!("{:<width$}", e.check)), &config);
+            }
+        }
+        if !first_batch {
+            for e in &events {
+                hooks.run(&e.check, &e.event).await;
+            }
+        }
+
+        if !args.follow {
+            break;
+        }
+        if first_batch && events.is_empty() && !is_stream {
+            println!("{}", style("(no events in time range, waiting...)").dim());
+        }
+        first_batch = false;
+        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+    }
+
+    Ok(())
+}
+
+/// An event tagged with the check it came from
+#[derive(Debug, Serialize)]
+struct CheckEvent {
+    check: String,
+    #[serde(flatten)]
+    event: EventItem,
+}
+
+/// Polling state for one check in a multi-check tail
+struct CheckStream {
+    slug: String,
+    check_id: Uuid,
+    /// Lower bound for the next poll; moves up to the newest event seen
+    since: String,
+    cursor: Option<String>,
+    seen_ids: HashSet<i64>,
+    newest: Option<DateTime<Utc>>,
+}
+
+impl CheckStream {
+    fn new(slug: String, check_id: Uuid, since: &str) -> Self {
+        Self {
+            slug,
+            check_id,
+            since: since.to_string(),
+            cursor: None,
+            seen_ids: HashSet::new(),
+            newest: None,
+        }
+    }
+
+    fn url(&self, limit: i64, types: Option<&str>) -> String {
+        let mut url = format!(
+            "/api/v1/checks/{}/events?limit={}&since={}",
+            self.check_id,
+            limit,
+            urlencoding::encode(&self.since)
+        );
+        if let Some(t) = types {
+            url.push_str(&format!("&types={}", t));
+        }
+        if let Some(c) = &self.cursor {
+            url.push_str(&format!("&cursor={}", c));
+        }
+        url
+    }
+
+    /// Keep unseen events and advance the cursor; returns whether more pages are waiting
+    fn absorb(&mut self, response: EventsResponse, out: &mut Vec<CheckEvent>) -> bool {
+        for event in response.events {
+            if self.seen_ids.insert(event.id) {
+                self.newest = self.newest.max(Some(event.occurred_at));
+                out.push(CheckEvent {
+                    check: self.slug.clone(),
+                    event,
+                });
+            }
+        }
+        match response.next_cursor {
+            Some(next) if response.has_more => {
+                self.cursor = Some(next);
+                true
+            }
+            _ => {
+                // Caught up: later polls only ask for events from the newest one on
+                // (seen_ids drops the overlap)
+                self.cursor = None;
+                if let Some(newest) = self.newest {
+                    self.since = newest.to_rfc3339_opts(SecondsFormat::Secs, true);
+                }
+                false
+            }
+        }
+    }
+}
+
+/// Shared settings for polling several checks
+struct Poll<'a> {
+    client: &'a ApiClient,
+    limit: i64,
+    types: Option<&'a str>,
+    concurrency: usize,
+    verbose: bool,
+}
+
+impl Poll<'_> {
+    /// Fetch new events from every check (at most `concurrency` requests at a time), oldest first.
+    /// A check that fails to load is reported and retried on the next round.
+    async fn round(&self, streams: &mut [CheckStream]) -> Vec<CheckEvent> {
+        let mut events = Vec::new();
+        let mut pending: Vec<usize> = (0..streams.len()).collect();
+        while !pending.is_empty() {
+            let requests: Vec<(usize, String)> = pending
+                .iter()
+                .map(|&i| (i, streams[i].url(self.limit, self.types)))
+                .collect();
+            let responses: Vec<(usize, Result<EventsResponse>)> = stream::iter(requests)
+                .map(|(i, url)| async move {
+                    if self.verbose {
+                        eprintln!("[verbose] Fetching events from: {}", url);
+                    }
+                    (i, self.client.get(&url).await)
+                })
+                .buffered(self.concurrency)
+                .collect()
+                .await;
+
+            pending.clear();
+            for (i, response) in responses {
+                match response {
+                    Ok(response) => {
+                        if streams[i].absorb(response, &mut events) {
+                            pending.push(i);
+                        }
+                    }
+                    Err(e) => {
+                        streams[i].cursor = None;
+                        print_error(&format!("{}: {}", streams[i].slug, e));
+                    }
+                }
+            }
+        }
+        events.sort_by_key(|e| (e.event.occurred_at, e.event.id));
+        events
+    }
+}
+
+/// Fetch all events since `since` (oldest first), following the cursor to the end
+pub(super) async fn fetch_events(
+    client: &ApiClient,
+    check_id: Uuid,
+    since: &str,
+    types: Option<&str>,
+    verbose: bool,
+) -> Result<Vec<EventItem>> {
+    let mut base_url = format!(
+        "/api/v1/checks/{}/events?limit=100&since={}",
+        check_id,
+        urlencoding::encode(since)
+    );
+    if let Some(t) = types {
+        base_url.push_str(&format!("&types={}", t));
+    }
+
+    let mut events: Vec<EventItem> = Vec::new();
+    let mut seen_ids: HashSet<i64> = HashSet::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let url = match &cursor {
+            Some(c) => format!("{}&cursor={}", base_url, c),
+            None => base_url.clone(),
+        };
+        if verbose {
+            eprintln!("[verbose] Fetching events from: {}", url);
+        }
+        let response: EventsResponse = client.get(&url).await?;
+        events.extend(
+            response
+                .events
+                .into_iter()
+                .filter(|e| seen_ids.insert(e.id)),
+        );
+        match response.next_cursor {
+            Some(next) if response.has_more => cursor = Some(next),
+            _ => break,
+        }
+    }
+    events.sort_by_key(|e| (e.occurred_at, e.id));
+    Ok(events)
+}
+
+/// Category of an event type, as accepted by --types
+pub(super) fn event_category(event_type: &str) -> Option<&'static str> {
+    match event_type {
+        "run_started" | "run_finished" => Some("signal"),
+        "status_changed" => Some("state"),
+        "alert_decision" => Some("alert"),
+        _ => None,
+    }
+}
+
 /// Print a single event line in human-readable format
-fn print_event_line(event: &EventItem, config: &OutputConfig) {
+fn print_event_line(event: &EventItem, check: Option<&str>, config: &OutputConfig) {
     // Format timestamp as HH:MM:SS
     let time_str = event.occurred_at.format("%H:%M:%S").to_string();
 
+    let event_label = event_category(&event.event_type).unwrap_or(&event.event_type);
+
     // Determine symbol and color based on event type
-    let (symbol, event_label) = match event.event_type.as_str() {
-        "run_started" => (style(">").cyan(), "signal"),
+    let symbol = match event.event_type.as_str() {
+        "run_started" => style(">").cyan(),
         "run_finished" => {
             // Check if success or fail from summary
             if event.summary.contains("success") {
-                (style("V").green(), "signal")
+                style("V").green()
             } else {
-                (style("X").red(), "signal")
+                style("X").red()
             }
         }
         "status_changed" => {
             // Check if going to bad state
-            if let Some(to) = &event.to_status {
-                match to.as_str() {
-                    "down" | "missing" => (style("!").red().bold(), "state"),
-                    "late" | "overrunning" => (style("!").yellow(), "state"),
-                    "up" => (style("V").green(), "state"),
-                    _ => (style("*").dim(), "state"),
-                }
-            } else {
-                (style("*").dim(), "state")
+            match event.to_status.as_deref() {
+                Some("down" | "missing") => style("!").red().bold(),
+                Some("late" | "overrunning") => style("!").yellow(),
+                Some("up") => style("V").green(),
+                _ => style("*").dim(),
             }
         }
         "alert_decision" => {
             if event.summary.contains("sent") || event.summary.contains("fired") {
-                (style("W").red().bold(), "alert")
+                style("W").red().bold()
             } else {
-                (style("o").dim(), "alert")
+                style("o").dim()
             }
         }
-        _ => (style("*").dim(), &event.event_type[..]),
+        _ => style("*").dim(),
     };
 
     // Print formatted line
@@ -212,11 +494,79 @@ fn print_event_line(event: &EventItem, config: &OutputConfig) {
         &symbol.to_string()
     };
 
-    println!(
-        "{}  {} {:8} {}",
-        style(&time_str).dim(),
-        symbol_str,
-        event_label,
-        event.summary
-    );
+    match check {
+        Some(check) => println!(
+            "{}  {}  {} {:8} {}",
+            style(&time_str).dim(),
+            style(check).bold(),
+            symbol_str,
+            event_label,
+            event.summary
+        ),
+        None => println!(
+            "{}  {} {:8} {}",
+            style(&time_str).dim(),
+            symbol_str,
+            event_label,
+            event.summary
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(ids: &[(i64, &str)], next_cursor: Option<&str>) -> EventsResponse {
+        let events: Vec<serde_json::Value> = ids
+            .iter()
+            .map(|(id, time)| {
+                serde_json::json!({
+                    "id": id,
+                    "event_type": "run_finished",
+                    "occurred_at": time,
+                    "source": "ping",
+                    "payload": {},
+                    "from_status": null,
+                    "to_status": null,
+                    "summary": "success",
+                })
+            })
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "events": events,
+            "next_cursor": next_cursor,
+            "has_more": next_cursor.is_some(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_stream_pages_and_dedups() {
+        let mut stream = CheckStream::new("backup".to_string(), Uuid::nil(), "30m");
+        assert!(
+            stream
+                .url(50, Some("state"))
+                .ends_with("?limit=50&since=30m&types=state")
+        );
+
+        let mut out = Vec::new();
+        let more = stream.absorb(
+            response(
+                &[(1, "2026-10-18T12:00:00Z"), (2, "2026-10-18T12:05:30Z")],
+                Some("c2"),
+            ),
+            &mut out,
+        );
+        assert!(more);
+        assert!(stream.url(50, None).ends_with("&cursor=c2"));
+
+        // The last page repeats event 2; caught up, the next poll starts from the newest event
+        let more = stream.absorb(response(&[(2, "2026-10-18T12:05:30Z")], None), &mut out);
+        assert!(!more);
+        assert_eq!(out.len(), 2);
+        assert!(out.iter().all(|e| e.check == "backup"));
+        assert_eq!(stream.cursor, None);
+        assert_eq!(stream.since, "2026-10-18T12:05:30Z");
+    }
 }
@@ -1,6 +1,7 @@
 //! API types and display types for the check module.
 
 use crate::cache::CheckLike;
+use crate::cli::AlertToggles;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tabled::Tabled;
@@ -81,6 +82,9 @@ impl CheckLike for Check {
     fn name(&self) -> &str {
         &self.name
     }
+    fn tags(&self) -> &[String] {
+        &self.tags
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -104,6 +108,8 @@ pub struct CreateCheckRequest {
     pub alert_after_fail_pings: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_runtime_seconds: Option<i32>,
+    #[serde(flatten)]
+    pub alerts: AlertSettings,
 }
 
 #[derive(Debug, Serialize, Default)]
@@ -128,6 +134,77 @@ pub struct UpdateCheckRequest {
     pub alert_after_fail_pings: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_runtime_seconds: Option<i32>,
+    #[serde(flatten)]
+    pub alerts: AlertSettings,
+}
+
+/// Per-check alert toggles (unset toggles keep the server default or current value)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AlertSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_on_down: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_on_late: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_on_overrun: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_on_anomaly: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_recovery: Option<bool>,
+}
+
+impl AlertSettings {
+    pub fn is_empty(&self) -> bool {
+        self.alert_on_down.is_none()
+            && self.alert_on_late.is_none()
+            && self.alert_on_overrun.is_none()
+            && self.alert_on_anomaly.is_none()
+            && self.notify_on_recovery.is_none()
+    }
+}
+
+impl From<AlertToggles> for AlertSettings {
+    fn from(toggles: AlertToggles) -> Self {
+        Self {
+            alert_on_down: toggles.alert_on_down,
+            alert_on_late: toggles.alert_on_late,
+            alert_on_overrun: toggles.alert_on_overrun,
+            alert_on_anomaly: toggles.alert_on_anomaly,
+            notify_on_recovery: toggles.notify_on_recovery,
+        }
+    }
+}
+
+/// Body for starting or scheduling a maintenance window on a check
+#[derive(Debug, Serialize, Clone)]
+pub struct MaintenanceRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Start of a one-off window (None = now, or recurring with `cron_expression`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<DateTime<Utc>>,
+    pub duration_seconds: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cron_expression: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MaintenanceWindow {
+    pub id: Uuid,
+    pub check_id: Uuid,
+    pub check_slug: String,
+    pub reason: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub duration_seconds: i32,
+    #[serde(default)]
+    pub cron_expression: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub active: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -138,6 +215,13 @@ pub struct PingLog {
     pub created_at: DateTime<Utc>,
     pub duration_ms: Option<i32>,
     pub source_ip: Option<String>,
+    #[serde(default)]
+    pub run_id: Option<String>,
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+    /// Request body (only returned when asked for with `include_body`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -178,14 +262,22 @@ pub struct CheckRowWithProject {
 
 #[derive(Debug, Tabled, Serialize)]
 pub struct PingRow {
+    #[tabled(rename = "ID")]
+    pub id: i64,
     #[tabled(rename = "TIME")]
     pub time: String,
     #[tabled(rename = "TYPE")]
     pub ping_type: String,
     #[tabled(rename = "DURATION")]
     pub duration: String,
+    #[tabled(rename = "RUN")]
+    pub run: String,
     #[tabled(rename = "SOURCE")]
     pub source: String,
+    /// Captured body, only with --show-body (printed after the table in table output)
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -8,9 +8,10 @@ use anyhow::Result;
 use dialoguer::{Confirm, Input, Select};
 
 use super::helpers::{
-    format_duration, parse_duration, resolve_check_by_org, validate_cron_cli, validate_timezone,
+    format_duration, format_toggle, parse_duration, resolve_check_by_org, validate_cron_cli,
+    validate_timezone,
 };
-use super::types::{Check, UpdateCheckRequest};
+use super::types::{AlertSettings, Check, UpdateCheckRequest};
 
 /// Update a check's configuration
 #[allow(clippy::too_many_arguments)]
@@ -27,6 +28,7 @@ pub async fn update(
     alert_after_miss_pings: Option<i32>,
     alert_after_fail_pings: Option<i32>,
     max_runtime: Option<String>,
+    alerts: AlertSettings,
     skip_confirm: bool,
     _verbose: bool,
 ) -> Result<()> {
@@ -44,7 +46,8 @@ pub async fn update(
         || tags.is_some()
         || alert_after_miss_pings.is_some()
         || alert_after_fail_pings.is_some()
-        || max_runtime.is_some();
+        || max_runtime.is_some()
+        || !alerts.is_empty();
 
     let req = if has_options {
         // Non-interactive mode: use provided options
@@ -59,6 +62,7 @@ pub async fn update(
             alert_after_miss_pings,
             alert_after_fail_pings,
             max_runtime,
+            alerts,
         )?
     } else {
         // Interactive mode: prompt for each field
@@ -66,17 +70,7 @@ pub async fn update(
     };
 
     // Check if there are any changes
-    if req.name.is_none()
-        && req.description.is_none()
-        && req.cron_expression.is_none()
-        && req.timezone.is_none()
-        && req.period_seconds.is_none()
-        && req.missing_after_seconds.is_none()
-        && req.tags.is_none()
-        && req.alert_after_miss_pings.is_none()
-        && req.alert_after_fail_pings.is_none()
-        && req.max_runtime_seconds.is_none()
-    {
+    if is_empty_request(&req) {
         print_warning("No changes specified");
         return Ok(());
     }
@@ -106,6 +100,21 @@ pub async fn update(
     Ok(())
 }
 
+/// Whether an update request changes nothing
+pub(super) fn is_empty_request(req: &UpdateCheckRequest) -> bool {
+    req.name.is_none()
+        && req.description.is_none()
+        && req.cron_expression.is_none()
+        && req.timezone.is_none()
+        && req.period_seconds.is_none()
+        && req.missing_after_seconds.is_none()
+        && req.tags.is_none()
+        && req.alert_after_miss_pings.is_none()
+        && req.alert_after_fail_pings.is_none()
+        && req.max_runtime_seconds.is_none()
+        && req.alerts.is_empty()
+}
+
 /// Print the changes that will be applied
 fn print_changes(check: &Check, req: &UpdateCheckRequest) {
     println!("\nChanges to '{}':", check.name);
@@ -194,11 +203,48 @@ fn print_changes(check: &Check, req: &UpdateCheckRequest) {
             format_duration(new_max_runtime)
         );
     }
+    let toggles = [
+        (
+            "Alert on down",
+            check.alert_on_down,
+            req.alerts.alert_on_down,
+        ),
+        (
+            "Alert on late",
+            check.alert_on_late,
+            req.alerts.alert_on_late,
+        ),
+        (
+            "Alert on overrun",
+            check.alert_on_overrun,
+            req.alerts.alert_on_overrun,
+        ),
+        (
+            "Alert on anomaly",
+            check.alert_on_anomaly,
+            req.alerts.alert_on_anomaly,
+        ),
+        (
+            "Notify on recovery",
+            check.notify_on_recovery,
+            req.alerts.notify_on_recovery,
+        ),
+    ];
+    for (label, old, new) in toggles {
+        if let Some(new) = new {
+            println!(
+                "  {}: {} -> {}",
+                label,
+                format_toggle(old),
+                format_toggle(Some(new))
+            );
+        }
+    }
 }
 
 /// Build UpdateCheckRequest from CLI options (non-interactive mode)
 #[allow(clippy::too_many_arguments)]
-fn build_update_request_from_options(
+pub(super) fn build_update_request_from_options(
     name: Option<String>,
     description: Option<String>,
     cron: Option<String>,
@@ -209,6 +255,7 @@ fn build_update_request_from_options(
     alert_after_miss_pings: Option<i32>,
     alert_after_fail_pings: Option<i32>,
     max_runtime: Option<String>,
+    alerts: AlertSettings,
 ) -> Result<UpdateCheckRequest> {
     // Handle cron validation
     let cron_expression = if let Some(ref c) = cron {
@@ -255,6 +302,7 @@ fn build_update_request_from_options(
         alert_after_miss_pings,
         alert_after_fail_pings,
         max_runtime_seconds,
+        alerts,
     })
 }
 
@@ -0,0 +1,820 @@
+//! Import crontab entries as checks and wrap them with `pakyas monitor`.
+//!
+//! Entries are parsed with their schedule (including `@daily`-style macros), the user field
+//! of system crontabs, and `CRON_TZ`/`TZ` lines. Each becomes a check in the active project;
+//! with `--wrap`, its line is rewritten to run through `pakyas monitor` after a backup is saved
+//! to the config directory.
+
+use crate::cli::{CrontabCommands, OutputFormat};
+use crate::commands::check::{create_check, slug_to_title, validate_slug, validate_timezone};
+use crate::commands::import::{
+    ImportedCheck, ImportedSchedule, Unmapped, existing_slugs, host_name, host_timezone, slugify,
+};
+use crate::config::{Config, Context};
+use crate::cron::validate_cron_expression;
+use crate::error::CliError;
+use crate::exit_codes;
+use crate::output::{print_info, print_output, print_success, print_warning};
+use anyhow::{Result, anyhow};
+use console::style;
+use dialoguer::Confirm;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tabled::Tabled;
+use uuid::Uuid;
+
+/// Programs that run the real job as their argument
+const WRAPPERS: &[&str] = &[
+    "sudo", "nice", "nohup", "ionice", "exec", "env", "time", "chronic",
+];
+
+/// Wrappers whose first argument is an option value, not the job
+const WRAPPERS_WITH_ARG: &[&str] = &["timeout", "flock"];
+
+/// Interpreters whose script argument names the job better than the interpreter
+const INTERPRETERS: &[&str] = &[
+    "sh", "bash", "zsh", "python", "python3", "php", "node", "ruby", "perl",
+];
+
+/// Longest command kept in a check description
+const MAX_DESCRIPTION_LEN: usize = 500;
+
+/// Handle crontab subcommands
+pub async fn handle(ctx: &Context, command: CrontabCommands, verbose: bool) -> Result<()> {
+    match command {
+        CrontabCommands::Import {
+            file,
+            system,
+            prefix,
+            wrap,
+            dry_run,
+            yes,
+            ..
+        } => {
+            let options = ImportOptions {
+                system,
+                prefix: prefix.unwrap_or_default(),
+                wrap,
+                dry_run,
+                yes,
+            };
+            import(ctx, file, options, verbose).await
+        }
+    }
+}
+
+struct ImportOptions {
+    system: bool,
+    prefix: String,
+    wrap: bool,
+    dry_run: bool,
+    yes: bool,
+}
+
+/// One scheduled line of a crontab
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrontabEntry {
+    /// Zero-based line index in the crontab
+    pub line: usize,
+    /// Cron expression, or why the line can't be monitored
+    pub schedule: Result<String, String>,
+    /// User field of system crontabs
+    pub user: Option<String>,
+    pub command: String,
+    /// Schedule and user fields as written, kept verbatim when the line is rewritten
+    pub prefix: String,
+    /// From a preceding `CRON_TZ=` or `TZ=` line
+    pub timezone: Option<String>,
+}
+
+/// Host details used to map entries
+struct MapContext<'a> {
+    prefix: &'a str,
+    timezone: Option<&'a str>,
+    host: &'a str,
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct CrontabRow {
+    #[tabled(rename = "LINE")]
+    line: usize,
+    #[tabled(rename = "SLUG")]
+    slug: String,
+    #[tabled(rename = "SCHEDULE")]
+    schedule: String,
+    #[tabled(rename = "ACTION")]
+    action: String,
+    #[tabled(rename = "NOTE")]
+    note: String,
+}
+
+async fn import(
+    ctx: &Context,
+    file: Option<PathBuf>,
+    options: ImportOptions,
+    verbose: bool,
+) -> Result<()> {
+    let content = match &file {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?,
+        None => read_user_crontab()?,
+    };
+    let system = options.system || file.as_deref().is_some_and(is_system_crontab);
+    let entries = parse_crontab(&content, system);
+    if verbose {
+        eprintln!(
+            "[verbose] Found {} entr{} ({} format)",
+            entries.len(),
+            if entries.len() == 1 { "y" } else { "ies" },
+            if system { "system" } else { "user" }
+        );
+    }
+    if entries.is_empty() {
+        print_warning("No crontab entries found");
+        return Ok(());
+    }
+
+    let host_timezone = host_timezone();
+    let host = host_name();
+    let map_ctx = MapContext {
+        prefix: &options.prefix,
+        timezone: host_timezone.as_deref(),
+        host: &host,
+    };
+
+    let project_id = ctx.require_project()?;
+    let project_uuid = Uuid::parse_str(project_id)
+        .map_err(|_| CliError::Other("Invalid project ID".to_string()))?;
+    let existing = existing_slugs(ctx, project_id, verbose).await?;
+    let pakyas_bin = std::env::current_exe()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| "pakyas".to_string());
+
+    let mut rows = Vec::with_capacity(entries.len());
+    let mut seen = HashSet::new();
+    let mut rewrites = Vec::new();
+    let mut failed = 0;
+
+    for entry in &entries {
+        let mut check = match map_entry(entry, &map_ctx) {
+            Ok(check) => check,
+            Err(unmapped) => {
+                rows.push(CrontabRow {
+                    line: entry.line + 1,
+                    slug: String::new(),
+                    schedule: String::new(),
+                    action: "skipped".to_string(),
+                    note: format!("{}: {}", unmapped.name, unmapped.reason),
+                });
+                continue;
+            }
+        };
+        check.slug = unique_slug(&check.slug, &seen);
+        seen.insert(check.slug.clone());
+
+        let action = if existing.contains(&check.slug) {
+            "exists"
+        } else if options.dry_run {
+            "create"
+        } else {
+            if verbose {
+                eprintln!("[verbose] Creating check '{}'", check.slug);
+            }
+            match create_check(ctx, &check.to_request(project_uuid)).await {
+                Ok(_) => "created",
+                Err(e) => {
+                    failed += 1;
+                    rows.push(CrontabRow {
+                        line: entry.line + 1,
+                        slug: check.slug.clone(),
+                        schedule: check.schedule.display(),
+                        action: "failed".to_string(),
+                        note: e.to_string(),
+                    });
+                    continue;
+                }
+            }
+        };
+
+        let mut note = entry
+            .user
+            .as_ref()
+            .map(|u| format!("runs as {}", u))
+            .unwrap_or_default();
+        if options.wrap {
+            match wrap_line(entry, &check.slug, &pakyas_bin) {
+                Some(line) => rewrites.push((entry.line, line)),
+                None => note = "command uses %; wrap it by hand".to_string(),
+            }
+        }
+        rows.push(CrontabRow {
+            line: entry.line + 1,
+            slug: check.slug,
+            schedule: check.schedule.display(),
+            action: action.to_string(),
+            note,
+        });
+    }
+
+    print_output(ctx, rows)?;
+    let table = ctx.output_format() == OutputFormat::Table;
+
+    if options.wrap && !rewrites.is_empty() {
+        if table {
+            println!();
+            print_diff(&content, &rewrites);
+        }
+        if options.dry_run {
+            print_info("Dry run: no checks were created and the crontab was not changed");
+        } else if options.yes
+            || Confirm::new()
+                .with_prompt(format!("Rewrite {} crontab line(s)?", rewrites.len()))
+                .default(false)
+                .interact()?
+        {
+            let updated = rewrite(&content, &rewrites);
+            let backup = match &file {
+                Some(path) => write_file_crontab(path, &updated, &Config::config_dir()?)?,
+                None => write_user_crontab(&content, &updated)?,
+            };
+            print_success(&format!(
+                "Rewrote {} line(s); backup saved to {}",
+                rewrites.len(),
+                backup.display()
+            ));
+        } else {
+            print_warning("Crontab left unchanged");
+        }
+    } else if options.dry_run && table {
+        print_info("Dry run: no checks were created");
+    }
+
+    if failed > 0 {
+        if table {
+            print_warning(&format!("{} check(s) failed to import", failed));
+        }
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+/// `/etc/crontab` and `/etc/cron.d/*` have a user field
+pub fn is_system_crontab(path: &Path) -> bool {
+    path == Path::new("/etc/crontab") || path.parent() == Some(Path::new("/etc/cron.d"))
+}
+
+/// Read the current user's crontab (`crontab -l`); no crontab reads as empty
+pub fn read_user_crontab() -> Result<String> {
+    let output = Command::new("crontab")
+        .arg("-l")
+        .output()
+        .map_err(|e| anyhow!("Failed to run 'crontab -l': {}", e))?;
+    if output.status.success() {
+        return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if stderr.contains("no crontab") {
+        return Ok(String::new());
+    }
+    Err(anyhow!("'crontab -l' failed: {}", stderr.trim()))
+}
+
+/// Back up a crontab file to `backup_dir`, then rewrite it in place (keeping owner and mode)
+///
+/// The backup can't go next to the file: cron runs every file in `/etc/cron.d`, so a copy
+/// there would run each job twice.
+fn write_file_crontab(path: &Path, content: &str, backup_dir: &Path) -> Result<PathBuf> {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "crontab".to_string());
+    std::fs::create_dir_all(backup_dir).map_err(CliError::ConfigWrite)?;
+    let backup = backup_dir.join(format!("{}.{}.bak", name, backup_stamp()));
+    std::fs::copy(path, &backup)
+        .map_err(|e| anyhow!("Failed to back up {}: {}", path.display(), e))?;
+    std::fs::write(path, content)
+        .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+    Ok(backup)
+}
+
+/// Back up the user crontab to the config directory, then install the new one
+fn write_user_crontab(original: &str, content: &str) -> Result<PathBuf> {
+    let backup_dir = Config::config_dir()?;
+    std::fs::create_dir_all(&backup_dir).map_err(CliError::ConfigWrite)?;
+    let backup = backup_dir.join(format!("crontab.{}.bak", backup_stamp()));
+    std::fs::write(&backup, original).map_err(CliError::ConfigWrite)?;
+
+    let mut child = Command::new("crontab")
+        .arg("-")
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|e| anyhow!("Failed to run 'crontab -': {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(content.as_bytes())?;
+    }
+    if !child.wait()?.success() {
+        return Err(anyhow!(
+            "'crontab -' rejected the new crontab; the original is in {}",
+            backup.display()
+        ));
+    }
+    Ok(backup)
+}
+
+fn backup_stamp() -> String {
+    chrono::Local::now().format("%Y%m%d%H%M%S").to_string()
+}
+
+/// Parse crontab content into scheduled entries (comments and env lines are skipped)
+pub fn parse_crontab(content: &str, system: bool) -> Vec<CrontabEntry> {
+    let mut entries = Vec::new();
+    let mut timezone = None;
+
+    for (index, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((name, value)) = env_assignment(line) {
+            if name == "CRON_TZ" || name == "TZ" {
+                timezone =
+                    Some(value.trim_matches(['"', '\'']).to_string()).filter(|tz| !tz.is_empty());
+            }
+            continue;
+        }
+
+        let field_count = if line.starts_with('@') { 1 } else { 5 } + usize::from(system);
+        let Some((fields, command_start)) = split_fields(raw, field_count) else {
+            entries.push(CrontabEntry {
+                line: index,
+                schedule: Err("malformed line".to_string()),
+                user: None,
+                command: line.to_string(),
+                prefix: String::new(),
+                timezone: timezone.clone(),
+            });
+            continue;
+        };
+
+        let schedule = if fields[0].starts_with('@') {
+            expand_macro(fields[0])
+        } else {
+            let expression = fields[..5].join(" ");
+            validate_cron_expression(&expression).map(|_| expression)
+        };
+
+        entries.push(CrontabEntry {
+            line: index,
+            schedule,
+            user: system.then(|| fields[field_count - 1].to_string()),
+            command: raw[command_start..].trim().to_string(),
+            prefix: raw[..command_start].to_string(),
+            timezone: timezone.clone(),
+        });
+    }
+
+    entries
+}
+
+/// `NAME=value` lines set the environment for the entries below them
+fn env_assignment(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = line.split_once('=')?;
+    let name = name.trim();
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid.then_some((name, value.trim()))
+}
+
+/// Split off `count` whitespace-separated fields; returns them and where the command starts
+fn split_fields(line: &str, count: usize) -> Option<(Vec<&str>, usize)> {
+    let mut fields = Vec::with_capacity(count);
+    let mut rest = line;
+    let mut offset = 0;
+    while fields.len() < count {
+        let trimmed = rest.trim_start();
+        offset += rest.len() - trimmed.len();
+        let end = trimmed.find(char::is_whitespace)?;
+        fields.push(&trimmed[..end]);
+        offset += end;
+        rest = &trimmed[end..];
+    }
+    let command_start = offset + (rest.len() - rest.trim_start().len());
+    (command_start < line.len()).then_some((fields, command_start))
+}
+
+/// Expand `@daily`-style macros to cron expressions
+pub fn expand_macro(word: &str) -> Result<String, String> {
+    let expression = match word {
+        "@yearly" | "@annually" => "0 0 1 1 *",
+        "@monthly" => "0 0 1 * *",
+        "@weekly" => "0 0 * * SUN",
+        "@daily" | "@midnight" => "0 0 * * *",
+        "@hourly" => "0 * * * *",
+        "@reboot" => return Err("@reboot has no schedule to monitor".to_string()),
+        other => return Err(format!("unknown schedule '{}'", other)),
+    };
+    Ok(expression.to_string())
+}
+
+/// Whether a line already runs through `pakyas monitor`
+fn is_wrapped(command: &str) -> bool {
+    let mut words = command.split_whitespace();
+    while let Some(word) = words.next() {
+        if (word == "pakyas" || word.ends_with("/pakyas")) && words.next() == Some("monitor") {
+            return true;
+        }
+    }
+    false
+}
+
+/// Propose a slug from the program (or script) a command runs
+fn propose_slug(command: &str) -> String {
+    let mut words = command.split_whitespace().peekable();
+
+    // Skip env assignments, `cd dir &&`, and wrappers like nice or flock
+    let program = loop {
+        let Some(word) = words.next() else {
+            break None;
+        };
+        // Option flags and their numeric values (e.g. `nice -n 10`)
+        if env_assignment(word).is_some() || word.starts_with('-') || word.parse::<f64>().is_ok() {
+            continue;
+        }
+        if word == "cd" {
+            while words.next_if(|w| *w != "&&" && *w != ";").is_some() {}
+            words.next();
+            continue;
+        }
+        if WRAPPERS.contains(&word) {
+            continue;
+        }
+        if WRAPPERS_WITH_ARG.contains(&word) {
+            while words.next_if(|w| w.starts_with('-')).is_some() {}
+            words.next();
+            continue;
+        }
+        break Some(word);
+    };
+
+    let program = match program {
+        Some(p) if INTERPRETERS.contains(&basename(p)) => {
+            words.find(|w| !w.starts_with('-')).unwrap_or(p)
+        }
+        Some(p) => p,
+        None => "",
+    };
+
+    let name = basename(program);
+    let name = name.rsplit_once('.').map_or(name, |(stem, ext)| {
+        if ["sh", "py", "rb", "pl", "php", "js"].contains(&ext) {
+            stem
+        } else {
+            name
+        }
+    });
+    let slug = slugify(name);
+    if slug.is_empty() {
+        "cron-job".to_string()
+    } else {
+        slug
+    }
+}
+
+fn basename(path: &str) -> &str {
+    path.trim_matches(['"', '\''])
+        .rsplit('/')
+        .next()
+        .unwrap_or(path)
+}
+
+/// Add -2, -3, ... until the slug is unused
+fn unique_slug(slug: &str, taken: &HashSet<String>) -> String {
+    if !taken.contains(slug) {
+        return slug.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}-{}", slug, n))
+        .find(|s| !taken.contains(s))
+        .unwrap_or_default()
+}
+
+/// Map one crontab entry onto a check
+fn map_entry(entry: &CrontabEntry, map_ctx: &MapContext) -> Result<ImportedCheck, Unmapped> {
+    let unmapped = |reason: String| Unmapped {
+        name: truncate(&entry.command, 40),
+        reason,
+    };
+
+    if is_wrapped(&entry.command) {
+        return Err(unmapped("already wrapped with pakyas monitor".to_string()));
+    }
+    let expression = entry.schedule.clone().map_err(&unmapped)?;
+
+    let timezone = entry
+        .timezone
+        .as_deref()
+        .or(map_ctx.timezone)
+        .map(str::to_string);
+    if let Some(tz) = &timezone {
+        validate_timezone(tz).map_err(|e| unmapped(e.to_string()))?;
+    }
+
+    let slug = format!("{}{}", map_ctx.prefix, propose_slug(&entry.command));
+    validate_slug(&slug).map_err(|e| unmapped(e.to_string()))?;
+
+    Ok(ImportedCheck {
+        name: slug_to_title(&slug),
+        slug,
+        description: Some(truncate(
+            &format!("Crontab on {}: {}", map_ctx.host, entry.command),
+            MAX_DESCRIPTION_LEN,
+        )),
+        tags: vec!["crontab".to_string()],
+        schedule: ImportedSchedule::Cron {
+            expression,
+            timezone,
+        },
+        grace_seconds: None,
+        external_id: None,
+    })
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max.saturating_sub(3)).collect();
+    format!("{}...", cut)
+}
+
+/// Rewrite an entry to run through `pakyas monitor`
+///
+/// Commands with shell syntax (including leading `NAME=value` assignments, which only a
+/// shell understands) run under `sh -c`. Returns None for commands using `%`, which cron
+/// turns into newlines before the shell sees them.
+fn wrap_line(entry: &CrontabEntry, slug: &str, pakyas_bin: &str) -> Option<String> {
+    if entry.command.contains('%') {
+        return None;
+    }
+    let simple = !starts_with_assignment(&entry.command)
+        && entry
+            .command
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || " -_./=:,+@".contains(c));
+    let command = if simple {
+        entry.command.clone()
+    } else {
+        format!("sh -c '{}'", entry.command.replace('\'', r"'\''"))
+    };
+    Some(format!(
+        "{}{} monitor {} -- {}",
+        entry.prefix, pakyas_bin, slug, command
+    ))
+}
+
+/// Whether the command starts with an environment assignment like `FOO=1 /usr/bin/job`
+fn starts_with_assignment(command: &str) -> bool {
+    let first = command.split_whitespace().next().unwrap_or_default();
+    first.split_once('=').is_some_and(|(name, _)| {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Replace lines by index, keeping every other line (and line endings) as they were
+fn rewrite(content: &str, rewrites: &[(usize, String)]) -> String {
+    content
+        .split_inclusive('\n')
+        .enumerate()
+        .map(
+            |(index, line)| match rewrites.iter().find(|(i, _)| *i == index) {
+                Some((_, new)) => {
+                    let ending = &line[line.trim_end_matches(['\r', '\n']).len()..];
+                    format!("{}{}", new, ending)
+                }
+                None => line.to_string(),
+            },
+        )
+        .collect()
+}
+
+fn print_diff(content: &str, rewrites: &[(usize, String)]) {
+    let lines: Vec<&str> = content.lines().collect();
+    for (index, new) in rewrites {
+        println!("@@ line {} @@", index + 1);
+        println!("{}", style(format!("-{}", lines[*index])).red());
+        println!("{}", style(format!("+{}", new)).green());
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYSTEM: &str = "\
+SHELL=/bin/sh
+PATH=/usr/bin:/bin
+# m h dom mon dow user command
+17 *\t* * *\troot    cd / && run-parts --report /etc/cron.hourly
+CRON_TZ=Europe/Berlin
+0 2 * * *  backup  /opt/scripts/backup-db.sh --full > /var/log/backup.log 2>&1
+@daily     root    /usr/local/bin/rotate
+@reboot    root    /usr/local/bin/warmup
+";
+
+    fn map_ctx() -> MapContext<'static> {
+        MapContext {
+            prefix: "",
+            timezone: Some("UTC"),
+            host: "web-1",
+        }
+    }
+
+    #[test]
+    fn test_parse_system_crontab() {
+        let entries = parse_crontab(SYSTEM, true);
+        assert_eq!(entries.len(), 4);
+
+        assert_eq!(entries[0].line, 3);
+        assert_eq!(entries[0].schedule.as_deref(), Ok("17 * * * *"));
+        assert_eq!(entries[0].user.as_deref(), Some("root"));
+        assert_eq!(
+            entries[0].command,
+            "cd / && run-parts --report /etc/cron.hourly"
+        );
+        assert_eq!(entries[0].prefix, "17 *\t* * *\troot    ");
+        assert!(entries[0].timezone.is_none());
+
+        assert_eq!(entries[1].user.as_deref(), Some("backup"));
+        assert_eq!(entries[1].timezone.as_deref(), Some("Europe/Berlin"));
+
+        assert_eq!(entries[2].schedule.as_deref(), Ok("0 0 * * *"));
+        assert!(entries[3].schedule.is_err());
+    }
+
+    #[test]
+    fn test_parse_user_crontab_and_malformed() {
+        let entries = parse_crontab("*/5 * * * * /usr/bin/sync-job\n0 1 * *\n", false);
+        assert_eq!(entries[0].schedule.as_deref(), Ok("*/5 * * * *"));
+        assert!(entries[0].user.is_none());
+        assert_eq!(entries[1].schedule, Err("malformed line".to_string()));
+    }
+
+    #[test]
+    fn test_macros_are_valid_cron() {
+        for word in ["@yearly", "@monthly", "@weekly", "@daily", "@hourly"] {
+            let expression = expand_macro(word).unwrap();
+            assert!(validate_cron_expression(&expression).is_ok(), "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_propose_slug() {
+        assert_eq!(
+            propose_slug("/opt/scripts/backup-db.sh --full > /var/log/b.log"),
+            "backup-db"
+        );
+        assert_eq!(
+            propose_slug("cd /srv/app && python3 manage.py clearsessions"),
+            "manage"
+        );
+        assert_eq!(
+            propose_slug("FOO=1 nice -n 10 flock -n /tmp/x.lock /usr/bin/Sync_Job"),
+            "sync-job"
+        );
+        assert_eq!(propose_slug("   "), "cron-job");
+    }
+
+    #[test]
+    fn test_map_entry() {
+        let entries = parse_crontab(SYSTEM, true);
+
+        let backup = map_entry(&entries[1], &map_ctx()).unwrap();
+        assert_eq!(backup.slug, "backup-db");
+        assert_eq!(
+            backup.schedule,
+            ImportedSchedule::Cron {
+                expression: "0 2 * * *".to_string(),
+                timezone: Some("Europe/Berlin".to_string()),
+            }
+        );
+        assert!(
+            backup
+                .description
+                .unwrap()
+                .starts_with("Crontab on web-1: ")
+        );
+
+        let rotate = map_entry(
+            &entries[2],
+            &MapContext {
+                prefix: "web-1-",
+                ..map_ctx()
+            },
+        )
+        .unwrap();
+        assert_eq!(rotate.slug, "web-1-rotate");
+
+        let reboot = map_entry(&entries[3], &map_ctx()).unwrap_err();
+        assert!(reboot.reason.contains("@reboot"));
+    }
+
+    #[test]
+    fn test_wrap_and_rewrite() {
+        let content =
+            "# jobs\r\n0 2 * * * /usr/bin/backup --full\r\n5 * * * * echo hi >> /tmp/log\r\n";
+        let entries = parse_crontab(content, false);
+
+        let simple = wrap_line(&entries[0], "backup", "pakyas").unwrap();
+        assert_eq!(
+            simple,
+            "0 2 * * * pakyas monitor backup -- /usr/bin/backup --full"
+        );
+        let shell = wrap_line(&entries[1], "echo", "pakyas").unwrap();
+        assert_eq!(
+            shell,
+            "5 * * * * pakyas monitor echo -- sh -c 'echo hi >> /tmp/log'"
+        );
+
+        let updated = rewrite(content, &[(1, simple.clone())]);
+        assert_eq!(
+            updated,
+            format!("# jobs\r\n{}\r\n5 * * * * echo hi >> /tmp/log\r\n", simple)
+        );
+
+        let rewritten = parse_crontab(&updated, false);
+        let unmapped = map_entry(&rewritten[0], &map_ctx()).unwrap_err();
+        assert!(unmapped.reason.contains("already wrapped"));
+    }
+
+    #[test]
+    fn test_wrap_env_assignment_uses_shell() {
+        let entries = parse_crontab(
+            "0 3 * * * FOO=1 /usr/bin/job\n0 4 * * * /usr/bin/job --level=2\n",
+            false,
+        );
+        assert_eq!(
+            wrap_line(&entries[0], "job", "pakyas").unwrap(),
+            "0 3 * * * pakyas monitor job -- sh -c 'FOO=1 /usr/bin/job'"
+        );
+        assert_eq!(
+            wrap_line(&entries[1], "job", "pakyas").unwrap(),
+            "0 4 * * * pakyas monitor job -- /usr/bin/job --level=2"
+        );
+    }
+
+    #[test]
+    fn test_wrap_skips_percent_and_quotes_single_quotes() {
+        let entries = parse_crontab(
+            "0 0 * * * tar czf /b/$(date +%F).tgz /srv\n0 1 * * * echo 'it''s'\n",
+            false,
+        );
+        assert!(wrap_line(&entries[0], "tar", "pakyas").is_none());
+        assert_eq!(
+            wrap_line(&entries[1], "echo", "pakyas").unwrap(),
+            r"0 1 * * * pakyas monitor echo -- sh -c 'echo '\''it'\'''\''s'\'''"
+        );
+    }
+
+    #[test]
+    fn test_unique_slug() {
+        let taken = HashSet::from(["backup".to_string(), "backup-2".to_string()]);
+        assert_eq!(unique_slug("backup", &taken), "backup-3");
+        assert_eq!(unique_slug("sync", &taken), "sync");
+    }
+
+    #[test]
+    fn test_file_backup_outside_cron_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cron_dir = temp_dir.path().join("cron.d");
+        let config_dir = temp_dir.path().join("config");
+        std::fs::create_dir(&cron_dir).unwrap();
+        let path = cron_dir.join("backup");
+        std::fs::write(&path, "0 2 * * * root /usr/bin/backup\n").unwrap();
+
+        let backup = write_file_crontab(&path, "# rewritten\n", &config_dir).unwrap();
+
+        assert_eq!(backup.parent(), Some(config_dir.as_path()));
+        assert!(
+            backup
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("backup.")
+        );
+        assert_eq!(
+            std::fs::read_to_string(&backup).unwrap(),
+            "0 2 * * * root /usr/bin/backup\n"
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# rewritten\n");
+        assert_eq!(std::fs::read_dir(&cron_dir).unwrap().count(), 1);
+    }
+}
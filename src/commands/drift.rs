@@ -0,0 +1,524 @@
+//! Detect drift between this host's schedules and the checks they report to.
+//!
+//! Crontab lines and systemd timers whose command runs `pakyas monitor <slug>` (or
+//! `pakyas ping <slug>`) are compared with their check. Schedules are compared by the
+//! instants they fire at, so `*/15 * * * *` matches `0,15,30,45 * * * *`, while a timezone
+//! change that moves the runs counts as drift.
+
+use crate::cli::{DriftArgs, OutputFormat};
+use crate::commands::check::Check;
+use crate::commands::crontab::{is_system_crontab, parse_crontab, read_user_crontab};
+use crate::commands::import::{ImportedSchedule, existing_checks, host_timezone};
+use crate::commands::systemd::{Timer, find_service, read_timers, timer_schedule};
+use crate::config::Context;
+use crate::cron::cron_schedules_equivalent;
+use crate::exit_codes;
+use crate::glob::glob_match;
+use crate::output::{print_output, print_success, print_warning};
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tabled::Tabled;
+use uuid::Uuid;
+
+const SYSTEM_CRONTAB: &str = "/etc/crontab";
+const CRON_D: &str = "/etc/cron.d";
+const DEFAULT_UNIT_DIR: &str = "/etc/systemd/system";
+
+/// `pakyas` options that take a value, so the value isn't mistaken for the slug
+const VALUE_FLAGS: &[&str] = &[
+    "--public-id",
+    "--exit-code",
+    "--run",
+    "--duration-ms",
+    "--external-timeout-ms",
+    "--healthchecks-id",
+    "--healthchecks-endpoint",
+    "--cronitor-key",
+    "--cronitor-api-key",
+    "--cronitor-endpoint",
+    "--webhook-url",
+    "--org",
+    "--project",
+    "--format",
+];
+
+/// How a host entry names its check
+#[derive(Debug, Clone, PartialEq)]
+enum CheckRef {
+    Slug(String),
+    PublicId(Uuid),
+}
+
+impl CheckRef {
+    fn matches(&self, check: &Check) -> bool {
+        match self {
+            CheckRef::Slug(slug) => check.slug == *slug,
+            CheckRef::PublicId(id) => check.public_id == *id,
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            CheckRef::Slug(slug) => slug.clone(),
+            CheckRef::PublicId(id) => id.to_string(),
+        }
+    }
+}
+
+/// A crontab line or timer on this host that reports to a check
+#[derive(Debug)]
+struct HostEntry {
+    /// Where the entry was found (`/etc/crontab:12`, a timer path, ...)
+    source: String,
+    check: CheckRef,
+    /// The entry's schedule, or why it couldn't be read
+    schedule: Result<ImportedSchedule, String>,
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct DriftRow {
+    #[tabled(rename = "FINDING")]
+    finding: String,
+    #[tabled(rename = "CHECK")]
+    check: String,
+    #[tabled(rename = "SOURCE")]
+    source: String,
+    #[tabled(rename = "HOST SCHEDULE")]
+    host_schedule: String,
+    #[tabled(rename = "CHECK SCHEDULE")]
+    check_schedule: String,
+}
+
+/// Scan local schedules and report drift from the active project's checks
+pub async fn drift(ctx: &Context, args: DriftArgs, verbose: bool) -> Result<()> {
+    let host_timezone = host_timezone();
+    // Explicit sources replace the defaults
+    let defaults = args.crontabs.is_empty() && args.unit_dirs.is_empty();
+
+    let mut entries = Vec::new();
+    if defaults {
+        match read_user_crontab() {
+            Ok(content) => entries.extend(scan_crontab(
+                "user crontab",
+                &content,
+                false,
+                host_timezone.as_deref(),
+            )),
+            Err(e) if verbose => eprintln!("[verbose] Skipping user crontab: {}", e),
+            Err(_) => {}
+        }
+    }
+    let crontabs = if defaults {
+        default_crontabs()
+    } else {
+        args.crontabs.clone()
+    };
+    for path in &crontabs {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let source = path.display().to_string();
+        entries.extend(scan_crontab(
+            &source,
+            &content,
+            is_system_crontab(path),
+            host_timezone.as_deref(),
+        ));
+    }
+
+    let unit_dirs: Vec<PathBuf> = if defaults {
+        Some(PathBuf::from(DEFAULT_UNIT_DIR))
+            .filter(|dir| dir.is_dir())
+            .into_iter()
+            .collect()
+    } else {
+        args.unit_dirs.clone()
+    };
+    if !unit_dirs.is_empty() {
+        for timer in read_timers(&unit_dirs)? {
+            entries.extend(scan_timer(&timer, host_timezone.as_deref()));
+        }
+    }
+    if verbose {
+        eprintln!(
+            "[verbose] Found {} host entr{} using pakyas",
+            entries.len(),
+            if entries.len() == 1 { "y" } else { "ies" }
+        );
+    }
+
+    let project_id = ctx.require_project()?;
+    let checks: Vec<Check> = existing_checks(ctx, project_id, verbose)
+        .await?
+        .into_iter()
+        .map(|c| c.check)
+        .filter(|c| !c.soft_deleted)
+        .collect();
+    let default_timezone = ctx.config.active_org_timezone.as_deref().unwrap_or("UTC");
+
+    let mut rows = find_drift(&entries, &checks, default_timezone);
+    rows.extend(
+        unreferenced(&entries, &checks, &args).map(|check| DriftRow {
+            finding: "unreferenced".to_string(),
+            check: check.slug.clone(),
+            source: "-".to_string(),
+            host_schedule: "-".to_string(),
+            check_schedule: check_schedule(check).display(),
+        }),
+    );
+
+    let findings = rows.len();
+    let table = ctx.output_format() == OutputFormat::Table;
+    if findings > 0 || !table {
+        print_output(ctx, rows)?;
+    }
+    if table {
+        if findings == 0 {
+            print_success(&format!(
+                "No drift: {} host entr{} match their checks",
+                entries.len(),
+                if entries.len() == 1 { "y" } else { "ies" }
+            ));
+        } else {
+            print_warning(&format!("{} finding(s)", findings));
+        }
+    }
+
+    if findings > 0 {
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+/// `/etc/crontab` and the files in `/etc/cron.d` that exist
+fn default_crontabs() -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = Some(PathBuf::from(SYSTEM_CRONTAB))
+        .filter(|p| p.is_file())
+        .into_iter()
+        .collect();
+    if let Ok(entries) = std::fs::read_dir(CRON_D) {
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            // cron ignores names with dots, like backups and package leftovers
+            .filter(|p| {
+                p.is_file()
+                    && p.file_name()
+                        .is_some_and(|n| !n.to_string_lossy().contains('.'))
+            })
+            .collect();
+        files.sort();
+        paths.extend(files);
+    }
+    paths
+}
+
+/// Crontab lines that run through pakyas
+fn scan_crontab(
+    source: &str,
+    content: &str,
+    system: bool,
+    host_timezone: Option<&str>,
+) -> Vec<HostEntry> {
+    parse_crontab(content, system)
+        .into_iter()
+        .filter_map(|entry| {
+            let check = check_ref(&entry.command)?;
+            let timezone = entry
+                .timezone
+                .clone()
+                .or_else(|| host_timezone.map(str::to_string));
+            Some(HostEntry {
+                source: format!("{}:{}", source, entry.line + 1),
+                check,
+                schedule: entry.schedule.map(|expression| ImportedSchedule::Cron {
+                    expression,
+                    timezone,
+                }),
+            })
+        })
+        .collect()
+}
+
+/// A timer whose service (with drop-ins) runs through pakyas
+fn scan_timer(timer: &Timer, host_timezone: Option<&str>) -> Option<HostEntry> {
+    let service = find_service(timer, &timer.service())?;
+    let check = ["ExecStartPre", "ExecStart", "ExecStartPost"]
+        .iter()
+        .flat_map(|key| service.get_all("Service", key))
+        .find_map(check_ref)?;
+    Some(HostEntry {
+        source: timer.path.display().to_string(),
+        check,
+        schedule: timer_schedule(timer, host_timezone, &mut Vec::new()),
+    })
+}
+
+/// The check a command reports to via `pakyas monitor` or `pakyas ping`
+fn check_ref(command: &str) -> Option<CheckRef> {
+    let words: Vec<&str> = command
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c| c == '\'' || c == '"'))
+        .collect();
+    let start = words.windows(2).position(|pair| {
+        // systemd prefixes like '-' (ignore failure) come before the path
+        let program = pair[0].trim_start_matches(['-', '@', ':', '+', '!']);
+        Path::new(program)
+            .file_name()
+            .is_some_and(|n| n == "pakyas")
+            && (pair[1] == "monitor" || pair[1] == "ping")
+    })?;
+
+    let mut args = words[start + 2..].iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--" => break,
+            "--public-id" => return args.next()?.parse().ok().map(CheckRef::PublicId),
+            _ if arg.starts_with("--public-id=") => {
+                return arg["--public-id=".len()..]
+                    .parse()
+                    .ok()
+                    .map(CheckRef::PublicId);
+            }
+            _ if VALUE_FLAGS.contains(arg) => {
+                args.next();
+            }
+            _ if arg.starts_with('-') => {}
+            slug => return Some(CheckRef::Slug(slug.to_string())),
+        }
+    }
+    None
+}
+
+/// Schedule drift, unknown checks, and unreadable schedules among the host entries
+fn find_drift(entries: &[HostEntry], checks: &[Check], default_timezone: &str) -> Vec<DriftRow> {
+    let mut rows = Vec::new();
+    for entry in entries {
+        let row = |finding: &str, host_schedule: String, check_schedule: String| DriftRow {
+            finding: finding.to_string(),
+            check: entry.check.label(),
+            source: entry.source.clone(),
+            host_schedule,
+            check_schedule,
+        };
+        let Some(check) = checks.iter().find(|c| entry.check.matches(c)) else {
+            let host = entry.schedule.as_ref().map(|s| s.display());
+            rows.push(row(
+                "unknown check",
+                host.unwrap_or_default(),
+                "-".to_string(),
+            ));
+            continue;
+        };
+        let expected = check_schedule(check);
+        match &entry.schedule {
+            Ok(schedule) if schedules_match(schedule, &expected, default_timezone) => {}
+            Ok(schedule) => rows.push(row("schedule", schedule.display(), expected.display())),
+            Err(reason) => rows.push(row("unreadable", reason.clone(), expected.display())),
+        }
+    }
+    rows
+}
+
+/// Checks in the selection that no host entry reports to
+fn unreferenced<'a>(
+    entries: &'a [HostEntry],
+    checks: &'a [Check],
+    args: &'a DriftArgs,
+) -> impl Iterator<Item = &'a Check> {
+    let referenced: HashSet<usize> = entries
+        .iter()
+        .filter_map(|e| checks.iter().position(|c| e.check.matches(c)))
+        .collect();
+    checks.iter().enumerate().filter_map(move |(i, check)| {
+        let selected = args.tag.as_ref().is_none_or(|t| check.tags.contains(t))
+            && args
+                .pattern
+                .as_ref()
+                .is_none_or(|p| glob_match(p, &check.slug));
+        (selected && check.status != "paused" && !referenced.contains(&i)).then_some(check)
+    })
+}
+
+fn check_schedule(check: &Check) -> ImportedSchedule {
+    match &check.cron_expression {
+        Some(expression) => ImportedSchedule::Cron {
+            expression: expression.clone(),
+            timezone: check.timezone.clone(),
+        },
+        None => ImportedSchedule::Interval {
+            period_seconds: check.period_seconds,
+        },
+    }
+}
+
+/// Whether a host schedule fires when the check expects it to
+///
+/// Cron schedules without a timezone run in `default_timezone`; host entries only lack one
+/// when the host's timezone is unknown.
+fn schedules_match(
+    host: &ImportedSchedule,
+    check: &ImportedSchedule,
+    default_timezone: &str,
+) -> bool {
+    let tz = |timezone: &Option<String>| {
+        timezone
+            .as_deref()
+            .unwrap_or(default_timezone)
+            .parse::<chrono_tz::Tz>()
+            .ok()
+    };
+    match (host, check) {
+        (
+            ImportedSchedule::Cron {
+                expression: host_expr,
+                timezone: host_tz,
+            },
+            ImportedSchedule::Cron {
+                expression: check_expr,
+                timezone: check_tz,
+            },
+        ) => match (tz(host_tz), tz(check_tz)) {
+            (Some(host_tz), Some(check_tz)) => {
+                cron_schedules_equivalent(host_expr, host_tz, check_expr, check_tz)
+            }
+            _ => false,
+        },
+        (
+            ImportedSchedule::Interval {
+                period_seconds: host,
+            },
+            ImportedSchedule::Interval {
+                period_seconds: check,
+            },
+        ) => host == check,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(slug: &str, cron: Option<&str>, timezone: Option<&str>) -> Check {
+        serde_json::from_value(serde_json::json!({
+            "id": Uuid::nil(),
+            "public_id": Uuid::new_v4(),
+            "project_id": Uuid::nil(),
+            "name": slug,
+            "slug": slug,
+            "description": null,
+            "period_seconds": 3600,
+            "missing_after_seconds": 300,
+            "cron_expression": cron,
+            "timezone": timezone,
+            "status": "up",
+            "last_ping_at": null,
+            "next_ping_expected_at": null,
+            "created_at": "2026-01-01T00:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_ref() {
+        assert_eq!(
+            check_ref("/usr/local/bin/pakyas monitor backup -- /opt/backup.sh"),
+            Some(CheckRef::Slug("backup".to_string()))
+        );
+        assert_eq!(
+            check_ref("pakyas monitor --external-timeout-ms 2000 --no-external db -- sh -c 'x'"),
+            Some(CheckRef::Slug("db".to_string()))
+        );
+        assert_eq!(
+            check_ref("-/usr/bin/pakyas ping sync --start"),
+            Some(CheckRef::Slug("sync".to_string()))
+        );
+        assert_eq!(
+            check_ref("/bin/pakyas ping sync --start"),
+            Some(CheckRef::Slug("sync".to_string()))
+        );
+        assert_eq!(
+            check_ref("pakyas monitor --public-id=00000000-0000-0000-0000-000000000000 -- run"),
+            Some(CheckRef::PublicId(Uuid::nil()))
+        );
+        assert_eq!(check_ref("/opt/backup.sh --monitor"), None);
+    }
+
+    #[test]
+    fn test_scan_crontab() {
+        let content = "CRON_TZ=Europe/Riga\n30 2 * * * pakyas monitor backup -- /opt/backup.sh\n0 * * * * /opt/other.sh\n";
+        let entries = scan_crontab("user crontab", content, false, Some("UTC"));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].source, "user crontab:2");
+        assert_eq!(entries[0].check, CheckRef::Slug("backup".to_string()));
+        assert_eq!(
+            entries[0].schedule,
+            Ok(ImportedSchedule::Cron {
+                expression: "30 2 * * *".to_string(),
+                timezone: Some("Europe/Riga".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_drift() {
+        let entry = |source: &str, slug: &str, cron: &str, tz: &str| HostEntry {
+            source: source.to_string(),
+            check: CheckRef::Slug(slug.to_string()),
+            schedule: Ok(ImportedSchedule::Cron {
+                expression: cron.to_string(),
+                timezone: Some(tz.to_string()),
+            }),
+        };
+        let checks = vec![
+            check("backup", Some("0 2 * * MON-FRI"), Some("Asia/Manila")),
+            check("report", Some("0 6 * * *"), None),
+            check("legacy", Some("0 1 * * *"), None),
+        ];
+        let entries = vec![
+            // Same instants, written differently and in another timezone
+            entry("a:1", "backup", "0 18 * * SUN-THU", "UTC"),
+            // Moved an hour
+            entry("a:2", "report", "0 7 * * *", "UTC"),
+            entry("a:3", "gone", "0 3 * * *", "UTC"),
+        ];
+
+        let rows = find_drift(&entries, &checks, "UTC");
+        let findings: Vec<(&str, &str)> = rows
+            .iter()
+            .map(|r| (r.finding.as_str(), r.check.as_str()))
+            .collect();
+        assert_eq!(
+            findings,
+            vec![("schedule", "report"), ("unknown check", "gone")]
+        );
+
+        let args = DriftArgs {
+            crontabs: vec![],
+            unit_dirs: vec![],
+            tag: None,
+            pattern: None,
+        };
+        let unused: Vec<&str> = unreferenced(&entries, &checks, &args)
+            .map(|c| c.slug.as_str())
+            .collect();
+        assert_eq!(unused, vec!["legacy"]);
+    }
+
+    #[test]
+    fn test_interval_schedules() {
+        let host = ImportedSchedule::Interval {
+            period_seconds: 900,
+        };
+        let mut every_15 = check("sync", None, None);
+        every_15.period_seconds = 900;
+        assert!(schedules_match(&host, &check_schedule(&every_15), "UTC"));
+        assert!(!schedules_match(
+            &host,
+            &check_schedule(&check("sync", Some("*/15 * * * *"), None)),
+            "UTC"
+        ));
+    }
+}
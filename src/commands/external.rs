@@ -0,0 +1,659 @@
+//! External monitor commands: list, validate, test, replay, report.
+
+use crate::cache::CheckCache;
+use crate::cli::{ExternalCommands, ExternalTestEvent, OutputFormat};
+use crate::client::ApiClient;
+use crate::commands::check::{CheckWithProject, parse_duration, resolve_check_meta};
+use crate::config::Context;
+use crate::error::CliError;
+use crate::exit_codes;
+use crate::external_log;
+use crate::external_monitors::{CheckMeta, ConfigIssue, ExternalMonitorConfig, unknown_key_issues};
+use crate::external_ping::{PingEvent, dispatch_external_pings, wait_budget_ms};
+use crate::external_spool::{self, SpoolEntry};
+use crate::output::{
+    print_error, print_info, print_output, print_single, print_success, print_table, print_warning,
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::time::Duration;
+use tabled::Tabled;
+
+/// Key used in `list` output for targets that apply to every check
+const ALL_CHECKS: &str = "*";
+
+/// Handle external subcommands
+pub async fn handle(ctx: &Context, command: ExternalCommands, verbose: bool) -> Result<()> {
+    match command {
+        ExternalCommands::List { check } => list(ctx, check.as_deref(), verbose).await,
+        ExternalCommands::Validate { offline } => validate(ctx, offline, verbose).await,
+        ExternalCommands::Test {
+            check,
+            events,
+            external_timeout_ms,
+        } => test(ctx, &check, &events, external_timeout_ms, verbose).await,
+        ExternalCommands::Replay {
+            check,
+            dry_run,
+            external_timeout_ms,
+        } => replay(ctx, check.as_deref(), dry_run, external_timeout_ms, verbose).await,
+        ExternalCommands::Report { since, check } => report(ctx, &since, check.as_deref(), verbose),
+    }
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct TargetRow {
+    #[tabled(rename = "CHECK")]
+    check: String,
+    #[tabled(rename = "TARGET")]
+    target: String,
+    #[tabled(rename = "DESTINATION")]
+    destination: String,
+}
+
+/// Check metadata for rule matching (key may be a slug or public_id)
+async fn check_meta(ctx: &Context, key: &str, verbose: bool) -> CheckMeta {
+    let public_id = key.parse::<uuid::Uuid>().ok();
+    resolve_check_meta(ctx, key, public_id.as_ref(), verbose).await
+}
+
+/// Print which config file is in use (table mode only, keeps JSON output clean)
+fn print_config_source(ctx: &Context, path: &std::path::Path) {
+    if ctx.output_format() != OutputFormat::Table {
+        return;
+    }
+    if path.exists() {
+        print_info(&format!("Using config: {}", path.display()));
+    } else {
+        print_info("No external_monitors.toml found; only environment variables apply");
+        for candidate in ExternalMonitorConfig::config_paths() {
+            eprintln!("  searched: {}", candidate.display());
+        }
+    }
+}
+
+/// List effective targets per check
+async fn list(ctx: &Context, check: Option<&str>, verbose: bool) -> Result<()> {
+    let path = ExternalMonitorConfig::path()?;
+    if verbose {
+        for candidate in ExternalMonitorConfig::config_paths() {
+            eprintln!(
+                "[verbose] {} (exists: {})",
+                candidate.display(),
+                candidate.exists()
+            );
+        }
+    }
+
+    let config = ExternalMonitorConfig::load_from_path(&path)?;
+    print_config_source(ctx, &path);
+
+    let keys: Vec<&str> = match check {
+        Some(key) => vec![key],
+        None => {
+            let mut keys = config.check_keys();
+            keys.push(ALL_CHECKS);
+            keys
+        }
+    };
+
+    let mut rows = Vec::new();
+    for key in keys {
+        let meta = check_meta(ctx, key, verbose).await;
+        rows.extend(
+            config
+                .build_monitors(&meta)
+                .into_iter()
+                .map(|target| TargetRow {
+                    check: key.to_string(),
+                    target: target.name().to_string(),
+                    destination: target.display_url(),
+                }),
+        );
+    }
+
+    if rows.is_empty() && ctx.output_format() == OutputFormat::Table {
+        print_info("No external targets configured");
+        return Ok(());
+    }
+
+    print_output(ctx, rows)?;
+
+    if config.migration_mode && ctx.output_format() == OutputFormat::Table {
+        print_info("Migration mode is on");
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct IssueJson {
+    location: String,
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ValidateReport {
+    path: String,
+    valid: bool,
+    issues: Vec<IssueJson>,
+}
+
+/// Validate external_monitors.toml and exit with ISSUES if anything is wrong
+async fn validate(ctx: &Context, offline: bool, verbose: bool) -> Result<()> {
+    let path = ExternalMonitorConfig::path()?;
+
+    if !path.exists() {
+        print_config_source(ctx, &path);
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(&path).map_err(CliError::ConfigRead)?;
+    let mut issues = Vec::new();
+
+    match toml::from_str::<toml::Value>(&content) {
+        Ok(value) => issues.extend(unknown_key_issues(&value)),
+        Err(e) => issues.push(ConfigIssue {
+            location: "(file)".to_string(),
+            message: e.to_string(),
+        }),
+    }
+
+    // Schema errors (wrong types, missing required fields) only surface on full load
+    let config = match ExternalMonitorConfig::load_from_path(&path) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            if issues.is_empty() {
+                issues.push(ConfigIssue {
+                    location: "(file)".to_string(),
+                    message: e.to_string(),
+                });
+            }
+            None
+        }
+    };
+
+    if let Some(config) = &config {
+        issues.extend(config.issues());
+
+        if !offline {
+            match known_check_keys(ctx, verbose).await {
+                Ok(known) => {
+                    for key in config.check_keys() {
+                        if !known.contains(&key.to_lowercase()) {
+                            issues.push(ConfigIssue {
+                                location: format!("checks.{}", key),
+                                message: format!(
+                                    "no check with slug or public ID '{}' in this organization",
+                                    key
+                                ),
+                            });
+                        }
+                    }
+                }
+                Err(e) => print_warning(&format!("Skipping check key validation: {}", e)),
+            }
+        }
+
+        // Tag rules need each check's tags, which come from the check cache
+        if config.has_tag_rules() {
+            let uncached: Vec<&str> = config
+                .check_keys()
+                .into_iter()
+                .filter(|key| {
+                    let public_id = key.parse::<uuid::Uuid>().ok();
+                    CheckMeta::cached(key, public_id.as_ref()).is_none()
+                })
+                .collect();
+            if !uncached.is_empty() {
+                print_warning(&format!(
+                    "Tag rules could not be evaluated for {}: not in the check cache",
+                    uncached.join(", ")
+                ));
+            }
+        }
+    }
+
+    if ctx.output_format() != OutputFormat::Table {
+        let report = ValidateReport {
+            path: path.display().to_string(),
+            valid: issues.is_empty(),
+            issues: issues
+                .iter()
+                .map(|i| IssueJson {
+                    location: i.location.clone(),
+                    message: i.message.clone(),
+                })
+                .collect(),
+        };
+        print_single(ctx, &report)?;
+    } else if issues.is_empty() {
+        print_success(&format!("{} is valid", path.display()));
+    } else {
+        for issue in &issues {
+            print_error(&format!("{}: {}", issue.location, issue.message));
+        }
+        eprintln!("\n{} issue(s) in {}", issues.len(), path.display());
+    }
+
+    if !issues.is_empty() {
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+/// Slugs and public IDs (lowercased) of checks in the active organization
+///
+/// Fetches from the API and refreshes the cache; falls back to the cache if the
+/// API is unreachable.
+async fn known_check_keys(ctx: &Context, verbose: bool) -> Result<HashSet<String>> {
+    let org_id = ctx.require_org()?;
+
+    let fetched: Result<Vec<CheckWithProject>> = async {
+        let client = ApiClient::new(ctx)?;
+        let url = format!("/api/v1/checks?org_id={}", org_id);
+        if verbose {
+            eprintln!("[verbose] Fetching checks from: {}", url);
+        }
+        client.get(&url).await
+    }
+    .await;
+
+    let mut known = HashSet::new();
+    match fetched {
+        Ok(checks) => {
+            let mut cache = CheckCache::load()?;
+            cache.update_from_checks(org_id, checks.iter().map(|c| c.check.clone()));
+            cache.save()?;
+
+            for c in checks {
+                known.insert(c.check.slug.to_lowercase());
+                known.insert(c.check.public_id.to_string());
+            }
+        }
+        Err(e) => {
+            if verbose {
+                eprintln!("[verbose] API unavailable ({}), using check cache", e);
+            }
+            let cache = CheckCache::load()?;
+            for (slug, entry) in cache.entries(org_id) {
+                known.insert(slug.to_lowercase());
+                known.insert(entry.public_id.to_string());
+            }
+            if known.is_empty() {
+                return Err(e);
+            }
+        }
+    }
+
+    Ok(known)
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct TestRow {
+    #[tabled(rename = "EVENT")]
+    event: String,
+    #[tabled(rename = "TARGET")]
+    target: String,
+    #[tabled(rename = "DESTINATION")]
+    destination: String,
+    #[tabled(rename = "STATUS")]
+    status: String,
+    #[tabled(rename = "ATTEMPTS")]
+    attempts: u32,
+    #[tabled(rename = "LATENCY (MS)")]
+    latency_ms: u64,
+    #[tabled(rename = "ERROR")]
+    error: String,
+}
+
+/// Build a synthetic event for `external test`
+fn synthetic_event(check: &str, event: ExternalTestEvent) -> PingEvent {
+    match event {
+        ExternalTestEvent::Start => PingEvent::start(check),
+        ExternalTestEvent::Success => PingEvent::success(check, 0),
+        ExternalTestEvent::Fail => {
+            PingEvent::fail(check, 1, 0, "synthetic failure from pakyas external test")
+        }
+    }
+}
+
+/// Send synthetic events through the normal dispatch path and report per-target results
+async fn test(
+    ctx: &Context,
+    check: &str,
+    events: &[ExternalTestEvent],
+    timeout_ms: u64,
+    verbose: bool,
+) -> Result<()> {
+    let config = ExternalMonitorConfig::load()?;
+    let monitors = config.build_monitors(&check_meta(ctx, check, verbose).await);
+
+    if monitors.is_empty() {
+        return Err(CliError::Other(format!(
+            "No external targets configured for '{}'. Run: pakyas external list",
+            check
+        ))
+        .into());
+    }
+
+    let wait = Duration::from_millis(wait_budget_ms(&monitors, timeout_ms));
+    let mut rows = Vec::new();
+
+    for &kind in events {
+        let event = synthetic_event(check, kind);
+        let event_name = format!("{:?}", event.event_type).to_lowercase();
+
+        let Some(handle) = dispatch_external_pings(monitors.clone(), event, timeout_ms, verbose)
+        else {
+            continue;
+        };
+        let outcomes = match tokio::time::timeout(wait, handle).await {
+            Ok(Ok(outcomes)) => outcomes,
+            Ok(Err(e)) => return Err(anyhow::anyhow!("external dispatch failed: {}", e)),
+            Err(_) => {
+                return Err(anyhow::anyhow!(
+                    "external {} dispatch timed out after {}ms",
+                    event_name,
+                    wait.as_millis()
+                ));
+            }
+        };
+
+        rows.extend(outcomes.into_iter().map(|o| TestRow {
+            event: event_name.clone(),
+            target: o.target.to_string(),
+            destination: o.url,
+            status: if o.success { "ok" } else { "failed" }.to_string(),
+            attempts: o.attempts,
+            latency_ms: o.latency_ms,
+            error: o.error.unwrap_or_default(),
+        }));
+    }
+
+    let failed = rows.iter().filter(|r| r.status != "ok").count();
+    print_output(ctx, rows)?;
+
+    if failed > 0 {
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct ReplayRow {
+    #[tabled(rename = "CHECK")]
+    check: String,
+    #[tabled(rename = "EVENT")]
+    event: String,
+    #[tabled(rename = "TARGET")]
+    target: String,
+    #[tabled(rename = "DESTINATION")]
+    destination: String,
+    #[tabled(rename = "SPOOLED")]
+    spooled_at: String,
+    #[tabled(rename = "STATUS")]
+    status: String,
+    #[tabled(rename = "ERROR")]
+    error: String,
+}
+
+impl ReplayRow {
+    fn new(entry: &SpoolEntry, status: &str) -> Self {
+        Self {
+            check: entry.event.check_identifier.clone(),
+            event: format!("{:?}", entry.event.event_type).to_lowercase(),
+            target: entry.target.clone(),
+            destination: entry.url.clone(),
+            spooled_at: entry.spooled_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            status: status.to_string(),
+            error: entry.error.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Resend spooled external events, keeping only those that fail again
+///
+/// Targets are resolved again from external_monitors.toml and matched by name and
+/// destination; entries with no matching target, or older than the spool's max age,
+/// are dropped.
+async fn replay(
+    ctx: &Context,
+    check: Option<&str>,
+    dry_run: bool,
+    timeout_ms: u64,
+    verbose: bool,
+) -> Result<()> {
+    let selected = |entry: &SpoolEntry| check.is_none_or(|c| entry.event.check_identifier == c);
+
+    if dry_run {
+        let rows: Vec<ReplayRow> = external_spool::load()?
+            .iter()
+            .filter(|e| selected(e))
+            .map(|e| ReplayRow::new(e, if e.is_expired() { "expired" } else { "pending" }))
+            .collect();
+        if rows.is_empty() && ctx.output_format() == OutputFormat::Table {
+            print_info("No spooled external events");
+            return Ok(());
+        }
+        return print_output(ctx, rows);
+    }
+
+    let (pending, mut keep): (Vec<SpoolEntry>, Vec<SpoolEntry>) = external_spool::take()?
+        .into_iter()
+        .partition(|e| selected(e));
+
+    if pending.is_empty() {
+        external_spool::restore(&keep)?;
+        if ctx.output_format() == OutputFormat::Table {
+            print_info("No spooled external events");
+        } else {
+            print_output(ctx, Vec::<ReplayRow>::new())?;
+        }
+        return Ok(());
+    }
+
+    // Entries are already out of the spool file, so put them back if config is broken
+    let config = match ExternalMonitorConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            keep.extend(pending);
+            external_spool::restore(&keep)?;
+            return Err(e.into());
+        }
+    };
+
+    let mut rows = Vec::with_capacity(pending.len());
+    for mut entry in pending {
+        if entry.is_expired() {
+            rows.push(ReplayRow::new(&entry, "expired"));
+            continue;
+        }
+
+        let target = config
+            .build_monitors(&check_meta(ctx, &entry.event.check_identifier, verbose).await)
+            .into_iter()
+            .find(|t| t.name() == entry.target && t.display_url() == entry.url);
+        let Some(target) = target else {
+            rows.push(ReplayRow::new(&entry, "no target"));
+            continue;
+        };
+
+        let wait = Duration::from_millis(wait_budget_ms(std::slice::from_ref(&target), timeout_ms));
+        if verbose {
+            eprintln!(
+                "[verbose] Replaying {:?} event for '{}' to {}",
+                entry.event.event_type, entry.event.check_identifier, entry.url
+            );
+        }
+        let outcome =
+            match dispatch_external_pings(vec![target], entry.event.clone(), timeout_ms, verbose) {
+                Some(handle) => match tokio::time::timeout(wait, handle).await {
+                    Ok(Ok(outcomes)) => outcomes.into_iter().next(),
+                    _ => None,
+                },
+                None => None,
+            };
+
+        match outcome {
+            Some(o) if o.success => rows.push(ReplayRow::new(&entry, "delivered")),
+            other => {
+                entry.error = Some(
+                    other
+                        .and_then(|o| o.error)
+                        .unwrap_or_else(|| "timed out".to_string()),
+                );
+                rows.push(ReplayRow::new(&entry, "failed"));
+                keep.push(entry);
+            }
+        }
+    }
+
+    external_spool::restore(&keep)?;
+
+    let failed = rows.iter().filter(|r| r.status == "failed").count();
+    print_output(ctx, rows)?;
+
+    if failed > 0 {
+        if ctx.output_format() == OutputFormat::Table {
+            print_warning(&format!("{} event(s) left in the spool", failed));
+        }
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Tabled)]
+struct ReportRow {
+    #[tabled(rename = "TARGET")]
+    target: String,
+    #[tabled(rename = "DESTINATION")]
+    destination: String,
+    #[tabled(rename = "EVENTS")]
+    events: usize,
+    #[tabled(rename = "SUCCESS")]
+    success: String,
+    #[tabled(rename = "TIMEOUTS")]
+    timeouts: usize,
+    #[tabled(rename = "AVG MS")]
+    avg_ms: u64,
+    #[tabled(rename = "P95 MS")]
+    p95_ms: u64,
+    #[tabled(rename = "PAKYAS FAIL / EXT OK")]
+    pakyas_failed_target_ok: usize,
+    #[tabled(rename = "PAKYAS OK / EXT FAIL")]
+    pakyas_ok_target_failed: usize,
+}
+
+/// Parse `--since` as a relative duration ("7d") or an RFC 3339 timestamp
+fn parse_since(since: &str) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(since) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+    let seconds = parse_duration(since)?;
+    Ok(Utc::now() - chrono::Duration::seconds(seconds as i64))
+}
+
+/// Summarize the external outcome log per target
+fn report(ctx: &Context, since: &str, check: Option<&str>, verbose: bool) -> Result<()> {
+    let since = parse_since(since)?;
+    let path = external_log::path()?;
+    if verbose {
+        eprintln!(
+            "[verbose] Reading {} since {}",
+            path.display(),
+            since.to_rfc3339()
+        );
+    }
+
+    let mut records = external_log::load_from_path(&path, since)?;
+    if let Some(check) = check {
+        records.retain(|r| r.check == check);
+    }
+
+    if records.is_empty() {
+        if ctx.output_format() == OutputFormat::Table {
+            print_info(&format!(
+                "No external outcomes logged since {}",
+                since.format("%Y-%m-%d %H:%M UTC")
+            ));
+        } else {
+            print_single(ctx, &Vec::<external_log::TargetSummary>::new())?;
+        }
+        return Ok(());
+    }
+
+    let summaries = external_log::summarize(&records);
+
+    if ctx.output_format() != OutputFormat::Table {
+        return print_single(ctx, &summaries);
+    }
+
+    let rows: Vec<ReportRow> = summaries
+        .iter()
+        .map(|s| ReportRow {
+            target: s.target.clone(),
+            destination: s.url.clone(),
+            events: s.total,
+            success: format!("{:.1}%", s.success_rate * 100.0),
+            timeouts: s.timeouts,
+            avg_ms: s.avg_latency_ms,
+            p95_ms: s.p95_latency_ms,
+            pakyas_failed_target_ok: s.pakyas_failed_target_ok,
+            pakyas_ok_target_failed: s.pakyas_ok_target_failed,
+        })
+        .collect();
+    print_table(rows);
+
+    let disagreements: usize = summaries
+        .iter()
+        .map(|s| s.pakyas_failed_target_ok + s.pakyas_ok_target_failed)
+        .sum();
+    println!(
+        "\n{} outcome(s) since {}, {} disagreement(s) with Pakyas",
+        records.len(),
+        since.format("%Y-%m-%d %H:%M UTC"),
+        disagreements
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external_ping::EventType;
+
+    #[test]
+    fn test_parse_since() {
+        let ts = parse_since("2024-01-01T00:00:00Z").unwrap();
+        assert_eq!(ts.to_rfc3339(), "2024-01-01T00:00:00+00:00");
+
+        let week_ago = parse_since("7d").unwrap();
+        let expected = Utc::now() - chrono::Duration::days(7);
+        assert!((week_ago - expected).num_seconds().abs() < 5);
+
+        assert!(parse_since("last tuesday").is_err());
+    }
+
+    #[test]
+    fn test_synthetic_events() {
+        let start = synthetic_event("backup-db", ExternalTestEvent::Start);
+        assert_eq!(start.event_type, EventType::Start);
+        assert_eq!(start.check_identifier, "backup-db");
+
+        let success = synthetic_event("backup-db", ExternalTestEvent::Success);
+        assert_eq!(success.event_type, EventType::Success);
+        assert_eq!(success.exit_code, Some(0));
+
+        let fail = synthetic_event("backup-db", ExternalTestEvent::Fail);
+        assert_eq!(fail.event_type, EventType::Fail);
+        assert_eq!(fail.exit_code, Some(1));
+        assert!(fail.output.is_some());
+    }
+}
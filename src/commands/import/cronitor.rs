@@ -0,0 +1,369 @@
+//! Import job monitors from the Cronitor API or an exported cronitor.yaml.
+
+use super::{ImportOptions, ImportSource, ImportedCheck, ImportedSchedule, Unmapped, run, slugify};
+use crate::commands::check::{validate_cron_cli, validate_slug, validate_timezone};
+use crate::config::Context;
+use crate::error::CliError;
+use crate::external_monitors::ExternalMonitorConfig;
+use crate::output::print_warning;
+use crate::ua::user_agent;
+use anyhow::{Result, anyhow};
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Stop paging after this many pages (guards against a server that never runs out)
+const MAX_PAGES: u32 = 100;
+
+/// Where to read Cronitor monitors from
+pub enum Input {
+    Api { api_key: String, endpoint: String },
+    File(PathBuf),
+}
+
+#[derive(Debug, Deserialize)]
+struct MonitorsPage {
+    #[serde(default)]
+    monitors: Vec<CronitorMonitor>,
+    #[serde(default)]
+    total_monitor_count: Option<usize>,
+}
+
+/// A monitor from the API or an export file
+#[derive(Debug, Clone, Default, Deserialize)]
+struct CronitorMonitor {
+    /// Set from the map key in export files
+    #[serde(default)]
+    key: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default, rename = "type")]
+    kind: Option<String>,
+    #[serde(default)]
+    schedule: Option<String>,
+    #[serde(default)]
+    timezone: Option<String>,
+    #[serde(default)]
+    grace_seconds: Option<i32>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default, alias = "notes")]
+    note: Option<String>,
+}
+
+/// Layout of `cronitor.yaml` (sections keyed by monitor key)
+#[derive(Debug, Default, Deserialize)]
+struct ExportFile {
+    #[serde(default)]
+    jobs: BTreeMap<String, CronitorMonitor>,
+    #[serde(default)]
+    heartbeats: BTreeMap<String, CronitorMonitor>,
+    #[serde(default)]
+    checks: BTreeMap<String, serde_yaml::Value>,
+    #[serde(default)]
+    sites: BTreeMap<String, serde_yaml::Value>,
+}
+
+/// Import monitors from Cronitor
+pub async fn import(
+    ctx: &Context,
+    input: Input,
+    options: ImportOptions,
+    verbose: bool,
+) -> Result<()> {
+    let monitors = match input {
+        Input::Api { api_key, endpoint } => fetch_monitors(&endpoint, &api_key, verbose).await?,
+        Input::File(path) => {
+            if verbose {
+                eprintln!("[verbose] Reading monitors from: {}", path.display());
+            }
+            let content = std::fs::read_to_string(&path).map_err(CliError::ConfigRead)?;
+            parse_export(&content)?
+        }
+    };
+    if verbose {
+        eprintln!("[verbose] Found {} monitor(s) in Cronitor", monitors.len());
+    }
+
+    let source = ImportSource {
+        label: "Cronitor",
+        service: "cronitor",
+        id_key: "monitor_key",
+        endpoint: None,
+    };
+    // Monitor keys alone aren't enough: pinging cronitor needs the account's API key.
+    // Warn up front, since `run` exits early when a check fails to import.
+    if options.write_config
+        && !ExternalMonitorConfig::load().is_ok_and(|c| c.has_cronitor_api_key())
+    {
+        print_warning(
+            "Set api_key under [targets.cronitor] in external_monitors.toml (or CRONITOR_API_KEY) so pakyas can ping Cronitor",
+        );
+    }
+
+    let mapped = monitors.iter().map(map_monitor).collect();
+    run(ctx, &source, mapped, options, verbose).await
+}
+
+/// Fetch all job monitors from the Cronitor API, following pagination
+async fn fetch_monitors(
+    endpoint: &str,
+    api_key: &str,
+    verbose: bool,
+) -> Result<Vec<CronitorMonitor>> {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .build()?;
+
+    let mut monitors = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let url = format!(
+            "{}/api/monitors?type=job&page={}",
+            endpoint.trim_end_matches('/'),
+            page
+        );
+        if verbose {
+            eprintln!("[verbose] Fetching monitors from: {}", url);
+        }
+
+        let response = client
+            .get(&url)
+            .header(reqwest::header::USER_AGENT, user_agent())
+            .basic_auth(api_key, Some(""))
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(anyhow!(
+                "Cronitor rejected the API key. Use an API key with monitor read access"
+            ));
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Cronitor API returned status {}: {}", status, body));
+        }
+
+        let page: MonitorsPage = response.json().await?;
+        let done = page.monitors.is_empty();
+        monitors.extend(page.monitors);
+        if done
+            || page
+                .total_monitor_count
+                .is_some_and(|t| monitors.len() >= t)
+        {
+            break;
+        }
+    }
+
+    Ok(monitors)
+}
+
+/// Parse an exported cronitor.yaml into monitors (keys filled from the map keys)
+fn parse_export(content: &str) -> Result<Vec<CronitorMonitor>> {
+    let file: ExportFile = serde_yaml::from_str(content)
+        .map_err(|e| anyhow!("Invalid Cronitor export file: {}", e))?;
+
+    let keyed = |section: BTreeMap<String, CronitorMonitor>, kind: &str| {
+        section
+            .into_iter()
+            .map(|(key, monitor)| CronitorMonitor {
+                key,
+                kind: monitor.kind.clone().or_else(|| Some(kind.to_string())),
+                ..monitor
+            })
+            .collect::<Vec<_>>()
+    };
+    let other = |section: BTreeMap<String, serde_yaml::Value>, kind: &str| {
+        section
+            .into_keys()
+            .map(|key| CronitorMonitor {
+                key,
+                kind: Some(kind.to_string()),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut monitors = keyed(file.jobs, "job");
+    monitors.extend(keyed(file.heartbeats, "heartbeat"));
+    monitors.extend(other(file.checks, "check"));
+    monitors.extend(other(file.sites, "site"));
+    Ok(monitors)
+}
+
+/// Parse Cronitor interval schedules like "every 5 minutes" or "every hour"
+fn parse_interval(schedule: &str) -> Option<i32> {
+    let rest = schedule.trim().to_lowercase();
+    let rest = rest.strip_prefix("every ")?;
+    let (count, unit) = match rest.split_once(' ') {
+        Some((count, unit)) => (count.parse::<i32>().ok()?, unit.trim()),
+        None => (1, rest),
+    };
+    let unit_seconds = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3600,
+        "day" => 86400,
+        "week" => 604800,
+        _ => return None,
+    };
+    count.checked_mul(unit_seconds).filter(|p| *p > 0)
+}
+
+/// Map one Cronitor monitor onto Pakyas fields
+fn map_monitor(monitor: &CronitorMonitor) -> Result<ImportedCheck, Unmapped> {
+    let name = monitor
+        .name
+        .clone()
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| monitor.key.clone());
+    let unmapped = |reason: String| Unmapped {
+        name: name.clone(),
+        reason,
+    };
+
+    let kind = monitor.kind.as_deref().unwrap_or("job");
+    if kind != "job" && kind != "heartbeat" {
+        return Err(unmapped(format!("{} monitors are not imported", kind)));
+    }
+
+    let slug = if validate_slug(&monitor.key).is_ok() {
+        monitor.key.clone()
+    } else {
+        slugify(&name)
+    };
+    if validate_slug(&slug).is_err() {
+        return Err(unmapped("no usable slug".to_string()));
+    }
+
+    let schedule = match monitor.schedule.as_deref().map(str::trim) {
+        None | Some("") => return Err(unmapped("no schedule".to_string())),
+        Some(s) if s.to_lowercase().starts_with("every ") => match parse_interval(s) {
+            Some(period_seconds) => ImportedSchedule::Interval { period_seconds },
+            None => return Err(unmapped(format!("unsupported interval '{}'", s))),
+        },
+        Some(expression) => {
+            validate_cron_cli(expression).map_err(|e| unmapped(e.to_string()))?;
+            if let Some(tz) = &monitor.timezone {
+                validate_timezone(tz).map_err(|e| unmapped(e.to_string()))?;
+            }
+            ImportedSchedule::Cron {
+                expression: expression.to_string(),
+                timezone: monitor.timezone.clone(),
+            }
+        }
+    };
+
+    Ok(ImportedCheck {
+        slug,
+        name,
+        description: monitor.note.clone().filter(|n| !n.is_empty()),
+        tags: monitor.tags.clone(),
+        schedule,
+        grace_seconds: monitor.grace_seconds,
+        external_id: Some(monitor.key.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("every 5 minutes"), Some(300));
+        assert_eq!(parse_interval("every hour"), Some(3600));
+        assert_eq!(parse_interval("Every 2 days"), Some(172800));
+        assert_eq!(parse_interval("every 0 minutes"), None);
+        assert_eq!(parse_interval("every fortnight"), None);
+        assert_eq!(parse_interval("0 * * * *"), None);
+    }
+
+    #[test]
+    fn test_parse_export_and_map() {
+        let content = r#"
+jobs:
+  nightly-backup:
+    name: Nightly Backup
+    schedule: "0 2 * * *"
+    timezone: America/New_York
+    grace_seconds: 900
+    tags: [prod]
+  Sync_Job:
+    schedule: every 15 minutes
+  broken:
+    schedule: "@daily"
+checks:
+  homepage:
+    request:
+      url: https://example.com
+"#;
+        let monitors = parse_export(content).unwrap();
+        assert_eq!(monitors.len(), 4);
+
+        let backup = map_monitor(&monitors[2]).unwrap();
+        assert_eq!(backup.slug, "nightly-backup");
+        assert_eq!(backup.name, "Nightly Backup");
+        assert_eq!(backup.grace_seconds, Some(900));
+        assert_eq!(backup.external_id.as_deref(), Some("nightly-backup"));
+        assert_eq!(
+            backup.schedule,
+            ImportedSchedule::Cron {
+                expression: "0 2 * * *".to_string(),
+                timezone: Some("America/New_York".to_string()),
+            }
+        );
+
+        // Keys that aren't valid slugs are slugified, but the monitor key is kept for pings
+        let sync = map_monitor(&monitors[0]).unwrap();
+        assert_eq!(sync.slug, "sync-job");
+        assert_eq!(sync.external_id.as_deref(), Some("Sync_Job"));
+        assert_eq!(
+            sync.schedule,
+            ImportedSchedule::Interval {
+                period_seconds: 900
+            }
+        );
+
+        let broken = map_monitor(&monitors[1]).unwrap_err();
+        assert!(broken.reason.contains("@daily"));
+
+        let homepage = map_monitor(&monitors[3]).unwrap_err();
+        assert_eq!(homepage.reason, "check monitors are not imported");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_monitors_follows_pages() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/monitors"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "monitors": [{"key": "a", "type": "job", "schedule": "0 * * * *"}],
+                "total_monitor_count": 2
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/monitors"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "monitors": [{"key": "b", "type": "job", "schedule": "every hour"}],
+                "total_monitor_count": 2
+            })))
+            .mount(&server)
+            .await;
+
+        let monitors = fetch_monitors(&server.uri(), "key", false).await.unwrap();
+        let keys: Vec<_> = monitors.iter().map(|m| m.key.as_str()).collect();
+        assert_eq!(keys, vec!["a", "b"]);
+    }
+}
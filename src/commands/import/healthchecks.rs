@@ -0,0 +1,280 @@
+//! Import from the Healthchecks.io management API (v3).
+
+use super::{ImportOptions, ImportSource, ImportedCheck, ImportedSchedule, Unmapped, run, slugify};
+use crate::commands::check::{validate_cron_cli, validate_slug, validate_timezone};
+use crate::config::Context;
+use crate::external_monitors::DEFAULT_HEALTHCHECKS_ENDPOINT;
+use crate::ua::user_agent;
+use anyhow::{Result, anyhow};
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+
+#[derive(Debug, Deserialize)]
+struct ChecksResponse {
+    checks: Vec<HcCheck>,
+}
+
+/// A check as returned by `GET /api/v3/checks/`
+#[derive(Debug, Deserialize)]
+struct HcCheck {
+    name: String,
+    #[serde(default)]
+    slug: String,
+    /// Space-separated
+    #[serde(default)]
+    tags: String,
+    #[serde(default)]
+    desc: String,
+    grace: i32,
+    /// Period for simple checks
+    #[serde(default)]
+    timeout: Option<i32>,
+    /// Cron (or OnCalendar) expression for scheduled checks
+    #[serde(default)]
+    schedule: Option<String>,
+    #[serde(default)]
+    tz: Option<String>,
+    /// Only present for read-write API keys
+    #[serde(default)]
+    ping_url: Option<String>,
+}
+
+/// Import checks from Healthchecks
+pub async fn import(
+    ctx: &Context,
+    api_key: &str,
+    endpoint: &str,
+    options: ImportOptions,
+    verbose: bool,
+) -> Result<()> {
+    let checks = fetch_checks(endpoint, api_key, verbose).await?;
+    if verbose {
+        eprintln!("[verbose] Found {} check(s) in Healthchecks", checks.len());
+    }
+
+    // Self-hosted instances ping on their own host; carry that over to the target config
+    let ping_endpoint = checks
+        .iter()
+        .filter_map(|c| c.ping_url.as_deref().and_then(split_ping_url))
+        .map(|(endpoint, _)| endpoint)
+        .find(|e| e != DEFAULT_HEALTHCHECKS_ENDPOINT);
+
+    let source = ImportSource {
+        label: "Healthchecks",
+        service: "healthchecks",
+        id_key: "uuid",
+        endpoint: ping_endpoint,
+    };
+    let mapped = checks.iter().map(map_check).collect();
+    run(ctx, &source, mapped, options, verbose).await
+}
+
+/// Fetch all checks visible to the project API key
+async fn fetch_checks(endpoint: &str, api_key: &str, verbose: bool) -> Result<Vec<HcCheck>> {
+    let url = format!("{}/api/v3/checks/", endpoint.trim_end_matches('/'));
+    if verbose {
+        eprintln!("[verbose] Fetching checks from: {}", url);
+    }
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .build()?;
+    let response = client
+        .get(&url)
+        .header(reqwest::header::USER_AGENT, user_agent())
+        .header("X-Api-Key", api_key)
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(anyhow!(
+            "Healthchecks rejected the API key. Use a project API key from Settings > API Access"
+        ));
+    }
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!(
+            "Healthchecks API returned status {}: {}",
+            status,
+            body
+        ));
+    }
+
+    Ok(response.json::<ChecksResponse>().await?.checks)
+}
+
+/// Split `https://hc-ping.com/<uuid>` into endpoint and uuid
+fn split_ping_url(ping_url: &str) -> Option<(String, String)> {
+    let (endpoint, uuid) = ping_url.trim_end_matches('/').rsplit_once('/')?;
+    uuid::Uuid::parse_str(uuid).ok()?;
+    Some((endpoint.to_string(), uuid.to_string()))
+}
+
+/// Map one Healthchecks check onto Pakyas fields
+fn map_check(check: &HcCheck) -> Result<ImportedCheck, Unmapped> {
+    let unmapped = |reason: String| Unmapped {
+        name: check.name.clone(),
+        reason,
+    };
+
+    let slug = if validate_slug(&check.slug).is_ok() {
+        check.slug.clone()
+    } else {
+        slugify(&check.name)
+    };
+    if validate_slug(&slug).is_err() {
+        return Err(unmapped(
+            "no usable slug (set one in Healthchecks)".to_string(),
+        ));
+    }
+
+    let schedule = match (&check.schedule, check.timeout) {
+        (Some(expression), _) => {
+            validate_cron_cli(expression).map_err(|e| unmapped(e.to_string()))?;
+            // Keep an explicit UTC; leaving it out would fall back to the org timezone
+            let timezone = check.tz.clone();
+            if let Some(tz) = &timezone {
+                validate_timezone(tz).map_err(|e| unmapped(e.to_string()))?;
+            }
+            ImportedSchedule::Cron {
+                expression: expression.clone(),
+                timezone,
+            }
+        }
+        (None, Some(period_seconds)) if period_seconds > 0 => {
+            ImportedSchedule::Interval { period_seconds }
+        }
+        _ => return Err(unmapped("no schedule or period".to_string())),
+    };
+
+    Ok(ImportedCheck {
+        slug,
+        name: check.name.clone(),
+        description: Some(check.desc.clone()).filter(|d| !d.is_empty()),
+        tags: check.tags.split_whitespace().map(str::to_string).collect(),
+        schedule,
+        grace_seconds: Some(check.grace),
+        external_id: check
+            .ping_url
+            .as_deref()
+            .and_then(split_ping_url)
+            .map(|(_, uuid)| uuid),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hc_check(json: serde_json::Value) -> HcCheck {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_map_simple_check() {
+        let check = hc_check(serde_json::json!({
+            "name": "Nightly Backup",
+            "slug": "nightly-backup",
+            "tags": "prod db",
+            "desc": "",
+            "grace": 3600,
+            "timeout": 86400,
+            "ping_url": "https://hc-ping.com/662ebe36-ecab-48db-afe3-e20029cb71e6"
+        }));
+
+        let mapped = map_check(&check).unwrap();
+        assert_eq!(mapped.slug, "nightly-backup");
+        assert_eq!(
+            mapped.schedule,
+            ImportedSchedule::Interval {
+                period_seconds: 86400
+            }
+        );
+        assert_eq!(mapped.grace_seconds, Some(3600));
+        assert_eq!(mapped.tags, vec!["prod", "db"]);
+        assert!(mapped.description.is_none());
+        assert_eq!(
+            mapped.external_id.as_deref(),
+            Some("662ebe36-ecab-48db-afe3-e20029cb71e6")
+        );
+    }
+
+    #[test]
+    fn test_map_cron_check_with_timezone() {
+        let check = hc_check(serde_json::json!({
+            "name": "Report",
+            "slug": "",
+            "grace": 600,
+            "schedule": "0 6 * * 1-5",
+            "tz": "Europe/Riga"
+        }));
+
+        let mapped = map_check(&check).unwrap();
+        assert_eq!(mapped.slug, "report");
+        assert_eq!(
+            mapped.schedule,
+            ImportedSchedule::Cron {
+                expression: "0 6 * * 1-5".to_string(),
+                timezone: Some("Europe/Riga".to_string()),
+            }
+        );
+        // Read-only keys have no ping_url
+        assert!(mapped.external_id.is_none());
+    }
+
+    #[test]
+    fn test_map_oncalendar_is_unmapped() {
+        let check = hc_check(serde_json::json!({
+            "name": "Weekly",
+            "slug": "weekly",
+            "grace": 600,
+            "schedule": "Mon *-*-* 02:00:00",
+            "tz": "UTC"
+        }));
+
+        let unmapped = map_check(&check).unwrap_err();
+        assert_eq!(unmapped.name, "Weekly");
+        assert!(!unmapped.reason.is_empty());
+    }
+
+    #[test]
+    fn test_split_ping_url() {
+        assert_eq!(
+            split_ping_url("https://hc.example.com/ping/662ebe36-ecab-48db-afe3-e20029cb71e6"),
+            Some((
+                "https://hc.example.com/ping".to_string(),
+                "662ebe36-ecab-48db-afe3-e20029cb71e6".to_string()
+            ))
+        );
+        assert_eq!(split_ping_url("https://hc-ping.com/not-a-uuid"), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_checks_sends_api_key() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/checks/"))
+            .and(header("X-Api-Key", "secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "checks": [{"name": "A", "slug": "a", "grace": 60, "timeout": 300}]
+            })))
+            .mount(&server)
+            .await;
+
+        let checks = fetch_checks(&server.uri(), "secret", false).await.unwrap();
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].slug, "a");
+
+        let err = fetch_checks(&server.uri(), "wrong", false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("404"));
+    }
+}
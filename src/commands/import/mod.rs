@@ -0,0 +1,475 @@
+//! Import checks from other monitoring services.
+//!
+//! Each source maps its checks onto [`ImportedCheck`]; [`run`] then creates the missing
+//! ones in the active project and adds the per-check ids to external_monitors.toml, so
+//! `pakyas monitor` keeps pinging the old service while migration mode is on.
+
+mod cronitor;
+mod healthchecks;
+
+use crate::cli::{ImportCommands, OutputFormat};
+use crate::client::ApiClient;
+use crate::commands::check::{
+    CheckWithProject, CreateCheckRequest, create_check, format_duration, smart_grace,
+    validate_timezone,
+};
+use crate::config::Context;
+use crate::cron::effective_period_from_cron;
+use crate::error::CliError;
+use crate::exit_codes;
+use crate::external_monitors::{CheckIdAdditions, ExternalMonitorConfig, append_check_ids};
+use crate::lock::{GlobalLock, atomic_write};
+use crate::output::{print_info, print_output, print_success, print_warning};
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashSet;
+use tabled::Tabled;
+use uuid::Uuid;
+
+/// Handle import subcommands
+pub async fn handle(ctx: &Context, command: ImportCommands, verbose: bool) -> Result<()> {
+    match command {
+        ImportCommands::Healthchecks {
+            api_key,
+            endpoint,
+            dry_run,
+            no_external_config,
+        } => {
+            let options = ImportOptions {
+                dry_run,
+                write_config: !no_external_config,
+            };
+            healthchecks::import(ctx, &api_key, &endpoint, options, verbose).await
+        }
+        ImportCommands::Cronitor {
+            api_key,
+            file,
+            endpoint,
+            dry_run,
+            no_external_config,
+        } => {
+            let options = ImportOptions {
+                dry_run,
+                write_config: !no_external_config,
+            };
+            let input = match (file, api_key) {
+                (Some(path), _) => cronitor::Input::File(path),
+                (None, Some(api_key)) => cronitor::Input::Api { api_key, endpoint },
+                (None, None) => {
+                    return Err(CliError::Other(
+                        "Cronitor API key required. Use --api-key or --file".to_string(),
+                    )
+                    .into());
+                }
+            };
+            cronitor::import(ctx, input, options, verbose).await
+        }
+    }
+}
+
+/// Flags shared by every import source
+#[derive(Debug, Clone, Copy)]
+pub struct ImportOptions {
+    pub dry_run: bool,
+    pub write_config: bool,
+}
+
+/// Schedule mapped from the source service
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportedSchedule {
+    Cron {
+        expression: String,
+        timezone: Option<String>,
+    },
+    Interval {
+        period_seconds: i32,
+    },
+}
+
+impl ImportedSchedule {
+    pub fn period_seconds(&self) -> i32 {
+        match self {
+            ImportedSchedule::Cron { expression, .. } => {
+                effective_period_from_cron(expression).unwrap_or(3600)
+            }
+            ImportedSchedule::Interval { period_seconds } => *period_seconds,
+        }
+    }
+
+    pub fn display(&self) -> String {
+        match self {
+            ImportedSchedule::Cron {
+                expression,
+                timezone: Some(tz),
+            } => format!("{} ({})", expression, tz),
+            ImportedSchedule::Cron { expression, .. } => expression.clone(),
+            ImportedSchedule::Interval { period_seconds } => {
+                format!("every {}", format_duration(*period_seconds))
+            }
+        }
+    }
+}
+
+/// A check from another service, mapped onto Pakyas fields
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedCheck {
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub schedule: ImportedSchedule,
+    /// Grace period from the source (None = Pakyas smart grace)
+    pub grace_seconds: Option<i32>,
+    /// Id to write to external_monitors.toml (uuid, monitor_key), when available
+    pub external_id: Option<String>,
+}
+
+impl ImportedCheck {
+    pub fn grace_seconds(&self) -> i32 {
+        self.grace_seconds
+            .unwrap_or_else(|| smart_grace(self.schedule.period_seconds()))
+    }
+
+    pub fn to_request(&self, project_id: Uuid) -> CreateCheckRequest {
+        let (cron_expression, timezone) = match &self.schedule {
+            ImportedSchedule::Cron {
+                expression,
+                timezone,
+            } => (Some(expression.clone()), timezone.clone()),
+            ImportedSchedule::Interval { .. } => (None, None),
+        };
+
+        CreateCheckRequest {
+            project_id,
+            name: self.name.clone(),
+            slug: self.slug.clone(),
+            period_seconds: self.schedule.period_seconds(),
+            missing_after_seconds: self.grace_seconds(),
+            description: self.description.clone(),
+            cron_expression,
+            timezone,
+            tags: (!self.tags.is_empty()).then(|| self.tags.clone()),
+            alert_after_miss_pings: None,
+            alert_after_fail_pings: None,
+            max_runtime_seconds: None,
+            alerts: Default::default(),
+        }
+    }
+}
+
+/// A source check that couldn't be mapped, with the reason
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unmapped {
+    pub name: String,
+    pub reason: String,
+}
+
+/// Where imported checks came from and how they appear in external_monitors.toml
+#[derive(Debug, Clone)]
+pub struct ImportSource {
+    /// Display name, e.g. "Healthchecks"
+    pub label: &'static str,
+    /// Target table under `checks.<slug>.targets`
+    pub service: &'static str,
+    /// Id field within that table
+    pub id_key: &'static str,
+    /// Global ping endpoint to write if the config has none (None = service default)
+    pub endpoint: Option<String>,
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct ImportRow {
+    #[tabled(rename = "SLUG")]
+    slug: String,
+    #[tabled(rename = "NAME")]
+    name: String,
+    #[tabled(rename = "SCHEDULE")]
+    schedule: String,
+    #[tabled(rename = "GRACE")]
+    grace: String,
+    #[tabled(rename = "ACTION")]
+    action: String,
+    #[tabled(rename = "CONFIG")]
+    config: String,
+    #[tabled(rename = "NOTE")]
+    note: String,
+}
+
+impl ImportRow {
+    fn mapped(check: &ImportedCheck, action: &str) -> Self {
+        Self {
+            slug: check.slug.clone(),
+            name: check.name.clone(),
+            schedule: check.schedule.display(),
+            grace: format_duration(check.grace_seconds()),
+            action: action.to_string(),
+            config: String::new(),
+            note: String::new(),
+        }
+    }
+
+    fn unmapped(unmapped: &Unmapped) -> Self {
+        Self {
+            slug: String::new(),
+            name: unmapped.name.clone(),
+            schedule: String::new(),
+            grace: String::new(),
+            action: "unmapped".to_string(),
+            config: String::new(),
+            note: unmapped.reason.clone(),
+        }
+    }
+}
+
+/// Turn a display name into a Pakyas slug (lowercase letters, digits, hyphens)
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    slug[..slug.len().min(100)]
+        .trim_end_matches('-')
+        .to_string()
+}
+
+/// IANA timezone of this host, if it can be determined
+pub fn host_timezone() -> Option<String> {
+    iana_time_zone::get_timezone()
+        .ok()
+        .filter(|tz| validate_timezone(tz).is_ok())
+}
+
+/// Name of this host, for check descriptions
+pub fn host_name() -> String {
+    hostname::get()
+        .map(|h| h.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "this host".to_string())
+}
+
+/// Slugs of checks already in the project
+pub async fn existing_slugs(
+    ctx: &Context,
+    project_id: &str,
+    verbose: bool,
+) -> Result<HashSet<String>> {
+    let checks = existing_checks(ctx, project_id, verbose).await?;
+    Ok(checks.into_iter().map(|c| c.check.slug).collect())
+}
+
+/// Checks already in the project
+pub async fn existing_checks(
+    ctx: &Context,
+    project_id: &str,
+    verbose: bool,
+) -> Result<Vec<CheckWithProject>> {
+    let client = ApiClient::new(ctx)?;
+    let url = format!("/api/v1/checks?project_id={}", project_id);
+    if verbose {
+        eprintln!("[verbose] Fetching existing checks from: {}", url);
+    }
+    client.get(&url).await
+}
+
+/// Create mapped checks in the active project and record their ids in external_monitors.toml
+///
+/// Checks whose slug already exists are not recreated, but their ids are still added to
+/// the config. With `dry_run`, nothing is created or written.
+pub async fn run(
+    ctx: &Context,
+    source: &ImportSource,
+    checks: Vec<Result<ImportedCheck, Unmapped>>,
+    options: ImportOptions,
+    verbose: bool,
+) -> Result<()> {
+    let project_id = ctx.require_project()?;
+    let project_uuid = Uuid::parse_str(project_id)
+        .map_err(|_| CliError::Other("Invalid project ID".to_string()))?;
+    let existing = existing_slugs(ctx, project_id, verbose).await?;
+
+    let mut rows = Vec::with_capacity(checks.len());
+    let mut seen = HashSet::new();
+    let mut ids = Vec::new();
+    let mut failed = 0;
+
+    for mapped in checks {
+        let check = match mapped {
+            Ok(check) => check,
+            Err(unmapped) => {
+                rows.push(ImportRow::unmapped(&unmapped));
+                continue;
+            }
+        };
+
+        if !seen.insert(check.slug.clone()) {
+            let mut row = ImportRow::mapped(&check, "skipped");
+            row.note = "duplicate slug in source".to_string();
+            rows.push(row);
+            continue;
+        }
+
+        let mut row = if existing.contains(&check.slug) {
+            ImportRow::mapped(&check, "exists")
+        } else if options.dry_run {
+            ImportRow::mapped(&check, "create")
+        } else {
+            if verbose {
+                eprintln!("[verbose] Creating check '{}'", check.slug);
+            }
+            match create_check(ctx, &check.to_request(project_uuid)).await {
+                Ok(_) => ImportRow::mapped(&check, "created"),
+                Err(e) => {
+                    failed += 1;
+                    let mut row = ImportRow::mapped(&check, "failed");
+                    row.note = e.to_string();
+                    rows.push(row);
+                    continue;
+                }
+            }
+        };
+
+        match &check.external_id {
+            Some(id) => ids.push((check.slug.clone(), id.clone())),
+            None if options.write_config => {
+                row.note = format!("no {} to map", source.id_key);
+            }
+            None => {}
+        }
+        rows.push(row);
+    }
+
+    let config_path = if options.write_config && !ids.is_empty() {
+        let additions = CheckIdAdditions {
+            service: source.service,
+            id_key: source.id_key,
+            ids,
+            endpoint: source.endpoint.clone(),
+        };
+        Some(write_config_ids(&additions, &mut rows, options.dry_run)?)
+    } else {
+        None
+    };
+
+    print_output(ctx, rows)?;
+
+    if ctx.output_format() == OutputFormat::Table {
+        if let Some(path) = config_path {
+            print_info(&format!("External monitor config: {}", path.display()));
+        }
+        if options.dry_run {
+            print_info("Dry run: no checks were created and no config was written");
+        } else if failed == 0 {
+            print_success(&format!("Imported checks from {}", source.label));
+        } else {
+            print_warning(&format!("{} check(s) failed to import", failed));
+        }
+    }
+
+    if failed > 0 {
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+/// Add per-check ids to external_monitors.toml and mark the affected rows
+fn write_config_ids(
+    additions: &CheckIdAdditions,
+    rows: &mut [ImportRow],
+    dry_run: bool,
+) -> Result<std::path::PathBuf> {
+    let _lock = if dry_run {
+        None
+    } else {
+        Some(GlobalLock::acquire()?)
+    };
+
+    let path = ExternalMonitorConfig::path()?;
+    let content = if path.exists() {
+        std::fs::read_to_string(&path).map_err(CliError::ConfigRead)?
+    } else {
+        String::new()
+    };
+
+    let (output, added) = append_check_ids(&content, additions)?;
+    if !dry_run && !added.is_empty() {
+        atomic_write(&path, &output)?;
+    }
+
+    let id_slugs: HashSet<&str> = additions.ids.iter().map(|(s, _)| s.as_str()).collect();
+    for row in rows
+        .iter_mut()
+        .filter(|r| id_slugs.contains(r.slug.as_str()))
+    {
+        row.config = if !added.contains(&row.slug) {
+            "already set".to_string()
+        } else if dry_run {
+            format!("add {}", additions.id_key)
+        } else {
+            format!("{} added", additions.id_key)
+        };
+    }
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Nightly Backup (db1)"), "nightly-backup-db1");
+        assert_eq!(slugify("  --ETL__job--  "), "etl-job");
+        assert_eq!(slugify("日本"), "");
+        assert_eq!(slugify(&"a".repeat(150)).len(), 100);
+    }
+
+    #[test]
+    fn test_to_request_defaults_grace() {
+        let check = ImportedCheck {
+            slug: "etl".to_string(),
+            name: "ETL".to_string(),
+            description: None,
+            tags: Vec::new(),
+            schedule: ImportedSchedule::Interval {
+                period_seconds: 86400,
+            },
+            grace_seconds: None,
+            external_id: None,
+        };
+        let req = check.to_request(Uuid::nil());
+
+        assert_eq!(req.period_seconds, 86400);
+        assert_eq!(req.missing_after_seconds, smart_grace(86400));
+        assert!(req.cron_expression.is_none());
+        assert!(req.tags.is_none());
+    }
+
+    #[test]
+    fn test_to_request_cron() {
+        let check = ImportedCheck {
+            slug: "backup".to_string(),
+            name: "Backup".to_string(),
+            description: Some("nightly".to_string()),
+            tags: vec!["prod".to_string()],
+            schedule: ImportedSchedule::Cron {
+                expression: "0 2 * * *".to_string(),
+                timezone: Some("Europe/Berlin".to_string()),
+            },
+            grace_seconds: Some(600),
+            external_id: Some("uuid".to_string()),
+        };
+        let req = check.to_request(Uuid::nil());
+
+        assert_eq!(req.period_seconds, 86400);
+        assert_eq!(req.missing_after_seconds, 600);
+        assert_eq!(req.cron_expression.as_deref(), Some("0 2 * * *"));
+        assert_eq!(req.timezone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(req.tags, Some(vec!["prod".to_string()]));
+    }
+}
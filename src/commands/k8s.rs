@@ -0,0 +1,653 @@
+//! Instrument Kubernetes CronJob manifests with `pakyas monitor`.
+//!
+//! CronJobs are read from manifest files (multi-document YAML or `kind: List`), never from a
+//! cluster. Each one gets a check in the active project, matched by slug when it exists, and
+//! its container command is wrapped with `pakyas monitor --public-id <uuid> --`. The public ID
+//! is also set as `PAKYAS_PUBLIC_ID` in the container env. Other documents pass through as-is.
+
+use crate::cli::{K8sCommands, OutputFormat};
+use crate::commands::check::{create_check, slug_to_title, validate_slug, validate_timezone};
+use crate::commands::crontab::expand_macro;
+use crate::commands::import::{
+    ImportedCheck, ImportedSchedule, Unmapped, existing_checks, slugify,
+};
+use crate::config::Context;
+use crate::cron::validate_cron_expression;
+use crate::error::CliError;
+use crate::exit_codes;
+use crate::output::{print_info, print_output, print_success, print_warning};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tabled::Tabled;
+use uuid::Uuid;
+
+/// Env var `pakyas monitor` reads the public ID from
+const PUBLIC_ID_ENV: &str = "PAKYAS_PUBLIC_ID";
+
+/// Handle k8s subcommands
+pub async fn handle(ctx: &Context, command: K8sCommands, verbose: bool) -> Result<()> {
+    match command {
+        K8sCommands::Instrument {
+            file,
+            output,
+            prefix,
+            container,
+            pakyas_bin,
+            dry_run,
+        } => {
+            let options = InstrumentOptions {
+                output,
+                prefix: prefix.unwrap_or_default(),
+                container,
+                pakyas_bin,
+                dry_run,
+            };
+            instrument(ctx, &file, options, verbose).await
+        }
+    }
+}
+
+struct InstrumentOptions {
+    output: Option<PathBuf>,
+    prefix: String,
+    container: Option<String>,
+    pakyas_bin: String,
+    dry_run: bool,
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct CronJobRow {
+    #[tabled(rename = "CRONJOB")]
+    cronjob: String,
+    #[tabled(rename = "SLUG")]
+    slug: String,
+    #[tabled(rename = "SCHEDULE")]
+    schedule: String,
+    #[tabled(rename = "ACTION")]
+    action: String,
+    #[tabled(rename = "NOTE")]
+    note: String,
+}
+
+async fn instrument(
+    ctx: &Context,
+    file: &Path,
+    options: InstrumentOptions,
+    verbose: bool,
+) -> Result<()> {
+    let content = if file == Path::new("-") {
+        std::io::read_to_string(std::io::stdin())
+            .map_err(|e| anyhow!("Failed to read manifest from stdin: {}", e))?
+    } else {
+        std::fs::read_to_string(file)
+            .map_err(|e| anyhow!("Failed to read {}: {}", file.display(), e))?
+    };
+    let mut documents = parse_documents(&content)?;
+    let mut cronjobs = Vec::new();
+    for document in documents.iter_mut() {
+        collect_cronjobs(document, &mut cronjobs);
+    }
+    if cronjobs.is_empty() {
+        return Err(CliError::Other(format!("No CronJob manifests in {}", file.display())).into());
+    }
+    if verbose {
+        eprintln!("[verbose] Found {} CronJob(s)", cronjobs.len());
+    }
+
+    let project_id = ctx.require_project()?;
+    let project_uuid = Uuid::parse_str(project_id)
+        .map_err(|_| CliError::Other("Invalid project ID".to_string()))?;
+    let mut existing: HashMap<String, Uuid> = existing_checks(ctx, project_id, verbose)
+        .await?
+        .into_iter()
+        .filter(|c| !c.check.soft_deleted)
+        .map(|c| (c.check.slug, c.check.public_id))
+        .collect();
+
+    let shared = shared_names(&cronjobs);
+    let mut rows = Vec::with_capacity(cronjobs.len());
+    let mut issues = 0;
+
+    for cronjob in cronjobs {
+        let name = qualified_name(cronjob);
+        let (check, mut notes) = match map_cronjob(cronjob, &options.prefix, &shared) {
+            Ok(mapped) => mapped,
+            Err(unmapped) => {
+                issues += 1;
+                rows.push(CronJobRow {
+                    cronjob: name,
+                    slug: String::new(),
+                    schedule: String::new(),
+                    action: "unmapped".to_string(),
+                    note: unmapped.reason,
+                });
+                continue;
+            }
+        };
+        let mut row = CronJobRow {
+            cronjob: name,
+            slug: check.slug.clone(),
+            schedule: check.schedule.display(),
+            action: String::new(),
+            note: String::new(),
+        };
+
+        let public_id = match existing.get(&check.slug) {
+            Some(public_id) => {
+                row.action = "matched".to_string();
+                Some(*public_id)
+            }
+            None if options.dry_run => {
+                row.action = "create".to_string();
+                None
+            }
+            None => {
+                if verbose {
+                    eprintln!("[verbose] Creating check '{}'", check.slug);
+                }
+                match create_check(ctx, &check.to_request(project_uuid)).await {
+                    Ok(created) => {
+                        row.action = "created".to_string();
+                        // A later CronJob with the same slug reuses this check
+                        existing.insert(check.slug.clone(), created.public_id);
+                        Some(created.public_id)
+                    }
+                    Err(e) => {
+                        issues += 1;
+                        row.action = "failed".to_string();
+                        notes.push(e.to_string());
+                        row.note = notes.join("; ");
+                        rows.push(row);
+                        continue;
+                    }
+                }
+            }
+        };
+
+        if let Some(public_id) = public_id.filter(|_| !options.dry_run) {
+            match wrap_cronjob(
+                cronjob,
+                public_id,
+                options.container.as_deref(),
+                &options.pakyas_bin,
+            ) {
+                Ok(true) => notes.push("wrapped".to_string()),
+                Ok(false) => notes.push("already wrapped".to_string()),
+                Err(reason) => {
+                    issues += 1;
+                    notes.push(format!("not wrapped: {}", reason));
+                }
+            }
+        }
+        row.note = notes.join("; ");
+        rows.push(row);
+    }
+
+    // The manifest owns stdout unless it goes to a file
+    let manifest_on_stdout = !options.dry_run && options.output.is_none();
+    let table = ctx.output_format() == OutputFormat::Table && !manifest_on_stdout;
+    if options.dry_run {
+        print_output(ctx, rows)?;
+        if table {
+            print_info("Dry run: no checks were created and no manifest was written");
+        }
+    } else {
+        let patched = render_documents(&documents)?;
+        match &options.output {
+            Some(path) => {
+                std::fs::write(path, patched)
+                    .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+                print_output(ctx, rows)?;
+                if table {
+                    print_success(&format!("Wrote patched manifest to {}", path.display()));
+                }
+            }
+            None => {
+                print!("{}", patched);
+                for row in &rows {
+                    eprintln!("{}: {} {} {}", row.cronjob, row.action, row.slug, row.note);
+                }
+            }
+        }
+    }
+
+    if issues > 0 {
+        if table {
+            print_warning(&format!("{} CronJob(s) were not instrumented", issues));
+        }
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+/// Split multi-document YAML, dropping empty documents
+fn parse_documents(content: &str) -> Result<Vec<Value>> {
+    let mut documents = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(content) {
+        let value = Value::deserialize(document)
+            .map_err(|e| CliError::Other(format!("Invalid manifest YAML: {}", e)))?;
+        if !value.is_null() {
+            documents.push(value);
+        }
+    }
+    Ok(documents)
+}
+
+fn render_documents(documents: &[Value]) -> Result<String> {
+    let rendered = documents
+        .iter()
+        .map(serde_yaml::to_string)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rendered.join("---\n"))
+}
+
+/// CronJobs in a document, including the items of a `kind: List`
+fn collect_cronjobs<'a>(value: &'a mut Value, out: &mut Vec<&'a mut Value>) {
+    let kind = value
+        .get("kind")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    match kind.as_deref() {
+        Some("CronJob") => out.push(value),
+        Some("List") => {
+            if let Some(items) = value.get_mut("items").and_then(Value::as_sequence_mut) {
+                for item in items {
+                    collect_cronjobs(item, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn metadata_str<'a>(cronjob: &'a Value, key: &str) -> Option<&'a str> {
+    cronjob.get("metadata")?.get(key)?.as_str()
+}
+
+/// `namespace/name`, or just the name when the manifest has no namespace
+fn qualified_name(cronjob: &Value) -> String {
+    let name = metadata_str(cronjob, "name").unwrap_or("<unnamed>");
+    match metadata_str(cronjob, "namespace") {
+        Some(namespace) => format!("{}/{}", namespace, name),
+        None => name.to_string(),
+    }
+}
+
+/// CronJob names used in more than one namespace
+fn shared_names(cronjobs: &[&mut Value]) -> HashSet<String> {
+    let mut namespaces: HashMap<&str, HashSet<Option<&str>>> = HashMap::new();
+    for cronjob in cronjobs {
+        if let Some(name) = metadata_str(cronjob, "name") {
+            namespaces
+                .entry(name)
+                .or_default()
+                .insert(metadata_str(cronjob, "namespace"));
+        }
+    }
+    namespaces
+        .into_iter()
+        .filter(|(_, namespaces)| namespaces.len() > 1)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Map a CronJob's name, schedule, and time zone onto a check, with notes for the row
+///
+/// Names in `shared` are used in several namespaces, so their slugs include the namespace.
+fn map_cronjob(
+    cronjob: &Value,
+    prefix: &str,
+    shared: &HashSet<String>,
+) -> Result<(ImportedCheck, Vec<String>), Unmapped> {
+    let qualified = qualified_name(cronjob);
+    let unmapped = |reason: String| Unmapped {
+        name: qualified.clone(),
+        reason,
+    };
+    let name = metadata_str(cronjob, "name")
+        .ok_or_else(|| unmapped("metadata.name is missing".to_string()))?;
+    let spec = cronjob.get("spec");
+    let schedule = spec
+        .and_then(|s| s.get("schedule"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| unmapped("spec.schedule is missing".to_string()))?
+        .trim();
+
+    // Older clusters accepted a CRON_TZ=/TZ= prefix in the schedule itself
+    let (inline_timezone, schedule) = match schedule.split_once(' ') {
+        Some((assignment, rest))
+            if assignment.starts_with("CRON_TZ=") || assignment.starts_with("TZ=") =>
+        {
+            let tz = assignment.split_once('=').map(|(_, tz)| tz);
+            (tz, rest.trim())
+        }
+        _ => (None, schedule),
+    };
+    let expression = if schedule.starts_with('@') {
+        expand_macro(schedule).map_err(&unmapped)?
+    } else {
+        validate_cron_expression(schedule).map_err(&unmapped)?;
+        schedule.to_string()
+    };
+
+    let mut notes = Vec::new();
+    // Without a time zone, CronJobs run in the controller's local time, nearly always UTC
+    let timezone = match spec
+        .and_then(|s| s.get("timeZone"))
+        .and_then(Value::as_str)
+        .or(inline_timezone)
+    {
+        Some(tz) => {
+            validate_timezone(tz).map_err(|e| unmapped(e.to_string()))?;
+            tz
+        }
+        None => {
+            notes.push("no time zone; using UTC".to_string());
+            "UTC"
+        }
+    };
+
+    let slug = match metadata_str(cronjob, "namespace").filter(|_| shared.contains(name)) {
+        Some(namespace) => {
+            notes.push("slug includes namespace".to_string());
+            format!("{}{}", prefix, slugify(&format!("{}-{}", namespace, name)))
+        }
+        None => format!("{}{}", prefix, slugify(name)),
+    };
+    validate_slug(&slug).map_err(|e| unmapped(e.to_string()))?;
+
+    let check = ImportedCheck {
+        name: slug_to_title(&slugify(name)),
+        slug,
+        description: Some(format!("Kubernetes CronJob {}", qualified)),
+        tags: vec!["kubernetes".to_string()],
+        schedule: ImportedSchedule::Cron {
+            expression,
+            timezone: Some(timezone.to_string()),
+        },
+        grace_seconds: None,
+        external_id: None,
+    };
+    Ok((check, notes))
+}
+
+/// Wrap the CronJob's container command and set `PAKYAS_PUBLIC_ID`
+///
+/// Returns false when the container already runs through `pakyas monitor`. The image
+/// entrypoint can't be read offline, so a container without `command` is an error.
+fn wrap_cronjob(
+    cronjob: &mut Value,
+    public_id: Uuid,
+    container_name: Option<&str>,
+    pakyas_bin: &str,
+) -> Result<bool, String> {
+    let containers = cronjob
+        .get_mut("spec")
+        .and_then(|v| v.get_mut("jobTemplate"))
+        .and_then(|v| v.get_mut("spec"))
+        .and_then(|v| v.get_mut("template"))
+        .and_then(|v| v.get_mut("spec"))
+        .and_then(|v| v.get_mut("containers"))
+        .and_then(Value::as_sequence_mut)
+        .ok_or("spec.jobTemplate.spec.template.spec.containers is missing")?;
+
+    let count = containers.len();
+    let container = match container_name {
+        Some(wanted) => containers
+            .iter_mut()
+            .find(|c| c.get("name").and_then(Value::as_str) == Some(wanted))
+            .ok_or_else(|| format!("no container named '{}'", wanted))?,
+        None if count == 1 => &mut containers[0],
+        None => return Err(format!("{} containers; pick one with --container", count)),
+    };
+    let container = container
+        .as_mapping_mut()
+        .ok_or("container is not a mapping")?;
+
+    let command: Vec<String> = match container.get("command").and_then(Value::as_sequence) {
+        Some(command) => command
+            .iter()
+            .map(|v| v.as_str().map(str::to_string))
+            .collect::<Option<_>>()
+            .ok_or("command has non-string entries")?,
+        None => {
+            return Err(
+                "no command set (the image entrypoint can't be wrapped offline)".to_string(),
+            );
+        }
+    };
+    if command.get(1).is_some_and(|arg| arg == "monitor")
+        && command.iter().any(|arg| arg == "--public-id")
+    {
+        return Ok(false);
+    }
+
+    let public_id = public_id.to_string();
+    let wrapped: Vec<Value> = [pakyas_bin, "monitor", "--public-id", &public_id, "--"]
+        .into_iter()
+        .map(str::to_string)
+        .chain(command)
+        .map(Value::String)
+        .collect();
+    container.insert("command".into(), Value::Sequence(wrapped));
+    set_env(container, PUBLIC_ID_ENV, &public_id)?;
+
+    Ok(true)
+}
+
+/// Set an env var on a container, replacing an existing entry with the same name
+fn set_env(container: &mut Mapping, name: &str, value: &str) -> Result<(), String> {
+    let env = container
+        .entry("env".into())
+        .or_insert_with(|| Value::Sequence(Vec::new()))
+        .as_sequence_mut()
+        .ok_or("env is not a list")?;
+    env.retain(|e| e.get("name").and_then(Value::as_str) != Some(name));
+
+    let mut entry = Mapping::new();
+    entry.insert("name".into(), name.into());
+    entry.insert("value".into(), value.into());
+    env.push(Value::Mapping(entry));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "\
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: settings
+---
+apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: nightly-report
+  namespace: billing
+spec:
+  schedule: \"30 2 * * *\"
+  timeZone: Europe/Riga
+  jobTemplate:
+    spec:
+      template:
+        spec:
+          containers:
+            - name: report
+              image: billing:1.4
+              command: [\"/app/report\"]
+              args: [\"--since\", \"1d\"]
+              env:
+                - name: PAKYAS_PUBLIC_ID
+                  value: stale
+          restartPolicy: OnFailure
+";
+
+    fn cronjob(documents: &mut [Value]) -> &mut Value {
+        let mut found = Vec::new();
+        for document in documents.iter_mut() {
+            collect_cronjobs(document, &mut found);
+        }
+        found.pop().unwrap()
+    }
+
+    #[test]
+    fn test_map_cronjob() {
+        let mut documents = parse_documents(MANIFEST).unwrap();
+        assert_eq!(documents.len(), 2);
+
+        let (check, notes) =
+            map_cronjob(cronjob(&mut documents), "prod-", &HashSet::new()).unwrap();
+        assert!(notes.is_empty());
+        assert_eq!(check.slug, "prod-nightly-report");
+        assert_eq!(check.name, "Nightly Report");
+        assert_eq!(
+            check.schedule,
+            ImportedSchedule::Cron {
+                expression: "30 2 * * *".to_string(),
+                timezone: Some("Europe/Riga".to_string()),
+            }
+        );
+        assert_eq!(
+            check.description.as_deref(),
+            Some("Kubernetes CronJob billing/nightly-report")
+        );
+    }
+
+    #[test]
+    fn test_map_cronjob_schedules() {
+        let job = |schedule: &str| -> Value {
+            serde_yaml::from_str(&format!(
+                "kind: CronJob\nmetadata:\n  name: sync\nspec:\n  schedule: \"{}\"\n",
+                schedule
+            ))
+            .unwrap()
+        };
+
+        let (hourly, notes) = map_cronjob(&job("@hourly"), "", &HashSet::new()).unwrap();
+        assert_eq!(
+            hourly.schedule,
+            ImportedSchedule::Cron {
+                expression: "0 * * * *".to_string(),
+                timezone: Some("UTC".to_string()),
+            }
+        );
+        assert_eq!(notes, ["no time zone; using UTC"]);
+        let (inline, notes) =
+            map_cronjob(&job("CRON_TZ=Asia/Tokyo 0 6 * * *"), "", &HashSet::new()).unwrap();
+        assert_eq!(
+            inline.schedule,
+            ImportedSchedule::Cron {
+                expression: "0 6 * * *".to_string(),
+                timezone: Some("Asia/Tokyo".to_string()),
+            }
+        );
+        assert!(notes.is_empty());
+        assert!(map_cronjob(&job("every day"), "", &HashSet::new()).is_err());
+    }
+
+    #[test]
+    fn test_same_name_in_two_namespaces() {
+        let job = |namespace: &str| {
+            format!(
+                "kind: CronJob\nmetadata:\n  name: cleanup\n  namespace: {}\nspec:\n  schedule: \"0 * * * *\"\n",
+                namespace
+            )
+        };
+        let content = format!("{}---\n{}---\n{}", job("billing"), job("ops"), job("ops"));
+        let mut documents = parse_documents(&content).unwrap();
+        let mut cronjobs = Vec::new();
+        for document in documents.iter_mut() {
+            collect_cronjobs(document, &mut cronjobs);
+        }
+
+        let shared = shared_names(&cronjobs);
+        let slugs: Vec<String> = cronjobs
+            .iter()
+            .map(|c| map_cronjob(c, "", &shared).unwrap().0.slug)
+            .collect();
+        assert_eq!(slugs, ["billing-cleanup", "ops-cleanup", "ops-cleanup"]);
+
+        let (_, notes) = map_cronjob(cronjobs[0], "", &shared).unwrap();
+        assert_eq!(
+            notes,
+            ["no time zone; using UTC", "slug includes namespace"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_cronjob() {
+        let mut documents = parse_documents(MANIFEST).unwrap();
+        let public_id = Uuid::nil();
+        let job = cronjob(&mut documents);
+
+        assert_eq!(wrap_cronjob(job, public_id, None, "pakyas"), Ok(true));
+        let container = &job["spec"]["jobTemplate"]["spec"]["template"]["spec"]["containers"][0];
+        let command: Vec<&str> = container["command"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        assert_eq!(
+            command,
+            vec![
+                "pakyas",
+                "monitor",
+                "--public-id",
+                "00000000-0000-0000-0000-000000000000",
+                "--",
+                "/app/report"
+            ]
+        );
+        let env = container["env"].as_sequence().unwrap();
+        assert_eq!(env.len(), 1);
+        assert_eq!(env[0]["value"], Value::from(public_id.to_string()));
+
+        // Wrapping again leaves the container alone
+        assert_eq!(wrap_cronjob(job, public_id, None, "pakyas"), Ok(false));
+
+        let rendered = render_documents(&documents).unwrap();
+        assert!(rendered.contains("kind: ConfigMap"));
+        assert_eq!(parse_documents(&rendered).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_wrap_cronjob_errors() {
+        let mut job: Value = serde_yaml::from_str(
+            "kind: CronJob\nspec:\n  jobTemplate:\n    spec:\n      template:\n        spec:\n          containers:\n            - name: a\n              image: x\n            - name: b\n              image: y\n              command: [run]\n",
+        )
+        .unwrap();
+
+        assert!(
+            wrap_cronjob(&mut job, Uuid::nil(), None, "pakyas")
+                .unwrap_err()
+                .contains("--container")
+        );
+        assert!(
+            wrap_cronjob(&mut job, Uuid::nil(), Some("a"), "pakyas")
+                .unwrap_err()
+                .contains("entrypoint")
+        );
+        assert_eq!(
+            wrap_cronjob(&mut job, Uuid::nil(), Some("b"), "pakyas"),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn test_collect_cronjobs_from_list() {
+        let mut list: Value = serde_yaml::from_str(
+            "kind: List\nitems:\n  - kind: CronJob\n    metadata: {name: a}\n  - kind: Service\n  - kind: CronJob\n    metadata: {name: b}\n",
+        )
+        .unwrap();
+        let mut found = Vec::new();
+        collect_cronjobs(&mut list, &mut found);
+        assert_eq!(found.len(), 2);
+    }
+}
@@ -0,0 +1,551 @@
+//! Diff desired manifest checks against the checks that exist in a project.
+
+use super::format::{DesiredCheck, DesiredSchedule};
+use crate::commands::check::{
+    Check, CreateCheckRequest, UpdateCheckRequest, format_duration, slug_to_title, smart_grace,
+};
+use crate::cron::effective_period_from_cron;
+use uuid::Uuid;
+
+/// What `apply` will do to one check
+#[derive(Debug)]
+pub enum Action {
+    Create(CreateCheckRequest),
+    Update {
+        check: Box<Check>,
+        req: UpdateCheckRequest,
+    },
+    Delete(Box<Check>),
+    Unchanged,
+    /// Exists on the server but not in the manifest (deleted only with --prune)
+    Unmanaged,
+}
+
+impl Action {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::Create(_) => "create",
+            Action::Update { .. } => "update",
+            Action::Delete(_) => "delete",
+            Action::Unchanged => "unchanged",
+            Action::Unmanaged => "unmanaged",
+        }
+    }
+
+    /// Whether `apply` makes an API call for this action
+    pub fn is_change(&self) -> bool {
+        matches!(
+            self,
+            Action::Create(_) | Action::Update { .. } | Action::Delete(_)
+        )
+    }
+}
+
+/// One planned action with a human-readable summary of what changes
+#[derive(Debug)]
+pub struct PlannedChange {
+    pub project: String,
+    pub slug: String,
+    pub action: Action,
+    pub changes: Vec<String>,
+}
+
+fn schedule_display(schedule: &DesiredSchedule) -> String {
+    match schedule {
+        DesiredSchedule::Cron {
+            expression,
+            timezone: Some(tz),
+        } => format!("cron {} ({})", expression, tz),
+        DesiredSchedule::Cron { expression, .. } => format!("cron {}", expression),
+        DesiredSchedule::Interval { period_seconds } => {
+            format!("every {}", format_duration(*period_seconds))
+        }
+    }
+}
+
+fn period_of(schedule: &DesiredSchedule) -> i32 {
+    match schedule {
+        DesiredSchedule::Cron { expression, .. } => {
+            effective_period_from_cron(expression).unwrap_or(3600)
+        }
+        DesiredSchedule::Interval { period_seconds } => *period_seconds,
+    }
+}
+
+fn or_none(value: Option<&str>) -> &str {
+    value.filter(|v| !v.is_empty()).unwrap_or("(none)")
+}
+
+/// Build the create request for a check that doesn't exist yet
+pub fn create_request(project_id: Uuid, desired: &DesiredCheck) -> CreateCheckRequest {
+    let period_seconds = period_of(&desired.schedule);
+    let (cron_expression, timezone) = match &desired.schedule {
+        DesiredSchedule::Cron {
+            expression,
+            timezone,
+        } => (Some(expression.clone()), timezone.clone()),
+        DesiredSchedule::Interval { .. } => (None, None),
+    };
+
+    CreateCheckRequest {
+        project_id,
+        name: desired
+            .name
+            .clone()
+            .unwrap_or_else(|| slug_to_title(&desired.slug)),
+        slug: desired.slug.clone(),
+        period_seconds,
+        missing_after_seconds: desired
+            .grace_seconds
+            .unwrap_or_else(|| smart_grace(period_seconds)),
+        description: desired.description.clone(),
+        cron_expression,
+        timezone,
+        tags: desired.tags.clone(),
+        alert_after_miss_pings: desired.alert_after_miss_pings,
+        alert_after_fail_pings: desired.alert_after_fail_pings,
+        max_runtime_seconds: desired.max_runtime_seconds,
+        alerts: desired.alerts.clone(),
+    }
+}
+
+/// Build the update request for the managed fields that differ, with a change summary
+pub fn update_request(check: &Check, desired: &DesiredCheck) -> (UpdateCheckRequest, Vec<String>) {
+    let mut req = UpdateCheckRequest::default();
+    let mut changes = Vec::new();
+
+    if let Some(name) = desired.name.as_ref().filter(|n| **n != check.name) {
+        changes.push(format!("name: {} -> {}", check.name, name));
+        req.name = Some(name.clone());
+    }
+
+    if let Some(description) = &desired.description {
+        if check.description.as_deref().unwrap_or("") != description {
+            changes.push(format!(
+                "description: {} -> {}",
+                or_none(check.description.as_deref()),
+                or_none(Some(description))
+            ));
+            req.description = Some(description.clone());
+        }
+    }
+
+    let current_cron = check.cron_expression.as_deref().filter(|c| !c.is_empty());
+    match &desired.schedule {
+        DesiredSchedule::Cron {
+            expression,
+            timezone,
+        } => {
+            if current_cron != Some(expression.as_str()) {
+                changes.push(format!("cron: {} -> {}", or_none(current_cron), expression));
+                req.cron_expression = Some(expression.clone());
+                req.period_seconds = Some(period_of(&desired.schedule));
+            }
+            if let Some(tz) = timezone {
+                if check.timezone.as_deref() != Some(tz.as_str()) {
+                    changes.push(format!(
+                        "tz: {} -> {}",
+                        or_none(check.timezone.as_deref()),
+                        tz
+                    ));
+                    req.timezone = Some(tz.clone());
+                }
+            }
+        }
+        DesiredSchedule::Interval { period_seconds } => {
+            if let Some(cron) = current_cron {
+                // Switching to an interval clears cron and timezone
+                changes.push(format!(
+                    "schedule: cron {} -> every {}",
+                    cron,
+                    format_duration(*period_seconds)
+                ));
+                req.cron_expression = Some(String::new());
+                req.timezone = Some(String::new());
+                req.period_seconds = Some(*period_seconds);
+            } else if check.period_seconds != *period_seconds {
+                changes.push(format!(
+                    "every: {} -> {}",
+                    format_duration(check.period_seconds),
+                    format_duration(*period_seconds)
+                ));
+                req.period_seconds = Some(*period_seconds);
+            }
+        }
+    }
+
+    if let Some(grace) = desired
+        .grace_seconds
+        .filter(|g| *g != check.missing_after_seconds)
+    {
+        changes.push(format!(
+            "grace: {} -> {}",
+            format_duration(check.missing_after_seconds),
+            format_duration(grace)
+        ));
+        req.missing_after_seconds = Some(grace);
+    }
+
+    if let Some(tags) = &desired.tags {
+        let mut current = check.tags.clone();
+        let mut wanted = tags.clone();
+        current.sort();
+        wanted.sort();
+        if current != wanted {
+            changes.push(format!(
+                "tags: [{}] -> [{}]",
+                check.tags.join(", "),
+                tags.join(", ")
+            ));
+            req.tags = Some(tags.clone());
+        }
+    }
+
+    // Same field pairing as `check update`
+    if let Some(value) = desired
+        .alert_after_miss_pings
+        .filter(|v| check.alert_after_failures != Some(*v))
+    {
+        changes.push(format!(
+            "alert_after_miss_pings: {} -> {}",
+            check
+                .alert_after_failures
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "inherited".to_string()),
+            value
+        ));
+        req.alert_after_miss_pings = Some(value);
+    }
+    if let Some(value) = desired
+        .alert_after_fail_pings
+        .filter(|v| check.missed_before_alert != *v)
+    {
+        changes.push(format!(
+            "alert_after_fail_pings: {} -> {}",
+            check.missed_before_alert, value
+        ));
+        req.alert_after_fail_pings = Some(value);
+    }
+
+    if let Some(value) = desired
+        .max_runtime_seconds
+        .filter(|v| check.max_runtime_seconds != Some(*v))
+    {
+        changes.push(format!(
+            "max_runtime: {} -> {}",
+            check
+                .max_runtime_seconds
+                .map(format_duration)
+                .unwrap_or_else(|| "(none)".to_string()),
+            format_duration(value)
+        ));
+        req.max_runtime_seconds = Some(value);
+    }
+
+    // Unset toggles on the server follow the server default
+    let wanted = &desired.alerts;
+    for (field, value, current, slot) in [
+        (
+            "alert_on_down",
+            wanted.alert_on_down,
+            check.alert_on_down,
+            &mut req.alerts.alert_on_down,
+        ),
+        (
+            "alert_on_late",
+            wanted.alert_on_late,
+            check.alert_on_late,
+            &mut req.alerts.alert_on_late,
+        ),
+        (
+            "alert_on_overrun",
+            wanted.alert_on_overrun,
+            check.alert_on_overrun,
+            &mut req.alerts.alert_on_overrun,
+        ),
+        (
+            "alert_on_anomaly",
+            wanted.alert_on_anomaly,
+            check.alert_on_anomaly,
+            &mut req.alerts.alert_on_anomaly,
+        ),
+        (
+            "notify_on_recovery",
+            wanted.notify_on_recovery,
+            check.notify_on_recovery,
+            &mut req.alerts.notify_on_recovery,
+        ),
+    ] {
+        if let Some(value) = value.filter(|v| current != Some(*v)) {
+            changes.push(format!(
+                "{}: {} -> {}",
+                field,
+                current
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| "(default)".to_string()),
+                value
+            ));
+            *slot = Some(value);
+        }
+    }
+
+    (req, changes)
+}
+
+/// Plan the actions for one project
+///
+/// Checks on the server that the manifest doesn't list are deleted with `prune`,
+/// and reported as unmanaged otherwise.
+pub fn diff_project(
+    project: &str,
+    project_id: Uuid,
+    desired: &[DesiredCheck],
+    current: &[Check],
+    prune: bool,
+) -> Vec<PlannedChange> {
+    let mut planned = Vec::new();
+
+    for wanted in desired {
+        let existing = current.iter().find(|c| c.slug == wanted.slug);
+        let (action, changes) = match existing {
+            None => (
+                Action::Create(create_request(project_id, wanted)),
+                vec![schedule_display(&wanted.schedule)],
+            ),
+            Some(check) => {
+                let (req, changes) = update_request(check, wanted);
+                if changes.is_empty() {
+                    (Action::Unchanged, changes)
+                } else {
+                    (
+                        Action::Update {
+                            check: Box::new(check.clone()),
+                            req,
+                        },
+                        changes,
+                    )
+                }
+            }
+        };
+        planned.push(PlannedChange {
+            project: project.to_string(),
+            slug: wanted.slug.clone(),
+            action,
+            changes,
+        });
+    }
+
+    for check in current {
+        if desired.iter().any(|d| d.slug == check.slug) {
+            continue;
+        }
+        let action = if prune {
+            Action::Delete(Box::new(check.clone()))
+        } else {
+            Action::Unmanaged
+        };
+        planned.push(PlannedChange {
+            project: project.to_string(),
+            slug: check.slug.clone(),
+            action,
+            changes: Vec::new(),
+        });
+    }
+
+    planned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::check::AlertSettings;
+    use crate::commands::manifest::format::{Manifest, ManifestCheck, ManifestProject};
+
+    fn server_check(slug: &str) -> Check {
+        serde_json::from_value(serde_json::json!({
+            "id": Uuid::nil(),
+            "public_id": Uuid::nil(),
+            "project_id": Uuid::nil(),
+            "name": slug_to_title(slug),
+            "slug": slug,
+            "tags": ["db"],
+            "description": null,
+            "period_seconds": 86400,
+            "missing_after_seconds": 600,
+            "cron_expression": "0 2 * * *",
+            "timezone": "Europe/Berlin",
+            "status": "up",
+            "last_ping_at": null,
+            "next_ping_expected_at": null,
+            "created_at": "2026-01-01T00:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    fn desired(slug: &str, schedule: DesiredSchedule) -> DesiredCheck {
+        DesiredCheck {
+            slug: slug.to_string(),
+            name: None,
+            description: None,
+            schedule,
+            grace_seconds: None,
+            tags: None,
+            alert_after_miss_pings: None,
+            alert_after_fail_pings: None,
+            max_runtime_seconds: None,
+            alerts: AlertSettings::default(),
+        }
+    }
+
+    fn nightly() -> DesiredSchedule {
+        DesiredSchedule::Cron {
+            expression: "0 2 * * *".to_string(),
+            timezone: Some("Europe/Berlin".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_unmanaged_fields_are_unchanged() {
+        let check = server_check("backup-db");
+        let (_, changes) = update_request(&check, &desired("backup-db", nightly()));
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_update_only_changed_fields() {
+        let check = server_check("backup-db");
+        let mut wanted = desired("backup-db", nightly());
+        wanted.grace_seconds = Some(900);
+        wanted.tags = Some(vec!["db".to_string(), "prod".to_string()]);
+        wanted.name = Some("Backup Db".to_string());
+
+        let (req, changes) = update_request(&check, &wanted);
+
+        assert_eq!(req.missing_after_seconds, Some(900));
+        assert_eq!(req.tags, Some(vec!["db".to_string(), "prod".to_string()]));
+        assert!(req.name.is_none());
+        assert!(req.cron_expression.is_none());
+        assert_eq!(changes.len(), 2);
+    }
+
+    #[test]
+    fn test_switch_cron_to_interval_clears_cron() {
+        let check = server_check("backup-db");
+        let wanted = desired(
+            "backup-db",
+            DesiredSchedule::Interval {
+                period_seconds: 3600,
+            },
+        );
+
+        let (req, _) = update_request(&check, &wanted);
+
+        assert_eq!(req.cron_expression.as_deref(), Some(""));
+        assert_eq!(req.timezone.as_deref(), Some(""));
+        assert_eq!(req.period_seconds, Some(3600));
+    }
+
+    #[test]
+    fn test_diff_project_create_update_prune() {
+        let current = vec![server_check("backup-db"), server_check("legacy")];
+        let mut changed = desired("backup-db", nightly());
+        changed.grace_seconds = Some(60);
+        let wanted = vec![
+            changed,
+            desired(
+                "heartbeat",
+                DesiredSchedule::Interval {
+                    period_seconds: 300,
+                },
+            ),
+        ];
+
+        let labels = |prune| {
+            diff_project("infra", Uuid::nil(), &wanted, &current, prune)
+                .iter()
+                .map(|p| (p.slug.clone(), p.action.label()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            labels(false),
+            vec![
+                ("backup-db".to_string(), "update"),
+                ("heartbeat".to_string(), "create"),
+                ("legacy".to_string(), "unmanaged"),
+            ]
+        );
+        assert_eq!(labels(true)[2], ("legacy".to_string(), "delete"));
+    }
+
+    #[test]
+    fn test_create_request_defaults() {
+        let req = create_request(
+            Uuid::nil(),
+            &desired(
+                "heartbeat",
+                DesiredSchedule::Interval {
+                    period_seconds: 300,
+                },
+            ),
+        );
+        assert_eq!(req.name, "Heartbeat");
+        assert_eq!(req.missing_after_seconds, smart_grace(300));
+        assert!(req.cron_expression.is_none());
+    }
+
+    #[test]
+    fn test_alert_toggles_diffed() {
+        let mut check = server_check("backup-db");
+        check.alert_on_late = Some(true);
+        let mut wanted = desired("backup-db", nightly());
+        wanted.alerts.alert_on_late = Some(false);
+        wanted.alerts.notify_on_recovery = Some(false);
+
+        let (req, changes) = update_request(&check, &wanted);
+
+        assert_eq!(req.alerts.alert_on_late, Some(false));
+        assert_eq!(req.alerts.notify_on_recovery, Some(false));
+        assert!(req.alerts.alert_on_down.is_none());
+        assert_eq!(
+            changes,
+            [
+                "alert_on_late: true -> false",
+                "notify_on_recovery: (default) -> false"
+            ]
+        );
+        assert_eq!(create_request(Uuid::nil(), &wanted).alerts, wanted.alerts);
+    }
+
+    #[test]
+    fn test_export_then_plan_is_unchanged() {
+        let mut check = server_check("backup-db");
+        check.alert_on_down = Some(false);
+        check.alert_on_anomaly = Some(true);
+        check.notify_on_recovery = Some(false);
+
+        let exported = ManifestCheck::from_check(&check);
+        assert_eq!(exported.alert_on_down, Some(false));
+        assert_eq!(exported.notify_on_recovery, Some(false));
+
+        let manifest = Manifest {
+            projects: [(
+                "infra".to_string(),
+                ManifestProject {
+                    checks: [("backup-db".to_string(), exported)].into(),
+                },
+            )]
+            .into(),
+        };
+        let wanted = manifest.validate().unwrap().remove("infra").unwrap();
+        let planned = diff_project("infra", Uuid::nil(), &wanted, &[check], false);
+
+        assert_eq!(planned.len(), 1);
+        assert_eq!(
+            planned[0].action.label(),
+            "unchanged",
+            "{:?}",
+            planned[0].changes
+        );
+    }
+}
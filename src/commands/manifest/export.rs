@@ -0,0 +1,159 @@
+//! Export existing checks as a manifest that `plan`/`apply` accept.
+
+use super::format::{Manifest, ManifestCheck, ManifestFormat};
+use crate::cli::ExportArgs;
+use crate::client::ApiClient;
+use crate::commands::check::CheckWithProject;
+use crate::commands::project::{Project, resolve_project};
+use crate::config::Context;
+use crate::output::print_success;
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+
+/// Write the current checks (optionally filtered) to a manifest file or stdout
+pub async fn export(ctx: &Context, args: ExportArgs, verbose: bool) -> Result<()> {
+    let org_id = ctx.require_org()?;
+    let client = ApiClient::new(ctx)?;
+
+    let projects: Vec<Project> = match &args.project {
+        Some(identifier) => vec![resolve_project(ctx, identifier).await?],
+        None => {
+            client
+                .get(&format!("/api/v1/projects?org_id={}", org_id))
+                .await?
+        }
+    };
+    let url = match &args.project {
+        Some(_) => format!("/api/v1/checks?project_id={}", projects[0].id),
+        None => format!("/api/v1/checks?org_id={}", org_id),
+    };
+    if verbose {
+        eprintln!("[verbose] Fetching checks from: {}", url);
+    }
+    let checks: Vec<CheckWithProject> = client.get(&url).await?;
+
+    let project_keys: HashMap<_, _> = projects.iter().map(|p| (p.id, p.slug.clone())).collect();
+    let manifest = build_manifest(&checks, &project_keys, args.tag.as_deref());
+    let count: usize = manifest.projects.values().map(|p| p.checks.len()).sum();
+    if verbose {
+        eprintln!("[verbose] Exporting {} check(s)", count);
+    }
+
+    match &args.output {
+        Some(path) => {
+            let content = manifest.render(ManifestFormat::from_path(path))?;
+            std::fs::write(path, content)
+                .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+            print_success(&format!(
+                "Exported {} check(s) to {}",
+                count,
+                path.display()
+            ));
+        }
+        None => print!("{}", manifest.render(ManifestFormat::Yaml)?),
+    }
+
+    Ok(())
+}
+
+/// Group checks by project slug, skipping deleted checks and those without `tag`
+fn build_manifest(
+    checks: &[CheckWithProject],
+    project_keys: &HashMap<uuid::Uuid, String>,
+    tag: Option<&str>,
+) -> Manifest {
+    let mut manifest = Manifest::default();
+    for entry in checks {
+        let check = &entry.check;
+        if check.soft_deleted || tag.is_some_and(|t| !check.tags.iter().any(|c| c == t)) {
+            continue;
+        }
+        // Fall back to the project name, which `apply` also resolves
+        let key = project_keys
+            .get(&check.project_id)
+            .cloned()
+            .unwrap_or_else(|| entry.project_name.clone());
+        manifest
+            .projects
+            .entry(key)
+            .or_default()
+            .checks
+            .insert(check.slug.clone(), ManifestCheck::from_check(check));
+    }
+    manifest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::manifest::diff::update_request;
+
+    fn entry(slug: &str, tags: &[&str], extra: serde_json::Value) -> CheckWithProject {
+        let mut json = serde_json::json!({
+            "id": uuid::Uuid::nil(),
+            "public_id": uuid::Uuid::nil(),
+            "project_id": uuid::Uuid::nil(),
+            "project_name": "Infra",
+            "name": "Some Check",
+            "slug": slug,
+            "tags": tags,
+            "description": null,
+            "period_seconds": 300,
+            "missing_after_seconds": 90,
+            "status": "up",
+            "last_ping_at": null,
+            "next_ping_expected_at": null,
+            "created_at": "2026-01-01T00:00:00Z"
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_build_manifest_filters_and_groups() {
+        let checks = vec![
+            entry("heartbeat", &["prod"], serde_json::json!({})),
+            entry("staging", &["staging"], serde_json::json!({})),
+            entry("gone", &["prod"], serde_json::json!({"soft_deleted": true})),
+        ];
+        let keys = HashMap::from([(uuid::Uuid::nil(), "infra".to_string())]);
+
+        let manifest = build_manifest(&checks, &keys, Some("prod"));
+        let exported = &manifest.projects["infra"].checks;
+        assert_eq!(exported.keys().collect::<Vec<_>>(), vec!["heartbeat"]);
+
+        let heartbeat = &exported["heartbeat"];
+        assert_eq!(heartbeat.every.as_deref(), Some("5m"));
+        assert_eq!(heartbeat.grace.as_deref(), Some("90s"));
+        assert!(heartbeat.cron.is_none());
+
+        // Unknown projects fall back to the project name
+        let manifest = build_manifest(&checks, &HashMap::new(), None);
+        assert_eq!(manifest.projects["Infra"].checks.len(), 2);
+    }
+
+    #[test]
+    fn test_exported_check_plans_no_changes() {
+        let exported = entry(
+            "backup-db",
+            &["db"],
+            serde_json::json!({
+                "cron_expression": "0 2 * * *",
+                "timezone": "Europe/Berlin",
+                "period_seconds": 86400,
+                "description": "Nightly dump",
+                "alert_after_failures": 3,
+                "missed_before_alert": 2,
+                "max_runtime_seconds": 5400
+            }),
+        );
+        let keys = HashMap::from([(uuid::Uuid::nil(), "infra".to_string())]);
+        let manifest = build_manifest(std::slice::from_ref(&exported), &keys, None);
+
+        let desired = manifest.validate().unwrap();
+        let (_, changes) = update_request(&exported.check, &desired["infra"][0]);
+        assert!(changes.is_empty(), "unexpected changes: {:?}", changes);
+    }
+}
@@ -0,0 +1,410 @@
+//! Manifest file format: projects and their checks, keyed by project and check slug.
+//!
+//! ```yaml
+//! projects:
+//!   infra:
+//!     checks:
+//!       backup-db:
+//!         cron: "0 2 * * *"
+//!         tz: Europe/Berlin
+//!         grace: 10m
+//!         tags: [db]
+//!       heartbeat:
+//!         every: 5m
+//! ```
+//!
+//! Fields left out are not managed: `apply` leaves them as they are on the server.
+//! Each check needs exactly one of `cron` or `every`.
+
+use crate::commands::check::{
+    AlertSettings, Check, parse_duration, validate_cron_cli, validate_slug, validate_timezone,
+};
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Serialization format of a manifest file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    Yaml,
+    Toml,
+}
+
+impl ManifestFormat {
+    /// Pick the format from the file extension (YAML unless `.toml`)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => ManifestFormat::Toml,
+            _ => ManifestFormat::Yaml,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// Keyed by project ID, slug, or name
+    #[serde(default)]
+    pub projects: BTreeMap<String, ManifestProject>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestProject {
+    /// Keyed by check slug
+    #[serde(default)]
+    pub checks: BTreeMap<String, ManifestCheck>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestCheck {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cron: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_after_miss_pings: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_after_fail_pings: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_runtime: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_on_down: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_on_late: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_on_overrun: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_on_anomaly: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify_on_recovery: Option<bool>,
+}
+
+/// Validated schedule of a desired check
+#[derive(Debug, Clone, PartialEq)]
+pub enum DesiredSchedule {
+    Cron {
+        expression: String,
+        timezone: Option<String>,
+    },
+    Interval {
+        period_seconds: i32,
+    },
+}
+
+/// A validated manifest check with durations parsed to seconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesiredCheck {
+    pub slug: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub schedule: DesiredSchedule,
+    pub grace_seconds: Option<i32>,
+    pub tags: Option<Vec<String>>,
+    pub alert_after_miss_pings: Option<i32>,
+    pub alert_after_fail_pings: Option<i32>,
+    pub max_runtime_seconds: Option<i32>,
+    pub alerts: AlertSettings,
+}
+
+impl Manifest {
+    /// Load a manifest from a file, or YAML from stdin when `path` is `-`
+    pub fn load(path: &Path) -> Result<Self> {
+        if path == Path::new("-") {
+            let content = std::io::read_to_string(std::io::stdin())?;
+            return Self::parse(&content, ManifestFormat::Yaml);
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        Self::parse(&content, ManifestFormat::from_path(path))
+            .map_err(|e| anyhow!("{}: {}", path.display(), e))
+    }
+
+    /// Parse manifest content
+    pub fn parse(content: &str, format: ManifestFormat) -> Result<Self> {
+        match format {
+            ManifestFormat::Yaml => {
+                serde_yaml::from_str(content).map_err(|e| anyhow!("Invalid manifest: {}", e))
+            }
+            ManifestFormat::Toml => {
+                toml::from_str(content).map_err(|e| anyhow!("Invalid manifest: {}", e))
+            }
+        }
+    }
+
+    /// Serialize the manifest
+    pub fn render(&self, format: ManifestFormat) -> Result<String> {
+        match format {
+            ManifestFormat::Yaml => Ok(serde_yaml::to_string(self)?),
+            ManifestFormat::Toml => Ok(toml::to_string(self)?),
+        }
+    }
+
+    /// Validate every check, reporting all problems at once
+    ///
+    /// Returns the desired checks per project key.
+    pub fn validate(&self) -> Result<BTreeMap<String, Vec<DesiredCheck>>> {
+        let mut desired = BTreeMap::new();
+        let mut errors = Vec::new();
+
+        for (project, manifest_project) in &self.projects {
+            let mut checks = Vec::new();
+            for (slug, check) in &manifest_project.checks {
+                match check.validate(slug) {
+                    Ok(check) => checks.push(check),
+                    Err(e) => errors.push(format!("projects.{}.checks.{}: {}", project, slug, e)),
+                }
+            }
+            desired.insert(project.clone(), checks);
+        }
+
+        if errors.is_empty() {
+            Ok(desired)
+        } else {
+            Err(anyhow!("Invalid manifest:\n  {}", errors.join("\n  ")))
+        }
+    }
+}
+
+/// Format seconds in the largest unit that divides them exactly, so it parses back losslessly
+fn manifest_duration(seconds: i32) -> String {
+    match seconds {
+        s if s > 0 && s % 86400 == 0 => format!("{}d", s / 86400),
+        s if s > 0 && s % 3600 == 0 => format!("{}h", s / 3600),
+        s if s > 0 && s % 60 == 0 => format!("{}m", s / 60),
+        s => format!("{}s", s),
+    }
+}
+
+impl ManifestCheck {
+    /// Describe an existing check, leaving out server-only fields (ids, status, pings)
+    pub fn from_check(check: &Check) -> Self {
+        let cron = check.cron_expression.clone().filter(|c| !c.is_empty());
+        let (tz, every) = match &cron {
+            Some(_) => (check.timezone.clone().filter(|t| !t.is_empty()), None),
+            None => (None, Some(manifest_duration(check.period_seconds))),
+        };
+
+        ManifestCheck {
+            name: Some(check.name.clone()),
+            description: check.description.clone().filter(|d| !d.is_empty()),
+            cron,
+            tz,
+            every,
+            grace: Some(manifest_duration(check.missing_after_seconds)),
+            tags: Some(check.tags.clone()).filter(|t| !t.is_empty()),
+            // Same field pairing as `check update`
+            alert_after_miss_pings: check.alert_after_failures,
+            alert_after_fail_pings: Some(check.missed_before_alert),
+            max_runtime: check.max_runtime_seconds.map(manifest_duration),
+            alert_on_down: check.alert_on_down,
+            alert_on_late: check.alert_on_late,
+            alert_on_overrun: check.alert_on_overrun,
+            alert_on_anomaly: check.alert_on_anomaly,
+            notify_on_recovery: check.notify_on_recovery,
+        }
+    }
+
+    fn validate(&self, slug: &str) -> Result<DesiredCheck> {
+        validate_slug(slug)?;
+
+        let schedule = match (&self.cron, &self.every) {
+            (Some(_), Some(_)) => return Err(anyhow!("set only one of 'cron' or 'every'")),
+            (None, None) => return Err(anyhow!("missing schedule: set 'cron' or 'every'")),
+            (Some(cron), None) => {
+                validate_cron_cli(cron)?;
+                if let Some(tz) = &self.tz {
+                    validate_timezone(tz)?;
+                }
+                DesiredSchedule::Cron {
+                    expression: cron.clone(),
+                    timezone: self.tz.clone(),
+                }
+            }
+            (None, Some(every)) => {
+                if self.tz.is_some() {
+                    return Err(anyhow!("'tz' only applies to 'cron' schedules"));
+                }
+                DesiredSchedule::Interval {
+                    period_seconds: parse_duration(every)?,
+                }
+            }
+        };
+
+        for (field, value) in [
+            ("alert_after_miss_pings", self.alert_after_miss_pings),
+            ("alert_after_fail_pings", self.alert_after_fail_pings),
+        ] {
+            if value.is_some_and(|v| !(1..=100).contains(&v)) {
+                return Err(anyhow!("'{}' must be between 1 and 100", field));
+            }
+        }
+
+        Ok(DesiredCheck {
+            slug: slug.to_string(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            schedule,
+            grace_seconds: self.grace.as_deref().map(parse_duration).transpose()?,
+            tags: self.tags.clone(),
+            alert_after_miss_pings: self.alert_after_miss_pings,
+            alert_after_fail_pings: self.alert_after_fail_pings,
+            max_runtime_seconds: self
+                .max_runtime
+                .as_deref()
+                .map(parse_duration)
+                .transpose()?,
+            alerts: AlertSettings {
+                alert_on_down: self.alert_on_down,
+                alert_on_late: self.alert_on_late,
+                alert_on_overrun: self.alert_on_overrun,
+                alert_on_anomaly: self.alert_on_anomaly,
+                notify_on_recovery: self.notify_on_recovery,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = r#"
+projects:
+  infra:
+    checks:
+      backup-db:
+        cron: "0 2 * * *"
+        tz: Europe/Berlin
+        grace: 10m
+        tags: [db]
+      heartbeat:
+        every: 5m
+        alert_after_miss_pings: 3
+"#;
+
+    #[test]
+    fn test_parse_yaml_and_toml_agree() {
+        let yaml = Manifest::parse(YAML, ManifestFormat::Yaml).unwrap();
+        let toml = Manifest::parse(
+            r#"
+[projects.infra.checks.backup-db]
+cron = "0 2 * * *"
+tz = "Europe/Berlin"
+grace = "10m"
+tags = ["db"]
+
+[projects.infra.checks.heartbeat]
+every = "5m"
+alert_after_miss_pings = 3
+"#,
+            ManifestFormat::Toml,
+        )
+        .unwrap();
+        assert_eq!(yaml, toml);
+    }
+
+    #[test]
+    fn test_validate() {
+        let manifest = Manifest::parse(YAML, ManifestFormat::Yaml).unwrap();
+        let desired = manifest.validate().unwrap();
+        let checks = &desired["infra"];
+
+        assert_eq!(checks[0].slug, "backup-db");
+        assert_eq!(checks[0].grace_seconds, Some(600));
+        assert_eq!(
+            checks[0].schedule,
+            DesiredSchedule::Cron {
+                expression: "0 2 * * *".to_string(),
+                timezone: Some("Europe/Berlin".to_string()),
+            }
+        );
+        assert_eq!(
+            checks[1].schedule,
+            DesiredSchedule::Interval {
+                period_seconds: 300
+            }
+        );
+        assert_eq!(checks[1].alert_after_miss_pings, Some(3));
+        assert!(checks[1].tags.is_none());
+    }
+
+    #[test]
+    fn test_validate_reports_all_errors() {
+        let manifest = Manifest::parse(
+            r#"
+projects:
+  infra:
+    checks:
+      no-schedule: {}
+      both:
+        cron: "0 * * * *"
+        every: 1h
+      Bad_Slug:
+        every: 1h
+"#,
+            ManifestFormat::Yaml,
+        )
+        .unwrap();
+
+        let err = manifest.validate().unwrap_err().to_string();
+        assert!(err.contains("projects.infra.checks.no-schedule: missing schedule"));
+        assert!(err.contains("projects.infra.checks.both: set only one"));
+        assert!(err.contains("projects.infra.checks.Bad_Slug"));
+    }
+
+    #[test]
+    fn test_unknown_field_rejected() {
+        let err = Manifest::parse(
+            "projects:\n  infra:\n    checks:\n      a:\n        every: 1h\n        gracee: 5m\n",
+            ManifestFormat::Yaml,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("gracee"));
+    }
+
+    #[test]
+    fn test_manifest_duration_roundtrips() {
+        for seconds in [30, 90, 600, 5400, 7200, 86400, 90000] {
+            assert_eq!(
+                parse_duration(&manifest_duration(seconds)).unwrap(),
+                seconds
+            );
+        }
+        assert_eq!(manifest_duration(5400), "90m");
+        assert_eq!(manifest_duration(172800), "2d");
+    }
+
+    #[test]
+    fn test_render_parses_back() {
+        let manifest = Manifest::parse(YAML, ManifestFormat::Yaml).unwrap();
+        for format in [ManifestFormat::Yaml, ManifestFormat::Toml] {
+            let rendered = manifest.render(format).unwrap();
+            assert_eq!(Manifest::parse(&rendered, format).unwrap(), manifest);
+        }
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ManifestFormat::from_path(Path::new("checks.toml")),
+            ManifestFormat::Toml
+        );
+        assert_eq!(
+            ManifestFormat::from_path(Path::new("checks.yml")),
+            ManifestFormat::Yaml
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Checks as code: `pakyas plan` and `pakyas apply` against a manifest file.
+//!
+//! The manifest (see [`format`]) lists checks per project. `plan` diffs it against the
+//! checks on the server; `apply` runs the resulting creates, updates, and (with
+//! `--prune`) deletes one check at a time and reports a result per check. `export` writes
+//! the existing checks in the same format.
+
+mod diff;
+mod export;
+mod format;
+
+pub use export::export;
+pub use format::{Manifest, ManifestCheck, ManifestFormat, ManifestProject};
+
+use diff::{Action, PlannedChange, diff_project};
+
+use crate::cache::CheckCache;
+use crate::cli::{ApplyArgs, OutputFormat, PlanArgs};
+use crate::client::ApiClient;
+use crate::commands::check::{Check, CheckWithProject, create_check};
+use crate::commands::project::resolve_project;
+use crate::config::Context;
+use crate::exit_codes;
+use crate::output::{print_info, print_output, print_success, print_warning};
+use anyhow::Result;
+use dialoguer::Confirm;
+use serde::Serialize;
+use std::path::Path;
+use tabled::Tabled;
+
+#[derive(Debug, Tabled, Serialize)]
+struct PlanRow {
+    #[tabled(rename = "PROJECT")]
+    project: String,
+    #[tabled(rename = "SLUG")]
+    slug: String,
+    #[tabled(rename = "ACTION")]
+    action: String,
+    #[tabled(rename = "CHANGES")]
+    changes: String,
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct ApplyRow {
+    #[tabled(rename = "PROJECT")]
+    project: String,
+    #[tabled(rename = "SLUG")]
+    slug: String,
+    #[tabled(rename = "ACTION")]
+    action: String,
+    #[tabled(rename = "RESULT")]
+    result: String,
+}
+
+/// Show the changes `apply` would make
+pub async fn plan(ctx: &Context, args: PlanArgs, verbose: bool) -> Result<()> {
+    let planned = build_plan(ctx, &args.file, args.prune, verbose).await?;
+    print_plan(ctx, &planned)?;
+    Ok(())
+}
+
+/// Apply a manifest: create, update, and (with --prune) delete checks
+pub async fn apply(ctx: &Context, args: ApplyArgs, verbose: bool) -> Result<()> {
+    let planned = build_plan(ctx, &args.file, args.prune, verbose).await?;
+    let pending = planned.iter().filter(|p| p.action.is_change()).count();
+
+    if pending == 0 {
+        print_plan(ctx, &planned)?;
+        return Ok(());
+    }
+
+    if !args.yes {
+        print_plan(ctx, &planned)?;
+        let confirm = Confirm::new()
+            .with_prompt(format!("Apply {} change(s)?", pending))
+            .default(false)
+            .interact()?;
+        if !confirm {
+            print_warning("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let org_id = ctx.require_org()?;
+    let client = ApiClient::new(ctx)?;
+    let mut rows = Vec::with_capacity(pending);
+    let mut deleted = Vec::new();
+    let mut failed = 0;
+
+    for change in planned.into_iter().filter(|p| p.action.is_change()) {
+        if verbose {
+            eprintln!(
+                "[verbose] {} {}/{}",
+                change.action.label(),
+                change.project,
+                change.slug
+            );
+        }
+
+        let (action, result) = match &change.action {
+            Action::Create(req) => ("created", create_check(ctx, req).await.map(|_| ())),
+            Action::Update { check, req } => (
+                "updated",
+                client
+                    .put_no_response(&format!("/api/v1/checks/{}", check.id), req)
+                    .await,
+            ),
+            Action::Delete(check) => {
+                let result = client.delete(&format!("/api/v1/checks/{}", check.id)).await;
+                if result.is_ok() {
+                    deleted.push(check.slug.clone());
+                }
+                ("deleted", result)
+            }
+            Action::Unchanged | Action::Unmanaged => continue,
+        };
+
+        let result = match result {
+            Ok(()) => action.to_string(),
+            Err(e) => {
+                failed += 1;
+                format!("failed: {}", e)
+            }
+        };
+        rows.push(ApplyRow {
+            project: change.project,
+            slug: change.slug,
+            action: change.action.label().to_string(),
+            result,
+        });
+    }
+
+    if !deleted.is_empty() {
+        let mut cache = CheckCache::load()?;
+        for slug in &deleted {
+            cache.invalidate(org_id, slug);
+        }
+        cache.save()?;
+    }
+
+    print_output(ctx, rows)?;
+
+    if ctx.output_format() == OutputFormat::Table {
+        if failed == 0 {
+            print_success(&format!("Applied {} change(s)", pending));
+        } else {
+            print_warning(&format!("{} of {} change(s) failed", failed, pending));
+        }
+    }
+
+    if failed > 0 {
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+/// Load and validate the manifest, then diff each project against the server
+async fn build_plan(
+    ctx: &Context,
+    file: &Path,
+    prune: bool,
+    verbose: bool,
+) -> Result<Vec<PlannedChange>> {
+    let manifest = Manifest::load(file)?;
+    let desired = manifest.validate()?;
+    let client = ApiClient::new(ctx)?;
+
+    let mut planned = Vec::new();
+    for (key, checks) in &desired {
+        let project = resolve_project(ctx, key).await?;
+        let url = format!("/api/v1/checks?project_id={}", project.id);
+        if verbose {
+            eprintln!(
+                "[verbose] Fetching checks for project {} ({})",
+                project.name, project.id
+            );
+        }
+        let current: Vec<Check> = client
+            .get::<Vec<CheckWithProject>>(&url)
+            .await?
+            .into_iter()
+            .map(|c| c.check)
+            .filter(|c| !c.soft_deleted)
+            .collect();
+
+        planned.extend(diff_project(key, project.id, checks, &current, prune));
+    }
+
+    Ok(planned)
+}
+
+/// Print the plan; the table hides unchanged checks and ends with a summary
+fn print_plan(ctx: &Context, planned: &[PlannedChange]) -> Result<()> {
+    let table = ctx.output_format() == OutputFormat::Table;
+    let rows: Vec<PlanRow> = planned
+        .iter()
+        .filter(|p| !table || !matches!(p.action, Action::Unchanged))
+        .map(|p| PlanRow {
+            project: p.project.clone(),
+            slug: p.slug.clone(),
+            action: p.action.label().to_string(),
+            changes: if table {
+                p.changes.join("\n")
+            } else {
+                p.changes.join("; ")
+            },
+        })
+        .collect();
+
+    if !table {
+        return print_output(ctx, rows);
+    }
+
+    let count = |label: &str| planned.iter().filter(|p| p.action.label() == label).count();
+    if planned.iter().any(|p| p.action.is_change()) || count("unmanaged") > 0 {
+        print_output(ctx, rows)?;
+        println!();
+    }
+    print_info(&format!(
+        "Plan: {} to create, {} to update, {} to delete, {} unchanged",
+        count("create"),
+        count("update"),
+        count("delete"),
+        count("unchanged")
+    ));
+    if count("unmanaged") > 0 {
+        print_info("Unmanaged checks are left alone; use --prune to delete them");
+    }
+    Ok(())
+}
@@ -1,10 +1,18 @@
+pub mod alert;
 pub mod api_key;
 pub mod auth;
 pub mod auth_key;
 pub mod check;
 pub mod completion;
+pub mod crontab;
+pub mod drift;
+pub mod external;
+pub mod import;
+pub mod k8s;
+pub mod manifest;
 pub mod monitor;
 pub mod org;
 pub mod ping;
 pub mod project;
+pub mod systemd;
 pub mod update;
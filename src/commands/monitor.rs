@@ -1,9 +1,15 @@
 use crate::cli::MonitorArgs;
-use crate::commands::check::resolve_public_id_verbose;
+use crate::commands::check::{resolve_check_meta, resolve_public_id_verbose};
 use crate::config::Context;
 use crate::error::CliError;
+use crate::exit_codes;
+use crate::external_log;
 use crate::external_monitors::{ExternalMonitorConfig, MonitorTarget};
-use crate::external_ping::{PingEvent, dispatch_await_any_success, dispatch_external_pings};
+use crate::external_ping::{
+    DispatchHandle, EventType, PingEvent, TargetOutcome, dispatch_await_any_success_with_outcomes,
+    dispatch_external_pings, wait_budget_ms,
+};
+use crate::external_spool;
 use crate::output::{print_error, print_warning};
 use crate::ua::user_agent;
 use anyhow::Result;
@@ -41,7 +47,8 @@ struct CommandResult {
 /// 4. Send completion ping to pakyas
 /// 5. Handle migration mode if pakyas fails
 /// 6. Send completion ping to external monitors (fire-and-forget)
-/// 7. Exit with the same code as the wrapped command (or 3 for monitoring failure)
+/// 7. Exit with the same code as the wrapped command (or 3 for monitoring failure,
+///    or 4 under --external-strict when an external monitor was not reached)
 pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<ExitCode> {
     // Validate command
     if args.command.is_empty() {
@@ -60,7 +67,7 @@ pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<
 
     // Load external monitor config (supports both slug and public_id as config key)
     let (monitors, migration_mode, check_identifier) =
-        load_external_config(&args, public_id, verbose);
+        load_external_config(ctx, &args, public_id, verbose).await;
 
     if verbose {
         eprintln!(
@@ -71,6 +78,11 @@ pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<
         eprintln!("[verbose] Migration mode: {}", migration_mode);
     }
 
+    // Exec hooks limited to other events are skipped and produce no outcome
+    let start_target_count = accepting_count(&monitors, EventType::Start);
+    // Exec hooks may need longer than the HTTP timeout before we stop waiting
+    let external_wait_ms = wait_budget_ms(&monitors, args.external_timeout_ms);
+
     // Send start ping to pakyas (with run_id for pairing, no duration for start)
     if verbose {
         eprintln!(
@@ -87,7 +99,7 @@ pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<
     let start_event = PingEvent::start(&check_identifier);
     let start_handle = dispatch_external_pings(
         monitors.clone(),
-        start_event,
+        start_event.clone(),
         args.external_timeout_ms,
         verbose,
     );
@@ -113,7 +125,9 @@ pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<
         result.exit_code,
         duration_ms,
         &result.stderr,
-    );
+    )
+    .with_signal(result.signal);
+    let completion_target_count = accepting_count(&monitors, completion_event.event_type);
 
     // Send completion ping to pakyas (with run_id for pairing)
     if verbose {
@@ -138,6 +152,9 @@ pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<
     }
 
     // Handle exit code based on pakyas result and migration mode
+    let pakyas_ok = pakyas_result.is_ok();
+    let mut migration_outcomes = Vec::new();
+    let mut monitoring_failed = false;
     let (exit_code, completion_handle) = match pakyas_result {
         Ok(_) => {
             // Pakyas succeeded - dispatch externals and await before exit
@@ -156,7 +173,14 @@ pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<
             }
             let any_external_success = if !monitors.is_empty() {
                 let timeout = args.external_timeout_ms.min(MIGRATION_MODE_TIMEOUT_MS);
-                dispatch_await_any_success(monitors, completion_event.clone(), timeout).await
+                let (success, outcomes) = dispatch_await_any_success_with_outcomes(
+                    monitors,
+                    completion_event.clone(),
+                    timeout,
+                )
+                .await;
+                migration_outcomes = outcomes;
+                success
             } else {
                 false
             };
@@ -170,6 +194,7 @@ pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<
                 (ExitCode::from(result.exit_code as u8), None)
             } else {
                 print_error(&format!("Pakyas ping failed: {}", e));
+                monitoring_failed = true;
                 (ExitCode::from(EXIT_MONITORING_FAILURE), None)
             }
         }
@@ -183,35 +208,88 @@ pub async fn execute(ctx: &Context, args: MonitorArgs, verbose: bool) -> Result<
                 verbose,
             );
             print_error(&format!("Pakyas ping failed: {}", e));
+            monitoring_failed = true;
             (ExitCode::from(EXIT_MONITORING_FAILURE), handle)
         }
     };
 
     // Await pending external monitor pings before exiting
-    await_external_handles(start_handle, completion_handle, args.external_timeout_ms).await;
+    // (without a handle, migration mode already awaited the completion dispatch)
+    let completion_dispatched = completion_handle.is_some();
+    let (start_outcomes, mut completion_outcomes) =
+        await_external_handles(start_handle, completion_handle, external_wait_ms).await;
+    completion_outcomes.extend(migration_outcomes);
+
+    // Log per-target outcomes next to the pakyas result for `pakyas external report`
+    // (the start ping to pakyas must have succeeded to get here)
+    external_log::record_quietly(
+        &check_identifier,
+        EventType::Start,
+        Some(true),
+        &start_outcomes,
+        verbose,
+    );
+    external_log::record_quietly(
+        &check_identifier,
+        completion_event.event_type,
+        Some(pakyas_ok),
+        &completion_outcomes,
+        verbose,
+    );
+
+    if args.external_spool {
+        external_spool::spool_quietly(&start_event, &start_outcomes, verbose);
+        external_spool::spool_quietly(&completion_event, &completion_outcomes, verbose);
+    }
+
+    let delivered = |outcomes: &[TargetOutcome], expected: usize| {
+        outcomes.len() == expected && outcomes.iter().all(|o| o.success)
+    };
+    let external_ok = delivered(&start_outcomes, start_target_count)
+        && (!completion_dispatched || delivered(&completion_outcomes, completion_target_count));
+    if args.external_strict && !external_ok && result.exit_code == 0 && !monitoring_failed {
+        print_error("External monitor delivery failed (--external-strict)");
+        // Only replaces a successful exit; job and pakyas failures take precedence
+        return Ok(ExitCode::from(exit_codes::NETWORK as u8));
+    }
 
     Ok(exit_code)
 }
 
+/// Number of targets that send events of this type
+fn accepting_count(monitors: &[MonitorTarget], event_type: EventType) -> usize {
+    monitors.iter().filter(|t| t.accepts(event_type)).count()
+}
+
 /// Await pending external monitor handles with timeout
+///
+/// Returns the start and completion outcomes (empty if a handle timed out).
 async fn await_external_handles(
-    start_handle: Option<tokio::task::JoinHandle<()>>,
-    completion_handle: Option<tokio::task::JoinHandle<()>>,
+    start_handle: Option<DispatchHandle>,
+    completion_handle: Option<DispatchHandle>,
     timeout_ms: u64,
-) {
+) -> (Vec<TargetOutcome>, Vec<TargetOutcome>) {
     let timeout = Duration::from_millis(timeout_ms);
+    let mut start_outcomes = Vec::new();
+    let mut completion_outcomes = Vec::new();
 
     if let Some(handle) = start_handle {
-        if tokio::time::timeout(timeout, handle).await.is_err() {
-            eprintln!("Warning: external start ping timed out");
+        match tokio::time::timeout(timeout, handle).await {
+            Ok(Ok(outcomes)) => start_outcomes = outcomes,
+            Ok(Err(_)) => {}
+            Err(_) => eprintln!("Warning: external start ping timed out"),
         }
     }
 
     if let Some(handle) = completion_handle {
-        if tokio::time::timeout(timeout, handle).await.is_err() {
-            eprintln!("Warning: external completion ping timed out");
+        match tokio::time::timeout(timeout, handle).await {
+            Ok(Ok(outcomes)) => completion_outcomes = outcomes,
+            Ok(Err(_)) => {}
+            Err(_) => eprintln!("Warning: external completion ping timed out"),
         }
     }
+
+    (start_outcomes, completion_outcomes)
 }
 
 /// Load external monitor configuration and determine migration mode
@@ -220,7 +298,8 @@ async fn await_external_handles(
 /// The identifier is either the slug (if available) or the public_id string.
 ///
 /// Monitors are merged additively: CLI arguments + config file monitors.
-fn load_external_config(
+async fn load_external_config(
+    ctx: &Context,
     args: &MonitorArgs,
     public_id: uuid::Uuid,
     verbose: bool,
@@ -289,7 +368,9 @@ fn load_external_config(
                     eprintln!("[verbose] Using config: {}", path.display());
                 }
             }
-            let config_monitors = config.build_monitors_for_check(&config_key);
+            // Check tags let `[[rules]]` match by tag as well as slug
+            let check_meta = resolve_check_meta(ctx, &config_key, Some(&public_id), verbose).await;
+            let config_monitors = config.build_monitors(&check_meta);
 
             if verbose && !config_monitors.is_empty() {
                 eprintln!(
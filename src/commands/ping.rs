@@ -1,19 +1,25 @@
 use crate::cli::PingArgs;
-use crate::commands::check::resolve_public_id_verbose;
+use crate::commands::check::{resolve_check_meta, resolve_public_id_verbose};
 use crate::config::Context;
+use crate::exit_codes;
+use crate::external_log;
 use crate::external_monitors::ExternalMonitorConfig;
-use crate::external_ping::{EventType, PingEvent, dispatch_external_pings};
-use crate::output::print_success;
+use crate::external_ping::{EventType, PingEvent, dispatch_external_pings, wait_budget_ms};
+use crate::external_spool;
+use crate::output::{print_error, print_success};
 use crate::ua::user_agent;
 use anyhow::Result;
 use reqwest::Client;
+use std::process::ExitCode;
 use std::time::Duration;
 use uuid::Uuid;
 
 const PING_TIMEOUT_SECS: u64 = 10;
 
 /// Execute the ping command
-pub async fn execute(ctx: &Context, args: PingArgs, verbose: bool) -> Result<()> {
+///
+/// Exits with 4 under `--external-strict` if an external monitor was not reached.
+pub async fn execute(ctx: &Context, args: PingArgs, verbose: bool) -> Result<ExitCode> {
     if verbose {
         eprintln!("[verbose] Ping URL base: {}", ctx.ping_url());
     }
@@ -44,22 +50,31 @@ pub async fn execute(ctx: &Context, args: PingArgs, verbose: bool) -> Result<()>
     // Supports both slug and public_id as config lookup keys
     if !args.no_external {
         let check_identifier = args.slug.clone().unwrap_or_else(|| public_id.to_string());
-        dispatch_external_ping(&args, &check_identifier, public_id, verbose).await;
+        let delivered =
+            dispatch_external_ping(ctx, &args, &check_identifier, public_id, verbose).await;
+        if !delivered && args.external_strict {
+            print_error("External monitor delivery failed (--external-strict)");
+            return Ok(ExitCode::from(exit_codes::NETWORK as u8));
+        }
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
 
 /// Dispatch ping to external monitors and await completion
 ///
 /// The `check_identifier` parameter can be either a slug or a public_id string,
 /// allowing external monitors to work with both slug-based and public_id-based invocations.
+///
+/// Returns false if any target failed or did not answer in time. A missing or
+/// unreadable config is not a delivery failure.
 async fn dispatch_external_ping(
+    ctx: &Context,
     args: &PingArgs,
     check_identifier: &str,
-    _public_id: Uuid,
+    public_id: Uuid,
     verbose: bool,
-) {
+) -> bool {
     // Show config paths being checked
     if verbose {
         eprintln!("[verbose] Checking external monitors config paths:");
@@ -83,12 +98,13 @@ async fn dispatch_external_ping(
             if verbose {
                 eprintln!("[verbose] Failed to load external monitor config: {}", e);
             }
-            return;
+            return true;
         }
     };
 
-    // Build targets for this check (key can be slug or public_id)
-    let monitors = external_config.build_monitors_for_check(check_identifier);
+    // Build targets for this check (key can be slug or public_id; its tags feed rules)
+    let check_meta = resolve_check_meta(ctx, check_identifier, Some(&public_id), verbose).await;
+    let monitors = external_config.build_monitors(&check_meta);
 
     if verbose {
         eprintln!(
@@ -99,19 +115,45 @@ async fn dispatch_external_ping(
     }
 
     if monitors.is_empty() {
-        return;
+        return true;
     }
 
     // Build the event based on ping type
     let event = build_external_event(args, check_identifier);
 
-    // Dispatch and await completion
-    if let Some(handle) =
-        dispatch_external_pings(monitors, event, args.external_timeout_ms, verbose)
-    {
-        let timeout = Duration::from_millis(args.external_timeout_ms);
-        if tokio::time::timeout(timeout, handle).await.is_err() {
+    // Dispatch and await completion (exec hooks may extend the wait)
+    // Exec hooks limited to other events are skipped and produce no outcome
+    let target_count = monitors
+        .iter()
+        .filter(|t| t.accepts(event.event_type))
+        .count();
+    let wait_ms = wait_budget_ms(&monitors, args.external_timeout_ms);
+    let Some(handle) =
+        dispatch_external_pings(monitors, event.clone(), args.external_timeout_ms, verbose)
+    else {
+        return true;
+    };
+
+    let timeout = Duration::from_millis(wait_ms);
+    match tokio::time::timeout(timeout, handle).await {
+        Ok(Ok(outcomes)) => {
+            // The pakyas ping already succeeded by the time externals are dispatched
+            external_log::record_quietly(
+                check_identifier,
+                event.event_type,
+                Some(true),
+                &outcomes,
+                verbose,
+            );
+            if args.external_spool {
+                external_spool::spool_quietly(&event, &outcomes, verbose);
+            }
+            outcomes.len() == target_count && outcomes.iter().all(|o| o.success)
+        }
+        Ok(Err(_)) => false,
+        Err(_) => {
             eprintln!("Warning: external ping timed out");
+            false
         }
     }
 }
@@ -129,6 +171,7 @@ fn build_external_event(args: &PingArgs, check_identifier: &str) -> PingEvent {
             timestamp: chrono::Utc::now(),
             host: hostname::get().ok().and_then(|h| h.into_string().ok()),
             output: None,
+            signal: None,
         }
     } else if let Some(exit_code) = args.exit_code {
         PingEvent::completion(check_identifier, exit_code, 0, "")
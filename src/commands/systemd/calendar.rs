@@ -0,0 +1,283 @@
+//! Convert systemd `OnCalendar=` expressions and time spans to Pakyas schedules.
+//!
+//! Only what maps exactly onto a 5-field cron expression is converted: shorthands like
+//! `daily`, and `[weekdays] *-MM-DD HH:MM[:00] [timezone]` forms with lists, `..` ranges and
+//! `/` repetitions. Specific years, `~` (days from month end), and non-zero seconds are rejected.
+
+use crate::commands::check::validate_timezone;
+use crate::cron::validate_cron_expression;
+
+/// A cron expression converted from `OnCalendar=`, with its timezone suffix if any
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarCron {
+    pub expression: String,
+    pub timezone: Option<String>,
+}
+
+/// Convert an `OnCalendar=` expression to cron, or explain why it can't be
+pub fn calendar_to_cron(spec: &str) -> Result<CalendarCron, String> {
+    let mut words: Vec<&str> = spec.split_whitespace().collect();
+    if words.is_empty() {
+        return Err("empty OnCalendar".to_string());
+    }
+
+    // A trailing timezone (e.g. "Europe/Berlin" or "UTC")
+    let timezone = match words.last() {
+        Some(last) if words.len() > 1 && validate_timezone(last).is_ok() => {
+            let tz = last.to_string();
+            words.pop();
+            Some(tz)
+        }
+        _ => None,
+    };
+
+    let expression = match words.as_slice() {
+        [word] if shorthand(word).is_some() => shorthand(word).unwrap_or_default().to_string(),
+        _ => convert_words(&words)?,
+    };
+    validate_cron_expression(&expression)
+        .map_err(|e| format!("'{}' converts to '{}': {}", spec, expression, e))?;
+
+    Ok(CalendarCron {
+        expression,
+        timezone,
+    })
+}
+
+fn shorthand(word: &str) -> Option<&'static str> {
+    Some(match word.to_ascii_lowercase().as_str() {
+        "minutely" => "* * * * *",
+        "hourly" => "0 * * * *",
+        "daily" => "0 0 * * *",
+        "weekly" => "0 0 * * MON",
+        "monthly" => "0 0 1 * *",
+        "quarterly" => "0 0 1 1,4,7,10 *",
+        "semiannually" => "0 0 1 1,7 *",
+        "yearly" | "annually" => "0 0 1 1 *",
+        _ => return None,
+    })
+}
+
+fn convert_words(words: &[&str]) -> Result<String, String> {
+    let mut weekdays = "*".to_string();
+    let mut date = None;
+    let mut time = None;
+
+    for word in words {
+        if word.contains(':') {
+            time = Some(*word);
+        } else if word.contains('-') && word.chars().next().is_some_and(|c| !c.is_alphabetic()) {
+            date = Some(*word);
+        } else if word
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c == ',' || c == '.')
+        {
+            weekdays = convert_weekdays(word)?;
+        } else {
+            return Err(format!("unsupported OnCalendar part '{}'", word));
+        }
+    }
+
+    let (month, day) = match date {
+        Some(date) => convert_date(date)?,
+        None => ("*".to_string(), "*".to_string()),
+    };
+    let (hour, minute) = match time {
+        Some(time) => convert_time(time)?,
+        None => ("0".to_string(), "0".to_string()),
+    };
+
+    Ok(format!(
+        "{} {} {} {} {}",
+        minute, hour, day, month, weekdays
+    ))
+}
+
+fn convert_weekdays(word: &str) -> Result<String, String> {
+    let day = |name: &str| -> Result<&'static str, String> {
+        let lower = name.to_ascii_lowercase();
+        let found = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"]
+            .into_iter()
+            .zip(["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"])
+            .find(|(short, _)| lower.len() >= 3 && short.starts_with(&lower[..3]));
+        found
+            .map(|(_, cron)| cron)
+            .ok_or_else(|| format!("unknown weekday '{}'", name))
+    };
+
+    word.split(',')
+        .map(|part| match part.split_once("..") {
+            Some((from, to)) => Ok(format!("{}-{}", day(from)?, day(to)?)),
+            None => day(part).map(str::to_string),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|parts| parts.join(","))
+}
+
+/// `*-MM-DD` or `MM-DD` → (month, day of month)
+fn convert_date(date: &str) -> Result<(String, String), String> {
+    if date.contains('~') {
+        return Err("'~' (days before month end) has no cron equivalent".to_string());
+    }
+    let parts: Vec<&str> = date.split('-').collect();
+    let (month, day) = match parts.as_slice() {
+        [year, month, day] => {
+            if *year != "*" {
+                return Err(format!("specific year '{}' has no cron equivalent", year));
+            }
+            (*month, *day)
+        }
+        [month, day] => (*month, *day),
+        _ => return Err(format!("unsupported date '{}'", date)),
+    };
+    Ok((convert_component(month)?, convert_component(day)?))
+}
+
+/// `HH:MM[:SS]` → (hour, minute); seconds must be zero
+fn convert_time(time: &str) -> Result<(String, String), String> {
+    let parts: Vec<&str> = time.split(':').collect();
+    let (hour, minute) = match parts.as_slice() {
+        [hour, minute] => (*hour, *minute),
+        [hour, minute, second] => {
+            if second.trim_start_matches('0').is_empty() {
+                (*hour, *minute)
+            } else {
+                return Err(format!(
+                    "seconds '{}' can't be expressed in cron (minute precision)",
+                    second
+                ));
+            }
+        }
+        _ => return Err(format!("unsupported time '{}'", time)),
+    };
+    Ok((convert_component(hour)?, convert_component(minute)?))
+}
+
+/// One calendar component: `*`, numbers, lists, `a..b` ranges, and `/step` repetitions
+fn convert_component(value: &str) -> Result<String, String> {
+    value
+        .split(',')
+        .map(|part| {
+            let (base, step) = match part.split_once('/') {
+                Some((base, step)) => (base, Some(number(step)?)),
+                None => (part, None),
+            };
+            let base = if base == "*" {
+                "*".to_string()
+            } else if let Some((from, to)) = base.split_once("..") {
+                format!("{}-{}", number(from)?, number(to)?)
+            } else {
+                number(base)?
+            };
+            Ok(match step {
+                Some(step) => format!("{}/{}", base, step),
+                None => base,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()
+        .map(|parts| parts.join(","))
+}
+
+fn number(value: &str) -> Result<String, String> {
+    value
+        .parse::<u32>()
+        .map(|n| n.to_string())
+        .map_err(|_| format!("unsupported calendar value '{}'", value))
+}
+
+/// Parse a systemd time span like `15min`, `1h 30m`, or `2 weeks` into seconds
+pub fn parse_timespan(span: &str) -> Option<i32> {
+    let span = span.trim();
+    if let Ok(seconds) = span.parse::<i64>() {
+        return i32::try_from(seconds).ok();
+    }
+
+    let mut total: i64 = 0;
+    let mut rest = span;
+    while !rest.trim_start().is_empty() {
+        rest = rest.trim_start();
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let value: i64 = rest[..digits].parse().ok()?;
+        rest = rest[digits..].trim_start();
+        let unit_len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let multiplier = match &rest[..unit_len] {
+            "" | "s" | "sec" | "second" | "seconds" => 1,
+            "m" | "min" | "minute" | "minutes" => 60,
+            "h" | "hr" | "hour" | "hours" => 3600,
+            "d" | "day" | "days" => 86400,
+            "w" | "week" | "weeks" => 604800,
+            "M" | "month" | "months" => 2_629_800,
+            "y" | "year" | "years" => 31_557_600,
+            "ms" | "msec" | "us" | "usec" => 0,
+            _ => return None,
+        };
+        total = total.checked_add(value.checked_mul(multiplier)?)?;
+        rest = &rest[unit_len..];
+    }
+    i32::try_from(total).ok().filter(|s| *s > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cron(spec: &str) -> String {
+        calendar_to_cron(spec).unwrap().expression
+    }
+
+    #[test]
+    fn test_shorthands() {
+        assert_eq!(cron("daily"), "0 0 * * *");
+        assert_eq!(cron("weekly"), "0 0 * * MON");
+        assert_eq!(cron("quarterly"), "0 0 1 1,4,7,10 *");
+    }
+
+    #[test]
+    fn test_full_forms() {
+        assert_eq!(cron("*-*-* 02:30:00"), "30 2 * * *");
+        assert_eq!(cron("Mon..Fri *-*-* 06:00"), "0 6 * * MON-FRI");
+        assert_eq!(cron("Sat,Sun 10:15"), "15 10 * * SAT,SUN");
+        assert_eq!(cron("*-*-01 04:00"), "0 4 1 * *");
+        assert_eq!(cron("*-01,07-01 00:00"), "0 0 1 1,7 *");
+        assert_eq!(cron("*:0/15"), "0/15 * * * *");
+        assert_eq!(cron("*-*-* 08..18:00"), "0 8-18 * * *");
+        assert_eq!(cron("Monday"), "0 0 * * MON");
+    }
+
+    #[test]
+    fn test_timezone_suffix() {
+        let converted = calendar_to_cron("*-*-* 03:00 Europe/Berlin").unwrap();
+        assert_eq!(converted.expression, "0 3 * * *");
+        assert_eq!(converted.timezone.as_deref(), Some("Europe/Berlin"));
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert!(
+            calendar_to_cron("2026-*-* 00:00")
+                .unwrap_err()
+                .contains("year")
+        );
+        assert!(calendar_to_cron("*-*~03").unwrap_err().contains("~"));
+        assert!(
+            calendar_to_cron("*-*-* 00:00:30")
+                .unwrap_err()
+                .contains("seconds")
+        );
+        assert!(calendar_to_cron("Funday").is_err());
+    }
+
+    #[test]
+    fn test_parse_timespan() {
+        assert_eq!(parse_timespan("90"), Some(90));
+        assert_eq!(parse_timespan("15min"), Some(900));
+        assert_eq!(parse_timespan("1h 30m"), Some(5400));
+        assert_eq!(parse_timespan("2 weeks"), Some(1_209_600));
+        assert_eq!(parse_timespan("1d12h"), Some(129_600));
+        assert_eq!(parse_timespan("soon"), None);
+    }
+}
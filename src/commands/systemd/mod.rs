@@ -0,0 +1,587 @@
+//! Import systemd timers as checks and instrument their services.
+//!
+//! `OnCalendar=` is converted to cron where that's exact (see [`calendar`]) and
+//! `OnUnitActiveSec=` to an interval. With `--instrument`, a drop-in is generated for each
+//! timer's service that either wraps ExecStart with `pakyas monitor` or adds ping hooks.
+
+mod calendar;
+mod unit;
+
+use crate::cli::{OutputFormat, SystemdCommands, SystemdInstrument};
+use crate::commands::check::{create_check, slug_to_title, smart_grace, validate_slug};
+use crate::commands::import::{
+    ImportedCheck, ImportedSchedule, Unmapped, existing_slugs, host_name, host_timezone, slugify,
+};
+use crate::config::Context;
+use crate::error::CliError;
+use crate::exit_codes;
+use crate::output::{print_info, print_output, print_success, print_warning};
+use anyhow::{Result, anyhow};
+use calendar::{calendar_to_cron, parse_timespan};
+use console::style;
+use dialoguer::Confirm;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tabled::Tabled;
+pub use unit::UnitFile;
+use unit::{FAIL_TEMPLATE_UNIT, drop_in, fail_template, parse_bool};
+use uuid::Uuid;
+
+const DEFAULT_UNIT_DIR: &str = "/etc/systemd/system";
+
+/// Where services are looked up when they aren't next to their timer
+const SERVICE_DIRS: &[&str] = &[
+    "/etc/systemd/system",
+    "/usr/lib/systemd/system",
+    "/lib/systemd/system",
+];
+
+/// File name of generated drop-ins inside `<service>.d/`
+const DROP_IN_NAME: &str = "pakyas.conf";
+
+/// Handle systemd subcommands
+pub async fn handle(ctx: &Context, command: SystemdCommands, verbose: bool) -> Result<()> {
+    match command {
+        SystemdCommands::Import {
+            paths,
+            prefix,
+            instrument,
+            unit_dir,
+            dry_run,
+            yes,
+        } => {
+            let options = ImportOptions {
+                prefix: prefix.unwrap_or_default(),
+                instrument,
+                unit_dir,
+                dry_run,
+                yes,
+            };
+            import(ctx, paths, options, verbose).await
+        }
+    }
+}
+
+struct ImportOptions {
+    prefix: String,
+    instrument: Option<SystemdInstrument>,
+    unit_dir: PathBuf,
+    dry_run: bool,
+    yes: bool,
+}
+
+/// A timer unit that was read from disk
+pub struct Timer {
+    pub path: PathBuf,
+    /// Unit name without `.timer`
+    pub name: String,
+    pub unit: UnitFile,
+}
+
+impl Timer {
+    /// Service the timer activates (`Unit=`, or the timer's own name)
+    pub fn service(&self) -> String {
+        self.unit
+            .get("Timer", "Unit")
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{}.service", self.name))
+    }
+}
+
+/// A mapped timer plus notes worth showing (ignored settings, Persistent, ...)
+#[derive(Debug)]
+struct MappedTimer {
+    check: ImportedCheck,
+    notes: Vec<String>,
+}
+
+#[derive(Debug, Tabled, Serialize)]
+struct TimerRow {
+    #[tabled(rename = "TIMER")]
+    timer: String,
+    #[tabled(rename = "SLUG")]
+    slug: String,
+    #[tabled(rename = "SCHEDULE")]
+    schedule: String,
+    #[tabled(rename = "ACTION")]
+    action: String,
+    #[tabled(rename = "NOTE")]
+    note: String,
+}
+
+async fn import(
+    ctx: &Context,
+    paths: Vec<PathBuf>,
+    options: ImportOptions,
+    verbose: bool,
+) -> Result<()> {
+    let paths = if paths.is_empty() {
+        vec![PathBuf::from(DEFAULT_UNIT_DIR)]
+    } else {
+        paths
+    };
+    let timers = read_timers(&paths)?;
+    if verbose {
+        eprintln!("[verbose] Found {} timer unit(s)", timers.len());
+    }
+    if timers.is_empty() {
+        print_warning("No .timer units found");
+        return Ok(());
+    }
+
+    let host_timezone = host_timezone();
+    let host = host_name();
+    let project_id = ctx.require_project()?;
+    let project_uuid = Uuid::parse_str(project_id)
+        .map_err(|_| CliError::Other("Invalid project ID".to_string()))?;
+    let existing = existing_slugs(ctx, project_id, verbose).await?;
+    let pakyas_bin = std::env::current_exe()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| "pakyas".to_string());
+
+    let mut rows = Vec::with_capacity(timers.len());
+    let mut drop_ins: Vec<(PathBuf, String)> = Vec::new();
+    let mut failed = 0;
+
+    for timer in &timers {
+        let mapped = match map_timer(timer, &options.prefix, host_timezone.as_deref(), &host) {
+            Ok(mapped) => mapped,
+            Err(unmapped) => {
+                rows.push(TimerRow {
+                    timer: timer.name.clone(),
+                    slug: String::new(),
+                    schedule: String::new(),
+                    action: "unmapped".to_string(),
+                    note: unmapped.reason,
+                });
+                continue;
+            }
+        };
+        let check = &mapped.check;
+        let mut notes = mapped.notes.clone();
+
+        let action = if existing.contains(&check.slug) {
+            "exists"
+        } else if options.dry_run {
+            "create"
+        } else {
+            if verbose {
+                eprintln!("[verbose] Creating check '{}'", check.slug);
+            }
+            match create_check(ctx, &check.to_request(project_uuid)).await {
+                Ok(_) => "created",
+                Err(e) => {
+                    failed += 1;
+                    rows.push(TimerRow {
+                        timer: timer.name.clone(),
+                        slug: check.slug.clone(),
+                        schedule: check.schedule.display(),
+                        action: "failed".to_string(),
+                        note: e.to_string(),
+                    });
+                    continue;
+                }
+            }
+        };
+
+        if let Some(mode) = options.instrument {
+            match instrument(timer, mode, &check.slug, &options.unit_dir, &pakyas_bin) {
+                Ok(file) => drop_ins.push(file),
+                Err(reason) => notes.push(format!("not instrumented: {}", reason)),
+            }
+        }
+
+        rows.push(TimerRow {
+            timer: timer.name.clone(),
+            slug: check.slug.clone(),
+            schedule: check.schedule.display(),
+            action: action.to_string(),
+            note: notes.join("; "),
+        });
+    }
+
+    if options.instrument == Some(SystemdInstrument::Hooks) && !drop_ins.is_empty() {
+        drop_ins.push((
+            options.unit_dir.join(FAIL_TEMPLATE_UNIT),
+            fail_template(&pakyas_bin),
+        ));
+    }
+
+    print_output(ctx, rows)?;
+    let table = ctx.output_format() == OutputFormat::Table;
+
+    if !drop_ins.is_empty() {
+        if table {
+            for (path, content) in &drop_ins {
+                println!("\n{}", style(path.display()).bold());
+                print!("{}", content);
+            }
+            println!();
+        }
+        if options.dry_run {
+            print_info("Dry run: no checks were created and no unit files were written");
+        } else if options.yes
+            || Confirm::new()
+                .with_prompt(format!("Write {} unit file(s)?", drop_ins.len()))
+                .default(false)
+                .interact()?
+        {
+            for (path, content) in &drop_ins {
+                write_unit_file(path, content)?;
+            }
+            print_success(&format!("Wrote {} unit file(s)", drop_ins.len()));
+            print_info("Run 'systemctl daemon-reload' to apply them");
+        } else {
+            print_warning("No unit files were written");
+        }
+    } else if options.dry_run && table {
+        print_info("Dry run: no checks were created");
+    }
+
+    if failed > 0 {
+        if table {
+            print_warning(&format!("{} check(s) failed to import", failed));
+        }
+        std::process::exit(exit_codes::ISSUES);
+    }
+
+    Ok(())
+}
+
+/// Read `.timer` files, scanning directories (not recursively)
+pub fn read_timers(paths: &[PathBuf]) -> Result<Vec<Timer>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            let entries = std::fs::read_dir(path)
+                .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+            let mut found: Vec<PathBuf> = entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.extension().is_some_and(|ext| ext == "timer") && p.is_file())
+                .collect();
+            found.sort();
+            files.extend(found);
+        } else {
+            files.push(path.clone());
+        }
+    }
+
+    files
+        .into_iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().trim_end_matches(".timer").to_string())
+                .unwrap_or_default();
+            Ok(Timer {
+                path,
+                name,
+                unit: UnitFile::parse(&content),
+            })
+        })
+        .collect()
+}
+
+/// Map a timer's schedule and settings onto a check
+fn map_timer(
+    timer: &Timer,
+    prefix: &str,
+    host_timezone: Option<&str>,
+    host: &str,
+) -> Result<MappedTimer, Unmapped> {
+    let unmapped = |reason: String| Unmapped {
+        name: timer.name.clone(),
+        reason,
+    };
+    let unit = &timer.unit;
+    let mut notes = Vec::new();
+    let schedule = timer_schedule(timer, host_timezone, &mut notes).map_err(&unmapped)?;
+
+    // Runs may start up to RandomizedDelaySec late, so allow for it in the grace period
+    let grace_seconds = match unit
+        .get("Timer", "RandomizedDelaySec")
+        .and_then(parse_timespan)
+    {
+        Some(delay) => {
+            notes.push("grace includes RandomizedDelaySec".to_string());
+            Some(smart_grace(schedule.period_seconds()).saturating_add(delay))
+        }
+        None => None,
+    };
+    if unit.get("Timer", "Persistent").is_some_and(parse_bool) {
+        notes.push("Persistent: runs missed while down start late".to_string());
+    }
+
+    let slug = format!("{}{}", prefix, slugify(&timer.name));
+    validate_slug(&slug).map_err(|e| unmapped(e.to_string()))?;
+
+    Ok(MappedTimer {
+        check: ImportedCheck {
+            name: unit
+                .get("Unit", "Description")
+                .map(str::to_string)
+                .unwrap_or_else(|| slug_to_title(&slug)),
+            slug,
+            description: Some(format!("systemd timer {} on {}", timer.name, host)),
+            tags: vec!["systemd".to_string()],
+            schedule,
+            grace_seconds,
+            external_id: None,
+        },
+        notes,
+    })
+}
+
+/// Schedule of a timer: its single `OnCalendar=` as cron (in the host timezone unless the
+/// calendar names one), or else `OnUnitActiveSec=`/`OnUnitInactiveSec=` as an interval
+pub fn timer_schedule(
+    timer: &Timer,
+    host_timezone: Option<&str>,
+    notes: &mut Vec<String>,
+) -> Result<ImportedSchedule, String> {
+    let unit = &timer.unit;
+    let calendars = unit.get_all("Timer", "OnCalendar");
+    let interval = unit
+        .get("Timer", "OnUnitActiveSec")
+        .map(|v| ("OnUnitActiveSec", v))
+        .or_else(|| {
+            unit.get("Timer", "OnUnitInactiveSec")
+                .map(|v| ("OnUnitInactiveSec", v))
+        });
+
+    match (calendars.as_slice(), interval) {
+        ([], None) => Err(
+            "no OnCalendar or OnUnitActiveSec schedule (boot-only timers can't be monitored)"
+                .to_string(),
+        ),
+        ([calendar], _) => {
+            let converted = calendar_to_cron(calendar)?;
+            if let Some((key, _)) = interval {
+                notes.push(format!("{} ignored in favor of OnCalendar", key));
+            }
+            Ok(ImportedSchedule::Cron {
+                expression: converted.expression,
+                timezone: converted
+                    .timezone
+                    .or_else(|| host_timezone.map(str::to_string)),
+            })
+        }
+        ([], Some((key, value))) => {
+            let period_seconds =
+                parse_timespan(value).ok_or_else(|| format!("unsupported {}={}", key, value))?;
+            if key == "OnUnitInactiveSec" {
+                notes.push("interval counts from the end of each run".to_string());
+            }
+            Ok(ImportedSchedule::Interval { period_seconds })
+        }
+        (several, _) => Err(format!(
+            "{} OnCalendar schedules; a check has one",
+            several.len()
+        )),
+    }
+}
+
+/// Build the drop-in for a timer's service as (path, content)
+fn instrument(
+    timer: &Timer,
+    mode: SystemdInstrument,
+    slug: &str,
+    unit_dir: &Path,
+    pakyas_bin: &str,
+) -> Result<(PathBuf, String), String> {
+    let service_name = timer.service();
+    let service =
+        find_service(timer, &service_name).ok_or_else(|| format!("{} not found", service_name))?;
+    let exec_start = service.get_all("Service", "ExecStart");
+    if exec_start.iter().any(|e| e.contains("pakyas")) {
+        return Err(format!("{} already calls pakyas", service_name));
+    }
+
+    let content = drop_in(mode, slug, &exec_start, pakyas_bin)?;
+    let path = unit_dir
+        .join(format!("{}.d", service_name))
+        .join(DROP_IN_NAME);
+    Ok((path, content))
+}
+
+/// Look for the service next to its timer, then in the standard unit directories, and
+/// apply its `<service>.d/*.conf` drop-ins from the same directories in file name order
+pub fn find_service(timer: &Timer, service_name: &str) -> Option<UnitFile> {
+    let dirs: Vec<PathBuf> = timer
+        .path
+        .parent()
+        .map(Path::to_path_buf)
+        .into_iter()
+        .chain(SERVICE_DIRS.iter().map(PathBuf::from))
+        .collect();
+    let mut content = dirs
+        .iter()
+        .find_map(|dir| std::fs::read_to_string(dir.join(service_name)).ok())?;
+
+    // A drop-in name in an earlier directory overrides the same name in later ones
+    let mut drop_ins: Vec<(std::ffi::OsString, PathBuf)> = Vec::new();
+    for dir in &dirs {
+        let Ok(entries) = std::fs::read_dir(dir.join(format!("{}.d", service_name))) else {
+            continue;
+        };
+        for path in entries.filter_map(|e| e.ok().map(|e| e.path())) {
+            let Some(name) = path.file_name().map(|n| n.to_os_string()) else {
+                continue;
+            };
+            if path.extension().is_some_and(|ext| ext == "conf")
+                && !drop_ins.iter().any(|(n, _)| *n == name)
+            {
+                drop_ins.push((name, path));
+            }
+        }
+    }
+    drop_ins.sort();
+    for (_, path) in drop_ins {
+        if let Ok(drop_in) = std::fs::read_to_string(path) {
+            content.push('\n');
+            content.push_str(&drop_in);
+        }
+    }
+
+    Some(UnitFile::parse(&content))
+}
+
+fn write_unit_file(path: &Path, content: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| anyhow!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    std::fs::write(path, content).map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer(name: &str, content: &str) -> Timer {
+        Timer {
+            path: PathBuf::from(format!("/nonexistent/{}.timer", name)),
+            name: name.to_string(),
+            unit: UnitFile::parse(content),
+        }
+    }
+
+    #[test]
+    fn test_map_calendar_timer() {
+        let backup = timer(
+            "db-backup",
+            "[Unit]\nDescription=Nightly DB backup\n[Timer]\nOnCalendar=*-*-* 02:30:00\nRandomizedDelaySec=10min\nPersistent=true\n",
+        );
+        let mapped = map_timer(&backup, "web-1-", Some("Europe/Riga"), "web-1").unwrap();
+
+        assert_eq!(mapped.check.slug, "web-1-db-backup");
+        assert_eq!(mapped.check.name, "Nightly DB backup");
+        assert_eq!(
+            mapped.check.schedule,
+            ImportedSchedule::Cron {
+                expression: "30 2 * * *".to_string(),
+                timezone: Some("Europe/Riga".to_string()),
+            }
+        );
+        assert_eq!(mapped.check.grace_seconds, Some(smart_grace(86400) + 600));
+        assert_eq!(mapped.notes.len(), 2);
+    }
+
+    #[test]
+    fn test_map_interval_timer() {
+        let sync = timer("sync", "[Timer]\nOnBootSec=5min\nOnUnitActiveSec=15min\n");
+        let mapped = map_timer(&sync, "", None, "web-1").unwrap();
+        assert_eq!(
+            mapped.check.schedule,
+            ImportedSchedule::Interval {
+                period_seconds: 900
+            }
+        );
+        assert!(mapped.check.grace_seconds.is_none());
+    }
+
+    #[test]
+    fn test_map_unsupported_timers() {
+        let boot = timer("warmup", "[Timer]\nOnBootSec=1min\n");
+        assert!(
+            map_timer(&boot, "", None, "h")
+                .unwrap_err()
+                .reason
+                .contains("boot")
+        );
+
+        let yearly = timer("report", "[Timer]\nOnCalendar=2027-01-01 00:00\n");
+        assert!(
+            map_timer(&yearly, "", None, "h")
+                .unwrap_err()
+                .reason
+                .contains("year")
+        );
+
+        let two = timer(
+            "twice",
+            "[Timer]\nOnCalendar=Mon 06:00\nOnCalendar=Fri 18:00\n",
+        );
+        assert!(
+            map_timer(&two, "", None, "h")
+                .unwrap_err()
+                .reason
+                .contains("2")
+        );
+    }
+
+    #[test]
+    fn test_instrument_reads_service_beside_timer() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("backup.timer"),
+            "[Timer]\nOnCalendar=daily\nUnit=do-backup.service\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("do-backup.service"),
+            "[Service]\nType=oneshot\nExecStart=/usr/bin/backup\n",
+        )
+        .unwrap();
+
+        let timers = read_timers(&[dir.path().to_path_buf()]).unwrap();
+        assert_eq!(timers.len(), 1);
+
+        let (path, content) = instrument(
+            &timers[0],
+            SystemdInstrument::Wrap,
+            "backup",
+            Path::new("/etc/systemd/system"),
+            "pakyas",
+        )
+        .unwrap();
+        assert_eq!(
+            path,
+            PathBuf::from("/etc/systemd/system/do-backup.service.d/pakyas.conf")
+        );
+        assert!(content.contains("ExecStart=pakyas monitor backup -- /usr/bin/backup"));
+
+        // Once the drop-in is in place, the service counts as instrumented
+        let drop_in_dir = dir.path().join("do-backup.service.d");
+        std::fs::create_dir(&drop_in_dir).unwrap();
+        std::fs::write(drop_in_dir.join(DROP_IN_NAME), content).unwrap();
+        let service = find_service(&timers[0], "do-backup.service").unwrap();
+        assert_eq!(
+            service.get_all("Service", "ExecStart"),
+            vec!["pakyas monitor backup -- /usr/bin/backup"]
+        );
+        assert!(
+            instrument(
+                &timers[0],
+                SystemdInstrument::Wrap,
+                "backup",
+                dir.path(),
+                "pakyas"
+            )
+            .unwrap_err()
+            .contains("already")
+        );
+    }
+}
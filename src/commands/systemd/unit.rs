@@ -0,0 +1,175 @@
+//! Minimal systemd unit file reader and drop-in generation.
+
+use crate::cli::SystemdInstrument as Instrument;
+
+/// Settings of a unit file in order, as (section, key, value)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnitFile {
+    entries: Vec<(String, String, String)>,
+}
+
+impl UnitFile {
+    /// Parse unit file content (comments, continuation lines, and sections)
+    pub fn parse(content: &str) -> Self {
+        let mut entries = Vec::new();
+        let mut section = String::new();
+        let mut pending = String::new();
+
+        for raw in content.lines() {
+            let line = raw.trim();
+            if pending.is_empty() && (line.is_empty() || line.starts_with(['#', ';'])) {
+                continue;
+            }
+            // A trailing backslash continues the value on the next line
+            if let Some(start) = line.strip_suffix('\\') {
+                pending.push_str(start.trim_end());
+                pending.push(' ');
+                continue;
+            }
+            let line = if pending.is_empty() {
+                line.to_string()
+            } else {
+                let joined = format!("{}{}", pending, line);
+                pending.clear();
+                joined
+            };
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = name.trim().to_string();
+            } else if let Some((key, value)) = line.split_once('=') {
+                entries.push((
+                    section.clone(),
+                    key.trim().to_string(),
+                    value.trim().to_string(),
+                ));
+            }
+        }
+
+        UnitFile { entries }
+    }
+
+    /// Last value of a setting
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.get_all(section, key).last().copied()
+    }
+
+    /// All values of a list setting; an empty assignment resets the list
+    pub fn get_all(&self, section: &str, key: &str) -> Vec<&str> {
+        let mut values = Vec::new();
+        for (s, k, v) in &self.entries {
+            if s == section && k == key {
+                if v.is_empty() {
+                    values.clear();
+                } else {
+                    values.push(v.as_str());
+                }
+            }
+        }
+        values
+    }
+}
+
+/// Parse a systemd boolean (`yes`, `true`, `on`, `1`)
+pub fn parse_bool(value: &str) -> bool {
+    matches!(
+        value.to_ascii_lowercase().as_str(),
+        "1" | "yes" | "y" | "true" | "t" | "on"
+    )
+}
+
+/// Template unit that `Hooks` drop-ins name in `OnFailure=`
+pub const FAIL_TEMPLATE_UNIT: &str = "pakyas-fail@.service";
+
+/// Content of the `pakyas-fail@.service` template (the instance name is the check slug)
+pub fn fail_template(pakyas_bin: &str) -> String {
+    format!(
+        "[Unit]\n\
+         Description=Report failed %i run to Pakyas\n\
+         \n\
+         [Service]\n\
+         Type=oneshot\n\
+         ExecStart={} ping %i --fail\n",
+        pakyas_bin
+    )
+}
+
+/// Build the drop-in for a service
+///
+/// `Wrap` needs exactly one ExecStart; anything else is an error so the caller can fall
+/// back to hooks or report it.
+pub fn drop_in(
+    mode: Instrument,
+    slug: &str,
+    exec_start: &[&str],
+    pakyas_bin: &str,
+) -> Result<String, String> {
+    let header = "# Generated by pakyas systemd import\n";
+    match mode {
+        Instrument::Wrap => {
+            let [command] = exec_start else {
+                return Err(format!(
+                    "{} ExecStart lines; use --instrument hooks",
+                    exec_start.len()
+                ));
+            };
+            // Keep special prefixes like '-' (ignore failure) or '+' (full privileges)
+            let split = command
+                .find(|c: char| !"-@:+!".contains(c))
+                .unwrap_or(command.len());
+            let (prefix, command) = command.split_at(split);
+            Ok(format!(
+                "{}[Service]\nExecStart=\nExecStart={}{} monitor {} -- {}\n",
+                header, prefix, pakyas_bin, slug, command
+            ))
+        }
+        Instrument::Hooks => Ok(format!(
+            "{}[Unit]\nOnFailure=pakyas-fail@{}.service\n\n[Service]\nExecStartPre=-{} ping {} --start\nExecStartPost=-{} ping {}\n",
+            header, slug, pakyas_bin, slug, pakyas_bin, slug
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_unit() {
+        let unit = UnitFile::parse(
+            "[Unit]\nDescription=Backup\n\n[Timer]\n# comment\nOnCalendar=daily\nOnCalendar=\nOnCalendar=*-*-* 02:00\nPersistent=true\n\n[Service]\nExecStart=/usr/bin/backup \\\n  --full\n",
+        );
+        assert_eq!(unit.get_all("Timer", "OnCalendar"), vec!["*-*-* 02:00"]);
+        assert_eq!(unit.get("Timer", "Persistent"), Some("true"));
+        assert_eq!(
+            unit.get("Service", "ExecStart"),
+            Some("/usr/bin/backup --full")
+        );
+        assert_eq!(unit.get("Timer", "Unit"), None);
+    }
+
+    #[test]
+    fn test_drop_in_wrap() {
+        let content = drop_in(
+            Instrument::Wrap,
+            "backup",
+            &["-/usr/bin/backup --full"],
+            "/usr/local/bin/pakyas",
+        )
+        .unwrap();
+        assert!(content.contains(
+            "ExecStart=\nExecStart=-/usr/local/bin/pakyas monitor backup -- /usr/bin/backup --full\n"
+        ));
+
+        let err = drop_in(Instrument::Wrap, "backup", &["/a", "/b"], "pakyas").unwrap_err();
+        assert!(err.contains("hooks"));
+    }
+
+    #[test]
+    fn test_drop_in_hooks() {
+        let content = drop_in(Instrument::Hooks, "backup", &[], "pakyas").unwrap();
+        assert!(content.contains("OnFailure=pakyas-fail@backup.service"));
+        assert!(content.contains("ExecStartPre=-pakyas ping backup --start"));
+        assert!(content.contains("ExecStartPost=-pakyas ping backup\n"));
+        assert!(fail_template("pakyas").contains("ExecStart=pakyas ping %i --fail"));
+    }
+}
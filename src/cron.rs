@@ -57,11 +57,22 @@ pub fn validate_cron_expression(expr: &str) -> Result<(), String> {
 }
 
 /// Get next N cron times in the specified timezone.
+/// The expression is evaluated in that timezone, so `0 2 * * *` means 02:00 local time.
 /// Used for dry-run display to show upcoming executions in the user's timezone.
 pub fn next_cron_times_in_tz(
     cron_expr: &str,
     tz: chrono_tz::Tz,
     count: usize,
+) -> Vec<chrono::DateTime<chrono_tz::Tz>> {
+    next_cron_times_in_tz_after(cron_expr, tz, chrono::Utc::now(), count)
+}
+
+/// Like [`next_cron_times_in_tz`], starting after a given instant.
+pub fn next_cron_times_in_tz_after(
+    cron_expr: &str,
+    tz: chrono_tz::Tz,
+    after: chrono::DateTime<chrono::Utc>,
+    count: usize,
 ) -> Vec<chrono::DateTime<chrono_tz::Tz>> {
     let normalized = normalize_cron_expression(cron_expr);
     let schedule = match Schedule::from_str(&normalized) {
@@ -69,17 +80,59 @@ pub fn next_cron_times_in_tz(
         Err(_) => return vec![],
     };
 
-    let now_utc = chrono::Utc::now();
     schedule
-        .after(&now_utc)
+        .after(&after.with_timezone(&tz))
         .take(count)
-        .map(|dt| dt.with_timezone(&tz))
         .collect()
 }
 
+/// Whether two cron schedules (each in its own timezone) fire at the same instants.
+/// Runs are compared from a start point in each month of the coming year, which covers
+/// DST changes and month-specific fields without walking a whole year of frequent runs.
+/// Each sample spans a full week (so every hour and weekday is seen) and at least
+/// a couple dozen runs (so rare schedules are compared too), capped for very frequent ones.
+/// Invalid expressions are never equivalent.
+pub fn cron_schedules_equivalent(
+    a: &str,
+    tz_a: chrono_tz::Tz,
+    b: &str,
+    tz_b: chrono_tz::Tz,
+) -> bool {
+    const MIN_RUNS_PER_SAMPLE: usize = 24;
+    /// One run a minute for a week
+    const MAX_RUNS_PER_SAMPLE: usize = 7 * 24 * 60;
+
+    let (Ok(schedule_a), Ok(schedule_b)) = (
+        Schedule::from_str(&normalize_cron_expression(a)),
+        Schedule::from_str(&normalize_cron_expression(b)),
+    ) else {
+        return false;
+    };
+    let sample = |schedule: &Schedule, tz: chrono_tz::Tz, start: chrono::DateTime<chrono::Utc>| {
+        let end = start + chrono::Duration::weeks(1);
+        schedule
+            .after(&start.with_timezone(&tz))
+            .map(|t| t.with_timezone(&chrono::Utc))
+            .enumerate()
+            .take_while(|(i, t)| *i < MIN_RUNS_PER_SAMPLE || *t < end)
+            .take(MAX_RUNS_PER_SAMPLE)
+            .map(|(_, t)| t)
+            .collect::<Vec<_>>()
+    };
+
+    let now = chrono::Utc::now();
+    (0..12).all(|month| {
+        let start = now + chrono::Duration::days(30 * month);
+        let runs_a = sample(&schedule_a, tz_a, start);
+        let runs_b = sample(&schedule_b, tz_b, start);
+        !runs_a.is_empty() && runs_a == runs_b
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Timelike;
 
     #[test]
     fn test_invalid_cron() {
@@ -148,9 +201,64 @@ mod tests {
         let times = next_cron_times_in_tz("0 2 * * *", Manila, 3);
         assert_eq!(times.len(), 3);
 
-        // All times should be in Manila timezone
+        // All times should be in Manila timezone, at 02:00 local time
         for time in &times {
             assert_eq!(time.timezone(), Manila);
+            assert_eq!(time.hour(), 2);
         }
     }
+
+    #[test]
+    fn test_cron_schedules_equivalent() {
+        use chrono_tz::{Asia::Manila, Europe::Riga, UTC};
+
+        // Different spellings of the same schedule
+        assert!(cron_schedules_equivalent(
+            "0 0 * * MON-FRI",
+            UTC,
+            "0 0 * * MON,TUE,WED,THU,FRI",
+            UTC
+        ));
+        assert!(cron_schedules_equivalent(
+            "*/15 * * * *",
+            UTC,
+            "0,15,30,45 * * * *",
+            UTC
+        ));
+        // Manila has no DST, so 10:00 there is always 02:00 UTC
+        assert!(cron_schedules_equivalent(
+            "0 10 * * *",
+            Manila,
+            "0 2 * * *",
+            UTC
+        ));
+        // Riga shifts with DST, so no fixed UTC hour matches all year
+        assert!(!cron_schedules_equivalent(
+            "0 4 * * *",
+            Riga,
+            "0 2 * * *",
+            UTC
+        ));
+        assert!(!cron_schedules_equivalent(
+            "0 2 * * *",
+            UTC,
+            "0 3 * * *",
+            UTC
+        ));
+        // Frequent schedules that only differ late in the day
+        assert!(!cron_schedules_equivalent(
+            "*/15 * * * *",
+            UTC,
+            "*/15 0-22 * * *",
+            UTC
+        ));
+        // Rare schedules still compare their runs
+        assert!(!cron_schedules_equivalent(
+            "0 0 1 1 *",
+            UTC,
+            "0 0 2 1 *",
+            UTC
+        ));
+        assert!(!cron_schedules_equivalent("invalid", UTC, "invalid", UTC));
+    }
 }
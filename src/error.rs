@@ -40,6 +40,9 @@ pub enum CliError {
     #[error("Invalid config format: {0}")]
     ConfigParse(#[from] toml::de::Error),
 
+    #[error("Invalid config value at {location}: {message}")]
+    ConfigInterpolation { location: String, message: String },
+
     #[error("Failed to serialize config: {0}")]
     ConfigSerialize(#[from] toml::ser::Error),
 
@@ -0,0 +1,341 @@
+//! Local log of external monitor dispatch outcomes, for migration reporting.
+//!
+//! Every dispatch appends one JSON line per target to `external_outcomes.jsonl` in the
+//! config directory, recording whether the target accepted the event and whether the
+//! Pakyas ping for the same event succeeded. `pakyas external report` summarizes it.
+//!
+//! Set `PAKYAS_EXTERNAL_LOG=0` to disable logging.
+
+use crate::config::Config;
+use crate::error::CliError;
+use crate::external_ping::{EventType, TargetOutcome};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Rotate the log once it grows past this size (one previous file is kept)
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+const LOG_FILE_NAME: &str = "external_outcomes.jsonl";
+
+/// One target's outcome for one event
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutcomeRecord {
+    pub timestamp: DateTime<Utc>,
+    pub check: String,
+    pub event: EventType,
+    pub target: String,
+    pub url: String,
+    pub success: bool,
+    #[serde(default)]
+    pub timed_out: bool,
+    pub latency_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Whether the Pakyas ping for the same event succeeded (None if unknown)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pakyas_ok: Option<bool>,
+}
+
+impl OutcomeRecord {
+    pub fn new(
+        check: &str,
+        event: EventType,
+        pakyas_ok: Option<bool>,
+        outcome: &TargetOutcome,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            check: check.to_string(),
+            event,
+            target: outcome.target.to_string(),
+            url: outcome.url.clone(),
+            success: outcome.success,
+            timed_out: outcome.timed_out,
+            latency_ms: outcome.latency_ms,
+            error: outcome.error.clone(),
+            pakyas_ok,
+        }
+    }
+}
+
+/// Whether outcome logging is enabled (PAKYAS_EXTERNAL_LOG=0/false disables it)
+pub fn is_enabled() -> bool {
+    !std::env::var("PAKYAS_EXTERNAL_LOG")
+        .map(|v| matches!(v.to_lowercase().as_str(), "0" | "false" | "no"))
+        .unwrap_or(false)
+}
+
+/// Path to the outcome log
+pub fn path() -> Result<PathBuf, CliError> {
+    Ok(Config::config_dir()?.join(LOG_FILE_NAME))
+}
+
+/// Path of the rotated (previous) log
+fn rotated_path(path: &Path) -> PathBuf {
+    path.with_extension("jsonl.1")
+}
+
+/// Append outcomes for one event to the default log
+///
+/// Errors are returned for verbose logging; callers should never fail a job on them.
+pub fn record(
+    check: &str,
+    event: EventType,
+    pakyas_ok: Option<bool>,
+    outcomes: &[TargetOutcome],
+) -> Result<(), CliError> {
+    if outcomes.is_empty() || !is_enabled() {
+        return Ok(());
+    }
+    let records: Vec<OutcomeRecord> = outcomes
+        .iter()
+        .map(|o| OutcomeRecord::new(check, event, pakyas_ok, o))
+        .collect();
+    append_to_path(&path()?, &records)
+}
+
+/// Record outcomes, reporting failures only in verbose mode (never fails the caller)
+pub fn record_quietly(
+    check: &str,
+    event: EventType,
+    pakyas_ok: Option<bool>,
+    outcomes: &[TargetOutcome],
+    verbose: bool,
+) {
+    if let Err(e) = record(check, event, pakyas_ok, outcomes) {
+        if verbose {
+            eprintln!("[verbose] Failed to write external outcome log: {}", e);
+        }
+    }
+}
+
+/// Append records to a specific log file, rotating it when too large
+pub fn append_to_path(path: &Path, records: &[OutcomeRecord]) -> Result<(), CliError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(CliError::ConfigWrite)?;
+    }
+
+    if std::fs::metadata(path).is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+        std::fs::rename(path, rotated_path(path)).map_err(CliError::ConfigWrite)?;
+    }
+
+    // One write per event keeps concurrent appenders from interleaving lines
+    let mut buf = String::new();
+    for record in records {
+        buf.push_str(&serde_json::to_string(record)?);
+        buf.push('\n');
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(CliError::ConfigWrite)?;
+    file.write_all(buf.as_bytes())
+        .map_err(CliError::ConfigWrite)?;
+    Ok(())
+}
+
+/// Load records at or after `since` from a log file and its rotated predecessor
+///
+/// Malformed lines (e.g., a partially written last line) are skipped.
+pub fn load_from_path(path: &Path, since: DateTime<Utc>) -> Result<Vec<OutcomeRecord>, CliError> {
+    let mut records = Vec::new();
+    for file in [rotated_path(path), path.to_path_buf()] {
+        if !file.exists() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&file).map_err(CliError::ConfigRead)?;
+        records.extend(
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str::<OutcomeRecord>(line).ok())
+                .filter(|r| r.timestamp >= since),
+        );
+    }
+    Ok(records)
+}
+
+/// Per-target summary over a set of records
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TargetSummary {
+    pub target: String,
+    pub url: String,
+    pub total: usize,
+    pub succeeded: usize,
+    pub timeouts: usize,
+    pub success_rate: f64,
+    pub avg_latency_ms: u64,
+    pub p95_latency_ms: u64,
+    /// Pakyas ping failed but this target accepted the event
+    pub pakyas_failed_target_ok: usize,
+    /// Pakyas ping succeeded but this target did not
+    pub pakyas_ok_target_failed: usize,
+}
+
+/// Summarize records per target (grouped by target name and destination)
+pub fn summarize(records: &[OutcomeRecord]) -> Vec<TargetSummary> {
+    let mut groups: BTreeMap<(&str, &str), Vec<&OutcomeRecord>> = BTreeMap::new();
+    for record in records {
+        groups
+            .entry((record.target.as_str(), record.url.as_str()))
+            .or_default()
+            .push(record);
+    }
+
+    groups
+        .into_iter()
+        .map(|((target, url), group)| {
+            let total = group.len();
+            let succeeded = group.iter().filter(|r| r.success).count();
+
+            let mut latencies: Vec<u64> = group.iter().map(|r| r.latency_ms).collect();
+            latencies.sort_unstable();
+            let avg_latency_ms = latencies.iter().sum::<u64>() / total as u64;
+            let p95_index = (total * 95).div_ceil(100).saturating_sub(1);
+
+            TargetSummary {
+                target: target.to_string(),
+                url: url.to_string(),
+                total,
+                succeeded,
+                timeouts: group.iter().filter(|r| r.timed_out).count(),
+                success_rate: succeeded as f64 / total as f64,
+                avg_latency_ms,
+                p95_latency_ms: latencies[p95_index],
+                pakyas_failed_target_ok: group
+                    .iter()
+                    .filter(|r| r.pakyas_ok == Some(false) && r.success)
+                    .count(),
+                pakyas_ok_target_failed: group
+                    .iter()
+                    .filter(|r| r.pakyas_ok == Some(true) && !r.success)
+                    .count(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn outcome(target: &'static str, success: bool, latency_ms: u64) -> TargetOutcome {
+        TargetOutcome {
+            target,
+            url: format!("https://{}.example.com", target),
+            success,
+            timed_out: false,
+            attempts: 1,
+            latency_ms,
+            error: (!success).then(|| "boom".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_append_and_load_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+
+        let old = OutcomeRecord {
+            timestamp: Utc::now() - chrono::Duration::days(10),
+            ..OutcomeRecord::new(
+                "backup-db",
+                EventType::Success,
+                Some(true),
+                &outcome("cronitor", true, 10),
+            )
+        };
+        let recent = OutcomeRecord::new(
+            "backup-db",
+            EventType::Fail,
+            Some(false),
+            &outcome("healthchecks.io", false, 20),
+        );
+        append_to_path(&path, &[old, recent.clone()]).unwrap();
+
+        // Partially written line is ignored
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"timestamp\":")
+            .unwrap();
+
+        let since = Utc::now() - chrono::Duration::days(7);
+        let loaded = load_from_path(&path, since).unwrap();
+        assert_eq!(loaded, vec![recent]);
+    }
+
+    #[test]
+    fn test_load_includes_rotated_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+
+        let first = OutcomeRecord::new(
+            "a",
+            EventType::Start,
+            Some(true),
+            &outcome("webhook", true, 1),
+        );
+        append_to_path(&rotated_path(&path), std::slice::from_ref(&first)).unwrap();
+        let second = OutcomeRecord::new(
+            "a",
+            EventType::Success,
+            Some(true),
+            &outcome("webhook", true, 2),
+        );
+        append_to_path(&path, std::slice::from_ref(&second)).unwrap();
+
+        let loaded = load_from_path(&path, Utc::now() - chrono::Duration::hours(1)).unwrap();
+        assert_eq!(loaded, vec![first, second]);
+    }
+
+    #[test]
+    fn test_summarize() {
+        let mut records = vec![
+            OutcomeRecord::new(
+                "a",
+                EventType::Success,
+                Some(true),
+                &outcome("cronitor", true, 100),
+            ),
+            OutcomeRecord::new(
+                "a",
+                EventType::Success,
+                Some(true),
+                &outcome("cronitor", false, 300),
+            ),
+            OutcomeRecord::new(
+                "a",
+                EventType::Fail,
+                Some(false),
+                &outcome("cronitor", true, 200),
+            ),
+            OutcomeRecord::new("a", EventType::Success, None, &outcome("webhook", true, 50)),
+        ];
+        records[1].timed_out = true;
+
+        let summary = summarize(&records);
+        assert_eq!(summary.len(), 2);
+
+        let cronitor = &summary[0];
+        assert_eq!(cronitor.target, "cronitor");
+        assert_eq!(cronitor.total, 3);
+        assert_eq!(cronitor.succeeded, 2);
+        assert_eq!(cronitor.timeouts, 1);
+        assert_eq!(cronitor.avg_latency_ms, 200);
+        assert_eq!(cronitor.p95_latency_ms, 300);
+        assert_eq!(cronitor.pakyas_failed_target_ok, 1);
+        assert_eq!(cronitor.pakyas_ok_target_failed, 1);
+
+        let webhook = &summary[1];
+        assert_eq!(webhook.total, 1);
+        assert!((webhook.success_rate - 1.0).abs() < f64::EPSILON);
+        assert_eq!(webhook.pakyas_failed_target_ok, 0);
+    }
+}
@@ -1,14 +1,23 @@
-//! External monitor configuration for integration with healthchecks.io, cronitor, and webhooks.
+//! External monitor configuration for integration with healthchecks.io, cronitor, webhooks,
+//! Prometheus Pushgateway, OpenTelemetry (OTLP/HTTP) collectors, local exec hooks, and
+//! syslog/journald.
 //!
 //! This module handles loading and merging configuration for external monitoring services,
 //! allowing pakyas-cli to ping multiple services in parallel during migrations.
+//!
+//! String values may reference `${VAR}`, `${VAR:-default}` or `file:/path` (see
+//! [`crate::interpolate`]), except exec hook commands, which are run by a shell that
+//! expands variables itself. Values read from files or substituted into credential
+//! fields are registered as secrets and masked in `display_url` and warning output.
 
 use crate::config::Config;
 use crate::error::CliError;
+use crate::external_ping::EventType;
 use directories::BaseDirs;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Mutex;
 
 /// Default healthchecks.io endpoint
 pub const DEFAULT_HEALTHCHECKS_ENDPOINT: &str = "https://hc-ping.com";
@@ -16,6 +25,69 @@ pub const DEFAULT_HEALTHCHECKS_ENDPOINT: &str = "https://hc-ping.com";
 /// Default cronitor telemetry endpoint
 pub const DEFAULT_CRONITOR_ENDPOINT: &str = "https://cronitor.link";
 
+/// Default Pushgateway job label
+pub const DEFAULT_PUSHGATEWAY_JOB: &str = "pakyas";
+
+/// Default OpenTelemetry service.name resource attribute
+pub const DEFAULT_OTLP_SERVICE_NAME: &str = "pakyas";
+
+/// Default timeout for exec hooks in milliseconds
+pub const DEFAULT_EXEC_TIMEOUT_MS: u64 = 10_000;
+
+/// Default syslog APP-NAME / journald SYSLOG_IDENTIFIER
+pub const DEFAULT_SYSLOG_APP_NAME: &str = "pakyas";
+
+/// Default journald native protocol socket
+pub const DEFAULT_JOURNALD_SOCKET: &str = "/run/systemd/journal/socket";
+
+/// Placeholder shown instead of secret values
+const SECRET_MASK: &str = "***";
+
+/// Resolved values shorter than this are not masked (avoids mangling unrelated text)
+const MIN_SECRET_LEN: usize = 4;
+
+/// Config keys holding credentials, or URLs that may embed one
+const CREDENTIAL_KEYS: &[&str] = &["api_key", "uuid", "url", "endpoint"];
+
+/// Values resolved from `${VAR}` / `file:` references in the loaded config
+static SECRETS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Register resolved values so they are masked in display output
+fn register_secrets(values: Vec<String>) {
+    let mut secrets = SECRETS.lock().unwrap_or_else(|e| e.into_inner());
+    for value in values {
+        if value.len() >= MIN_SECRET_LEN && !secrets.contains(&value) {
+            secrets.push(value);
+        }
+    }
+    // Longest first so a secret containing another is masked whole
+    secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+}
+
+/// Whether a config location (e.g. `checks.db.targets.cronitor.api_key`) is a credential
+///
+/// Header values (OTLP auth tokens) count as credentials too.
+fn is_credential_location(location: &str) -> bool {
+    let mut keys = location
+        .rsplit('.')
+        .map(|key| key.split('[').next().unwrap_or(key));
+    let last = keys.next().unwrap_or_default();
+    CREDENTIAL_KEYS.contains(&last) || keys.next() == Some("headers")
+}
+
+/// Exec hook commands are left to the shell, which expands `$PAKYAS_EVENT_*` itself
+fn is_exec_command_location(location: &str) -> bool {
+    location == "exec.command" || location.ends_with(".exec.command")
+}
+
+/// Replace any registered secret values in `text` with `***`
+pub fn mask_secrets(text: &str) -> String {
+    let secrets = SECRETS.lock().unwrap_or_else(|e| e.into_inner());
+    secrets.iter().fold(text.to_string(), |acc, secret| {
+        acc.replace(secret.as_str(), SECRET_MASK)
+    })
+}
+
 /// Root configuration loaded from external_monitors.toml
 #[derive(Debug, Deserialize, Default)]
 pub struct ExternalMonitorsFile {
@@ -30,6 +102,10 @@ pub struct ExternalMonitorsFile {
     /// Per-check target configurations
     #[serde(default)]
     pub checks: HashMap<String, CheckTargets>,
+
+    /// Rules attaching targets to many checks by slug glob and/or tags
+    #[serde(default)]
+    pub rules: Vec<Rule>,
 }
 
 /// Global target settings - shared across all checks
@@ -43,6 +119,21 @@ pub struct GlobalTargets {
 
     #[serde(default)]
     pub webhook: Option<GlobalWebhook>,
+
+    #[serde(default)]
+    pub pushgateway: Option<GlobalPushgateway>,
+
+    #[serde(default)]
+    pub otlp: Option<GlobalOtlp>,
+
+    #[serde(default)]
+    pub exec: Option<ExecHook>,
+
+    #[serde(default)]
+    pub syslog: Option<GlobalSyslog>,
+
+    #[serde(default)]
+    pub journald: Option<GlobalJournald>,
 }
 
 /// Global healthchecks settings (endpoint only, no uuid)
@@ -75,6 +166,133 @@ pub struct GlobalWebhook {
     pub url: String,
 }
 
+/// Global Prometheus Pushgateway settings
+#[derive(Debug, Deserialize, Clone)]
+pub struct GlobalPushgateway {
+    pub url: String,
+
+    #[serde(default = "default_pushgateway_job")]
+    pub job: String,
+}
+
+fn default_pushgateway_job() -> String {
+    DEFAULT_PUSHGATEWAY_JOB.to_string()
+}
+
+/// Global OpenTelemetry settings (OTLP/HTTP collector base URL)
+#[derive(Debug, Deserialize, Clone)]
+pub struct GlobalOtlp {
+    pub endpoint: String,
+
+    #[serde(default = "default_otlp_service_name")]
+    pub service_name: String,
+
+    /// Extra request headers (e.g., collector auth tokens)
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+}
+
+fn default_otlp_service_name() -> String {
+    DEFAULT_OTLP_SERVICE_NAME.to_string()
+}
+
+/// Local exec hook settings (usable globally or per check)
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExecHook {
+    /// Shell command to run (via `sh -c`, or `cmd /C` on Windows)
+    pub command: String,
+
+    #[serde(default = "default_exec_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Event types that trigger the hook (empty = all events)
+    #[serde(default)]
+    pub events: Vec<EventType>,
+}
+
+fn default_exec_timeout_ms() -> u64 {
+    DEFAULT_EXEC_TIMEOUT_MS
+}
+
+/// Transport used to reach a syslog receiver
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SyslogTransport {
+    Udp,
+    Tcp,
+    Unix,
+}
+
+/// Syslog facility (subset useful for job audit trails)
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SyslogFacility {
+    #[default]
+    User,
+    Daemon,
+    Cron,
+    Local0,
+    Local1,
+    Local2,
+    Local3,
+    Local4,
+    Local5,
+    Local6,
+    Local7,
+}
+
+impl SyslogFacility {
+    /// Numeric facility code (RFC 5424 section 6.2.1)
+    pub fn code(self) -> u8 {
+        match self {
+            SyslogFacility::User => 1,
+            SyslogFacility::Daemon => 3,
+            SyslogFacility::Cron => 9,
+            SyslogFacility::Local0 => 16,
+            SyslogFacility::Local1 => 17,
+            SyslogFacility::Local2 => 18,
+            SyslogFacility::Local3 => 19,
+            SyslogFacility::Local4 => 20,
+            SyslogFacility::Local5 => 21,
+            SyslogFacility::Local6 => 22,
+            SyslogFacility::Local7 => 23,
+        }
+    }
+}
+
+/// Global syslog settings (RFC 5424 messages)
+#[derive(Debug, Deserialize, Clone)]
+pub struct GlobalSyslog {
+    pub transport: SyslogTransport,
+
+    /// host:port for udp/tcp, socket path (e.g., /dev/log) for unix
+    pub address: String,
+
+    #[serde(default)]
+    pub facility: SyslogFacility,
+
+    #[serde(default = "default_syslog_app_name")]
+    pub app_name: String,
+}
+
+fn default_syslog_app_name() -> String {
+    DEFAULT_SYSLOG_APP_NAME.to_string()
+}
+
+/// Global journald settings (native protocol)
+#[derive(Debug, Deserialize, Clone)]
+pub struct GlobalJournald {
+    #[serde(default = "default_journald_socket")]
+    pub socket: String,
+
+    #[serde(default = "default_syslog_app_name")]
+    pub identifier: String,
+}
+
+fn default_journald_socket() -> String {
+    DEFAULT_JOURNALD_SOCKET.to_string()
+}
+
 /// Per-check target configurations
 #[derive(Debug, Deserialize, Default)]
 pub struct CheckTargets {
@@ -82,14 +300,96 @@ pub struct CheckTargets {
     pub targets: CheckTargetIds,
 }
 
-/// Per-check target IDs
+/// Rule matching checks by slug glob and/or tags
+///
+/// A rule applies when every condition it sets holds: `match` against the slug, and
+/// at least one of `tags` on the check. Healthchecks and cronitor ids may use `{slug}`,
+/// e.g. `monitor_key = "{slug}"`; exec commands get the check in `$PAKYAS_EVENT_CHECK`.
 #[derive(Debug, Deserialize, Default)]
+pub struct Rule {
+    #[serde(default, rename = "match")]
+    pub pattern: Option<String>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    pub targets: CheckTargetIds,
+}
+
+impl Rule {
+    /// Whether this rule applies to the given check
+    pub fn matches(&self, check: &CheckMeta) -> bool {
+        if self.pattern.is_none() && self.tags.is_empty() {
+            return false;
+        }
+        let pattern_ok = self
+            .pattern
+            .as_deref()
+            .is_none_or(|p| crate::glob::glob_match(p, check.slug()));
+        let tags_ok = self.tags.is_empty() || self.tags.iter().any(|t| check.tags.contains(t));
+        pattern_ok && tags_ok
+    }
+}
+
+/// What external monitor rules can match on for a check
+#[derive(Debug, Clone, Default)]
+pub struct CheckMeta {
+    /// Config lookup key (slug or public_id, as invoked)
+    pub key: String,
+    /// Slug, when known (e.g., resolved from a public_id via the cache)
+    pub slug: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl CheckMeta {
+    /// Metadata from the key alone (no tags)
+    pub fn from_key(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Metadata from the local check cache
+    ///
+    /// Looks up by public_id first (gives the slug for public_id invocations), then
+    /// by key as a slug. `None` when the check isn't cached.
+    pub fn cached(key: &str, public_id: Option<&uuid::Uuid>) -> Option<Self> {
+        let cache = crate::cache::CheckCache::load().ok()?;
+        let (slug, entry) = match public_id.and_then(|id| cache.find_by_public_id(id)) {
+            Some((slug, entry)) => (slug, entry),
+            None => (key, cache.find_by_slug(key)?),
+        };
+        Some(Self {
+            key: key.to_string(),
+            slug: Some(slug.to_string()),
+            tags: entry.tags.clone(),
+        })
+    }
+
+    /// Slug used for glob matching and `{slug}` templating
+    pub fn slug(&self) -> &str {
+        self.slug.as_deref().unwrap_or(&self.key)
+    }
+}
+
+/// Substitute `{slug}` in a rule-provided id
+fn apply_template(value: &str, check: &CheckMeta) -> String {
+    value.replace("{slug}", check.slug())
+}
+
+/// Per-check target IDs
+#[derive(Debug, Deserialize, Default, Clone)]
 pub struct CheckTargetIds {
     #[serde(default)]
     pub healthchecks: Option<CheckHealthchecks>,
 
     #[serde(default)]
     pub cronitor: Option<CheckCronitor>,
+
+    #[serde(default)]
+    pub exec: Option<ExecHook>,
 }
 
 /// Per-check healthchecks config (uuid required)
@@ -119,6 +419,30 @@ pub enum MonitorTarget {
     Webhook {
         url: String,
     },
+    Pushgateway {
+        endpoint: String,
+        job: String,
+    },
+    Otlp {
+        endpoint: String,
+        service_name: String,
+        headers: HashMap<String, String>,
+    },
+    Exec {
+        command: String,
+        timeout_ms: u64,
+        events: Vec<EventType>,
+    },
+    Syslog {
+        transport: SyslogTransport,
+        address: String,
+        facility: SyslogFacility,
+        app_name: String,
+    },
+    Journald {
+        socket: String,
+        identifier: String,
+    },
 }
 
 impl MonitorTarget {
@@ -128,11 +452,41 @@ impl MonitorTarget {
             MonitorTarget::Healthchecks { .. } => "healthchecks.io",
             MonitorTarget::Cronitor { .. } => "cronitor",
             MonitorTarget::Webhook { .. } => "webhook",
+            MonitorTarget::Pushgateway { .. } => "pushgateway",
+            MonitorTarget::Otlp { .. } => "otlp",
+            MonitorTarget::Exec { .. } => "exec",
+            MonitorTarget::Syslog { .. } => "syslog",
+            MonitorTarget::Journald { .. } => "journald",
+        }
+    }
+
+    /// Whether this target sends events of this type (exec hooks may be limited to some)
+    pub fn accepts(&self, event_type: EventType) -> bool {
+        match self {
+            MonitorTarget::Exec { events, .. } => events.is_empty() || events.contains(&event_type),
+            _ => true,
         }
     }
 
+    /// Whether a successful send means a monitoring service received the event
+    ///
+    /// Local exec hooks exiting 0 and syslog/journald socket writes say nothing about
+    /// delivery, so they can't stand in for a failed pakyas ping in migration mode.
+    pub fn confirms_delivery(&self) -> bool {
+        !matches!(
+            self,
+            MonitorTarget::Exec { .. }
+                | MonitorTarget::Syslog { .. }
+                | MonitorTarget::Journald { .. }
+        )
+    }
+
     /// Get a display URL for verbose logging (hides sensitive parts)
     pub fn display_url(&self) -> String {
+        mask_secrets(&self.unmasked_display_url())
+    }
+
+    fn unmasked_display_url(&self) -> String {
         match self {
             MonitorTarget::Healthchecks { endpoint, uuid } => {
                 format!("{}/{}", endpoint, uuid)
@@ -146,6 +500,34 @@ impl MonitorTarget {
                 format!("{}/p/***/{}", endpoint, monitor_key)
             }
             MonitorTarget::Webhook { url } => url.clone(),
+            MonitorTarget::Pushgateway { endpoint, job } => {
+                format!("{}/metrics/job/{}", endpoint.trim_end_matches('/'), job)
+            }
+            // Header values may carry tokens, so only the endpoint is shown
+            MonitorTarget::Otlp { endpoint, .. } => {
+                format!("{}/v1/traces", endpoint.trim_end_matches('/'))
+            }
+            MonitorTarget::Exec { command, .. } => format!("exec: {}", command),
+            MonitorTarget::Syslog {
+                transport, address, ..
+            } => {
+                let scheme = match transport {
+                    SyslogTransport::Udp => "udp",
+                    SyslogTransport::Tcp => "tcp",
+                    SyslogTransport::Unix => "unix",
+                };
+                format!("{}://{}", scheme, address)
+            }
+            MonitorTarget::Journald { socket, .. } => format!("unix://{}", socket),
+        }
+    }
+
+    /// Create an exec target from hook settings
+    pub fn from_exec_hook(hook: &ExecHook) -> Self {
+        MonitorTarget::Exec {
+            command: hook.command.clone(),
+            timeout_ms: hook.timeout_ms,
+            events: hook.events.clone(),
         }
     }
 
@@ -205,6 +587,125 @@ impl MonitorTarget {
     }
 }
 
+/// Known keys at each level of external_monitors.toml (for unknown-key detection)
+const ROOT_KEYS: &[&str] = &["migration_mode", "targets", "checks", "rules"];
+const GLOBAL_TARGET_KEYS: &[&str] = &[
+    "healthchecks",
+    "cronitor",
+    "webhook",
+    "pushgateway",
+    "otlp",
+    "exec",
+    "syslog",
+    "journald",
+];
+const CHECK_KEYS: &[&str] = &["targets"];
+const CHECK_TARGET_KEYS: &[&str] = &["healthchecks", "cronitor", "exec"];
+const RULE_KEYS: &[&str] = &["match", "tags", "targets"];
+
+/// A problem found while validating external_monitors.toml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// Dotted path to the offending table, e.g. `checks.backup-db.targets.cronitor`
+    pub location: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new(location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Report keys that serde would silently ignore (typos like `[targets.healthcheck]`)
+pub fn unknown_key_issues(value: &toml::Value) -> Vec<ConfigIssue> {
+    fn check_table(
+        table: Option<&toml::Table>,
+        known: &[&str],
+        location: &str,
+        issues: &mut Vec<ConfigIssue>,
+    ) {
+        for key in table.into_iter().flat_map(|t| t.keys()) {
+            if !known.contains(&key.as_str()) {
+                let path = if location.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", location, key)
+                };
+                issues.push(ConfigIssue::new(
+                    path,
+                    format!(
+                        "unknown key '{}' (expected one of: {})",
+                        key,
+                        known.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    let mut issues = Vec::new();
+    let root = value.as_table();
+    check_table(root, ROOT_KEYS, "", &mut issues);
+
+    let targets = root
+        .and_then(|r| r.get("targets"))
+        .and_then(|t| t.as_table());
+    check_table(targets, GLOBAL_TARGET_KEYS, "targets", &mut issues);
+
+    let checks = root
+        .and_then(|r| r.get("checks"))
+        .and_then(|c| c.as_table());
+    for (key, check) in checks.into_iter().flatten() {
+        let location = format!("checks.{}", key);
+        check_table(check.as_table(), CHECK_KEYS, &location, &mut issues);
+
+        let check_targets = check
+            .as_table()
+            .and_then(|c| c.get("targets"))
+            .and_then(|t| t.as_table());
+        check_table(
+            check_targets,
+            CHECK_TARGET_KEYS,
+            &format!("{}.targets", location),
+            &mut issues,
+        );
+    }
+
+    let rules = root.and_then(|r| r.get("rules")).and_then(|r| r.as_array());
+    for (i, rule) in rules.into_iter().flatten().enumerate() {
+        let location = format!("rules[{}]", i);
+        check_table(rule.as_table(), RULE_KEYS, &location, &mut issues);
+
+        let rule_targets = rule
+            .as_table()
+            .and_then(|r| r.get("targets"))
+            .and_then(|t| t.as_table());
+        check_table(
+            rule_targets,
+            CHECK_TARGET_KEYS,
+            &format!("{}.targets", location),
+            &mut issues,
+        );
+    }
+
+    issues
+}
+
+/// Validate that a configured URL is absolute http(s)
+fn check_url(location: &str, url: &str, issues: &mut Vec<ConfigIssue>) {
+    let message = match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => return,
+        Ok(parsed) => format!("unsupported URL scheme '{}' in '{}'", parsed.scheme(), url),
+        Err(e) => format!("invalid URL '{}': {}", url, e),
+    };
+    // The URL may carry an interpolated token
+    issues.push(ConfigIssue::new(location, mask_secrets(&message)));
+}
+
 /// Loaded and resolved external monitor configuration
 #[derive(Debug)]
 pub struct ExternalMonitorConfig {
@@ -229,7 +730,26 @@ impl ExternalMonitorConfig {
         }
 
         let content = std::fs::read_to_string(path).map_err(CliError::ConfigRead)?;
-        let file_config: ExternalMonitorsFile = toml::from_str(&content)?;
+        let mut value: toml::Value = toml::from_str(&content)?;
+
+        let substitutions = crate::interpolate::interpolate_toml(
+            &mut value,
+            &|name| std::env::var(name).ok(),
+            &is_exec_command_location,
+        )
+        .map_err(|e| CliError::ConfigInterpolation {
+            location: e.location,
+            message: e.message,
+        })?;
+        register_secrets(
+            substitutions
+                .into_iter()
+                .filter(|s| s.from_file || is_credential_location(&s.location))
+                .map(|s| s.value)
+                .collect(),
+        );
+
+        let file_config: ExternalMonitorsFile = value.try_into()?;
 
         // Check for migration_mode env var override
         let migration_mode = std::env::var("PAKYAS_MIGRATION_MODE")
@@ -292,13 +812,60 @@ impl ExternalMonitorConfig {
     /// 3. Merge: global settings + per-check IDs = complete target
     /// 4. No ID configured = service skipped
     pub fn build_monitors_for_check(&self, key: &str) -> Vec<MonitorTarget> {
+        self.build_monitors(&CheckMeta::from_key(key))
+    }
+
+    /// Merge per-check IDs with matching rules
+    ///
+    /// An explicit `[checks.<key>]` setting wins; otherwise the first matching rule
+    /// that sets a target provides it (with `{slug}` substituted in ids).
+    fn resolve_check_targets(&self, check: &CheckMeta) -> CheckTargetIds {
+        let mut resolved = self
+            .file_config
+            .checks
+            .get(&check.key)
+            .map(|c| c.targets.clone())
+            .unwrap_or_default();
+
+        for rule in self.file_config.rules.iter().filter(|r| r.matches(check)) {
+            if resolved.healthchecks.is_none() {
+                resolved.healthchecks =
+                    rule.targets
+                        .healthchecks
+                        .as_ref()
+                        .map(|hc| CheckHealthchecks {
+                            uuid: apply_template(&hc.uuid, check),
+                        });
+            }
+            if resolved.cronitor.is_none() {
+                resolved.cronitor = rule.targets.cronitor.as_ref().map(|cr| CheckCronitor {
+                    monitor_key: apply_template(&cr.monitor_key, check),
+                });
+            }
+            // Exec commands go to a shell, so they are never templated; hooks read the
+            // check from $PAKYAS_EVENT_CHECK instead
+            if resolved.exec.is_none() {
+                resolved.exec.clone_from(&rule.targets.exec);
+            }
+        }
+
+        resolved
+    }
+
+    /// Whether any `[[rules]]` entry matches by tag
+    pub fn has_tag_rules(&self) -> bool {
+        self.file_config.rules.iter().any(|r| !r.tags.is_empty())
+    }
+
+    /// Build monitor targets for a check, including targets attached by `[[rules]]`
+    pub fn build_monitors(&self, check: &CheckMeta) -> Vec<MonitorTarget> {
         let mut targets = Vec::new();
 
-        // Get per-check config if exists
-        let check_config = self.file_config.checks.get(key);
+        // Per-check IDs: explicit config merged with matching rules
+        let check_targets = self.resolve_check_targets(check);
 
         // Healthchecks.io: needs global endpoint + per-check uuid
-        if let Some(check_hc) = check_config.and_then(|c| c.targets.healthchecks.as_ref()) {
+        if let Some(check_hc) = check_targets.healthchecks.as_ref() {
             let endpoint = self
                 .file_config
                 .targets
@@ -315,7 +882,7 @@ impl ExternalMonitorConfig {
         }
 
         // Cronitor: needs global api_key + per-check monitor_key
-        if let Some(check_cr) = check_config.and_then(|c| c.targets.cronitor.as_ref()) {
+        if let Some(check_cr) = check_targets.cronitor.as_ref() {
             // api_key from config or env
             let api_key = self
                 .file_config
@@ -351,18 +918,270 @@ impl ExternalMonitorConfig {
             targets.push(MonitorTarget::Webhook { url });
         }
 
+        // Pushgateway: global only, grouped by check identifier and host at push time
+        if let Some(pushgateway) = self.file_config.targets.pushgateway.as_ref() {
+            targets.push(MonitorTarget::Pushgateway {
+                endpoint: pushgateway.url.clone(),
+                job: pushgateway.job.clone(),
+            });
+        } else if let Ok(endpoint) = std::env::var("PUSHGATEWAY_URL") {
+            targets.push(MonitorTarget::Pushgateway {
+                endpoint,
+                job: default_pushgateway_job(),
+            });
+        }
+
+        // OTLP: global only, one span per completed run
+        if let Some(otlp) = self.file_config.targets.otlp.as_ref() {
+            targets.push(MonitorTarget::Otlp {
+                endpoint: otlp.endpoint.clone(),
+                service_name: otlp.service_name.clone(),
+                headers: otlp.headers.clone(),
+            });
+        } else if let Ok(endpoint) = std::env::var("PAKYAS_OTLP_ENDPOINT") {
+            targets.push(MonitorTarget::Otlp {
+                endpoint,
+                service_name: default_otlp_service_name(),
+                headers: HashMap::new(),
+            });
+        }
+
+        // Exec: per-check hook replaces the global hook for that check
+        let exec_hook = check_targets
+            .exec
+            .as_ref()
+            .or(self.file_config.targets.exec.as_ref());
+        if let Some(hook) = exec_hook {
+            targets.push(MonitorTarget::from_exec_hook(hook));
+        }
+
+        // Syslog / journald: global only, audit trail of every event
+        if let Some(syslog) = self.file_config.targets.syslog.as_ref() {
+            targets.push(MonitorTarget::Syslog {
+                transport: syslog.transport,
+                address: syslog.address.clone(),
+                facility: syslog.facility,
+                app_name: syslog.app_name.clone(),
+            });
+        }
+        if let Some(journald) = self.file_config.targets.journald.as_ref() {
+            targets.push(MonitorTarget::Journald {
+                socket: journald.socket.clone(),
+                identifier: journald.identifier.clone(),
+            });
+        }
+
         targets
     }
 
+    /// Check keys with a `[checks.<key>]` section, sorted
+    pub fn check_keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self.file_config.checks.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        keys
+    }
+
+    /// Find settings that parse but can't work at dispatch time
+    ///
+    /// `build_monitors_for_check` skips these silently (e.g., a cronitor monitor_key
+    /// with no API key), so this is what `pakyas external validate` reports.
+    pub fn issues(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        let targets = &self.file_config.targets;
+
+        if let Some(hc) = &targets.healthchecks {
+            check_url("targets.healthchecks", &hc.endpoint, &mut issues);
+        }
+        if let Some(cr) = &targets.cronitor {
+            check_url("targets.cronitor", &cr.endpoint, &mut issues);
+            if cr.api_key.trim().is_empty() {
+                issues.push(ConfigIssue::new("targets.cronitor", "api_key is empty"));
+            }
+        }
+        if let Some(webhook) = &targets.webhook {
+            check_url("targets.webhook", &webhook.url, &mut issues);
+        }
+        if let Some(pushgateway) = &targets.pushgateway {
+            check_url("targets.pushgateway", &pushgateway.url, &mut issues);
+        }
+        if let Some(otlp) = &targets.otlp {
+            check_url("targets.otlp", &otlp.endpoint, &mut issues);
+        }
+        if let Some(exec) = &targets.exec {
+            if exec.command.trim().is_empty() {
+                issues.push(ConfigIssue::new("targets.exec", "command is empty"));
+            }
+        }
+        if let Some(syslog) = &targets.syslog {
+            if syslog.address.trim().is_empty() {
+                issues.push(ConfigIssue::new("targets.syslog", "address is empty"));
+            }
+        }
+
+        let has_cronitor_key =
+            targets.cronitor.is_some() || std::env::var("CRONITOR_API_KEY").is_ok();
+
+        for key in self.check_keys() {
+            let check = &self.file_config.checks[key].targets;
+            let location = format!("checks.{}.targets", key);
+
+            if let Some(hc) = &check.healthchecks {
+                if hc.uuid.trim().is_empty() {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.healthchecks", location),
+                        "uuid is empty",
+                    ));
+                }
+            }
+            if let Some(cr) = &check.cronitor {
+                if cr.monitor_key.trim().is_empty() {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.cronitor", location),
+                        "monitor_key is empty",
+                    ));
+                }
+                if !has_cronitor_key {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.cronitor", location),
+                        "no cronitor API key (set targets.cronitor.api_key or CRONITOR_API_KEY); \
+                         this target is skipped",
+                    ));
+                }
+            }
+            if let Some(exec) = &check.exec {
+                if exec.command.trim().is_empty() {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.exec", location),
+                        "command is empty",
+                    ));
+                }
+            }
+        }
+
+        for (i, rule) in self.file_config.rules.iter().enumerate() {
+            let location = format!("rules[{}]", i);
+            if rule.pattern.is_none() && rule.tags.is_empty() {
+                issues.push(ConfigIssue::new(
+                    &location,
+                    "rule needs `match` and/or `tags`; it never applies",
+                ));
+            }
+            let t = &rule.targets;
+            if t.healthchecks.is_none() && t.cronitor.is_none() && t.exec.is_none() {
+                issues.push(ConfigIssue::new(&location, "rule attaches no targets"));
+            }
+            if t.cronitor.is_some() && !has_cronitor_key {
+                issues.push(ConfigIssue::new(
+                    format!("{}.targets.cronitor", location),
+                    "no cronitor API key (set targets.cronitor.api_key or CRONITOR_API_KEY); \
+                     this target is skipped",
+                ));
+            }
+        }
+
+        issues
+    }
+
+    /// Whether a cronitor API key is available (needed to ping per-check monitor keys)
+    pub fn has_cronitor_api_key(&self) -> bool {
+        self.file_config.targets.cronitor.is_some() || std::env::var("CRONITOR_API_KEY").is_ok()
+    }
+
     /// Check if any external monitors are configured
     pub fn has_any_monitors(&self) -> bool {
         self.file_config.targets.webhook.is_some()
             || std::env::var("EXTERNAL_WEBHOOK_URL").is_ok()
+            || self.file_config.targets.pushgateway.is_some()
+            || std::env::var("PUSHGATEWAY_URL").is_ok()
+            || self.file_config.targets.otlp.is_some()
+            || self.file_config.targets.exec.is_some()
+            || self.file_config.targets.syslog.is_some()
+            || self.file_config.targets.journald.is_some()
+            || std::env::var("PAKYAS_OTLP_ENDPOINT").is_ok()
             || !self.file_config.checks.is_empty()
+            || !self.file_config.rules.is_empty()
+    }
+}
+
+/// Per-check target ids to add to external_monitors.toml (e.g., after an import)
+#[derive(Debug, Clone, Default)]
+pub struct CheckIdAdditions {
+    /// Target table under `checks.<slug>.targets`, e.g. `healthchecks`
+    pub service: &'static str,
+    /// Id field within that table, e.g. `uuid`
+    pub id_key: &'static str,
+    /// `(slug, id)` pairs
+    pub ids: Vec<(String, String)>,
+    /// Global `[targets.<service>] endpoint`, written only if that table is missing
+    pub endpoint: Option<String>,
+}
+
+/// Append `[checks.<slug>.targets.<service>]` blocks to external_monitors.toml content
+///
+/// Text is appended rather than re-serialized so comments and layout are kept. Checks
+/// that already have the service configured are left alone. Returns the new content
+/// and the slugs that were added.
+pub fn append_check_ids(
+    content: &str,
+    additions: &CheckIdAdditions,
+) -> Result<(String, Vec<String>), CliError> {
+    let value: toml::Value = toml::from_str(content)?;
+    let service = additions.service;
+
+    let mut blocks = Vec::new();
+    if let Some(endpoint) = &additions.endpoint {
+        if value.get("targets").and_then(|t| t.get(service)).is_none() {
+            blocks.push(format!(
+                "[targets.{}]\nendpoint = {}\n",
+                service,
+                toml::Value::String(endpoint.clone())
+            ));
+        }
+    }
+
+    let mut added = Vec::new();
+    for (slug, id) in &additions.ids {
+        let configured = value
+            .get("checks")
+            .and_then(|c| c.get(slug))
+            .and_then(|c| c.get("targets"))
+            .and_then(|t| t.get(service))
+            .is_some();
+        if configured || added.contains(slug) {
+            continue;
+        }
+        blocks.push(format!(
+            "[checks.{}.targets.{}]\n{} = {}\n",
+            slug,
+            service,
+            additions.id_key,
+            toml::Value::String(id.clone())
+        ));
+        added.push(slug.clone());
+    }
+
+    if blocks.is_empty() {
+        return Ok((content.to_string(), added));
+    }
+
+    let mut output = content.to_string();
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+    for block in blocks {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(&block);
     }
+
+    // Never write a file we can't load back
+    toml::from_str::<toml::Value>(&output)?;
+    Ok((output, added))
 }
 
 #[cfg(test)]
+#[allow(unsafe_code)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
@@ -577,27 +1396,548 @@ monitor_key = "my-job-monitor"
     }
 
     #[test]
-    fn test_from_cli_args_empty() {
-        let targets = MonitorTarget::from_cli_args(None, None, None, None, None, &[]);
-        assert!(targets.is_empty());
-    }
+    fn test_load_pushgateway_global() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.pushgateway]
+url = "http://pushgateway.internal:9091"
+"#,
+        )
+        .unwrap();
 
-    #[test]
-    fn test_from_cli_args_healthchecks_only() {
-        let targets = MonitorTarget::from_cli_args(Some("test-uuid"), None, None, None, None, &[]);
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        let targets = config.build_monitors_for_check("any-check");
 
         assert_eq!(targets.len(), 1);
         match &targets[0] {
-            MonitorTarget::Healthchecks { endpoint, uuid } => {
-                assert_eq!(endpoint, DEFAULT_HEALTHCHECKS_ENDPOINT);
-                assert_eq!(uuid, "test-uuid");
+            MonitorTarget::Pushgateway { endpoint, job } => {
+                assert_eq!(endpoint, "http://pushgateway.internal:9091");
+                assert_eq!(job, DEFAULT_PUSHGATEWAY_JOB);
             }
-            _ => panic!("Expected Healthchecks target"),
+            _ => panic!("Expected Pushgateway target"),
         }
+        assert_eq!(
+            targets[0].display_url(),
+            "http://pushgateway.internal:9091/metrics/job/pakyas"
+        );
     }
 
     #[test]
-    fn test_from_cli_args_healthchecks_custom_endpoint() {
+    fn test_load_otlp_global() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.otlp]
+endpoint = "http://localhost:4318"
+service_name = "batch-jobs"
+
+[targets.otlp.headers]
+authorization = "Bearer secret"
+"#,
+        )
+        .unwrap();
+
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        let targets = config.build_monitors_for_check("any-check");
+
+        assert_eq!(targets.len(), 1);
+        match &targets[0] {
+            MonitorTarget::Otlp {
+                endpoint,
+                service_name,
+                headers,
+            } => {
+                assert_eq!(endpoint, "http://localhost:4318");
+                assert_eq!(service_name, "batch-jobs");
+                assert_eq!(headers.get("authorization").unwrap(), "Bearer secret");
+            }
+            _ => panic!("Expected Otlp target"),
+        }
+        assert_eq!(targets[0].display_url(), "http://localhost:4318/v1/traces");
+    }
+
+    #[test]
+    fn test_load_exec_global_and_per_check() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.exec]
+command = "logger -t pakyas"
+
+[checks."backup-db".targets.exec]
+command = "systemctl restart backup"
+timeout_ms = 30000
+events = ["fail"]
+"#,
+        )
+        .unwrap();
+
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+
+        let targets = config.build_monitors_for_check("other-check");
+        assert_eq!(targets.len(), 1);
+        match &targets[0] {
+            MonitorTarget::Exec {
+                command,
+                timeout_ms,
+                events,
+            } => {
+                assert_eq!(command, "logger -t pakyas");
+                assert_eq!(*timeout_ms, DEFAULT_EXEC_TIMEOUT_MS);
+                assert!(events.is_empty());
+            }
+            _ => panic!("Expected Exec target"),
+        }
+
+        // Per-check hook replaces the global one
+        let targets = config.build_monitors_for_check("backup-db");
+        assert_eq!(targets.len(), 1);
+        match &targets[0] {
+            MonitorTarget::Exec {
+                command,
+                timeout_ms,
+                events,
+            } => {
+                assert_eq!(command, "systemctl restart backup");
+                assert_eq!(*timeout_ms, 30000);
+                assert_eq!(events, &vec![EventType::Fail]);
+            }
+            _ => panic!("Expected Exec target"),
+        }
+    }
+
+    #[test]
+    fn test_load_syslog_and_journald() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.syslog]
+transport = "tcp"
+address = "siem.internal:6514"
+facility = "local3"
+
+[targets.journald]
+"#,
+        )
+        .unwrap();
+
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        let targets = config.build_monitors_for_check("any-check");
+
+        assert_eq!(targets.len(), 2);
+        match &targets[0] {
+            MonitorTarget::Syslog {
+                transport,
+                address,
+                facility,
+                app_name,
+            } => {
+                assert_eq!(*transport, SyslogTransport::Tcp);
+                assert_eq!(address, "siem.internal:6514");
+                assert_eq!(facility.code(), 19);
+                assert_eq!(app_name, DEFAULT_SYSLOG_APP_NAME);
+            }
+            _ => panic!("Expected Syslog target"),
+        }
+        assert_eq!(targets[0].display_url(), "tcp://siem.internal:6514");
+        match &targets[1] {
+            MonitorTarget::Journald { socket, identifier } => {
+                assert_eq!(socket, DEFAULT_JOURNALD_SOCKET);
+                assert_eq!(identifier, DEFAULT_SYSLOG_APP_NAME);
+            }
+            _ => panic!("Expected Journald target"),
+        }
+    }
+
+    #[test]
+    fn test_load_syslog_invalid_facility() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.syslog]
+transport = "udp"
+address = "127.0.0.1:514"
+facility = "mail"
+"#,
+        )
+        .unwrap();
+
+        let result = ExternalMonitorConfig::load_from_path(&path);
+        assert!(matches!(result, Err(CliError::ConfigParse(_))));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_issues_cronitor_without_api_key() {
+        let original = std::env::var("CRONITOR_API_KEY").ok();
+        // SAFETY: Tests run serially via #[serial] attribute
+        unsafe { std::env::remove_var("CRONITOR_API_KEY") };
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.webhook]
+url = "not a url"
+
+[checks.backup-db.targets.cronitor]
+monitor_key = "abc"
+"#,
+        )
+        .unwrap();
+
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        let issues = config.issues();
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].location, "targets.webhook");
+        assert!(issues[0].message.contains("invalid URL"));
+        assert_eq!(issues[1].location, "checks.backup-db.targets.cronitor");
+        assert!(issues[1].message.contains("no cronitor API key"));
+
+        if let Some(val) = original {
+            // SAFETY: Tests run serially via #[serial] attribute
+            unsafe { std::env::set_var("CRONITOR_API_KEY", val) };
+        }
+    }
+
+    #[test]
+    fn test_unknown_key_issues() {
+        let value: toml::Value = toml::from_str(
+            r#"
+migration_mode = true
+
+[targets.healthcheck]
+endpoint = "https://hc-ping.com"
+
+[checks.backup-db.targets.webhook]
+url = "https://example.com"
+
+[checks.nightly.target.cronitor]
+monitor_key = "abc"
+"#,
+        )
+        .unwrap();
+
+        let mut locations: Vec<String> = unknown_key_issues(&value)
+            .into_iter()
+            .map(|i| i.location)
+            .collect();
+        locations.sort();
+
+        assert_eq!(
+            locations,
+            vec![
+                "checks.backup-db.targets.webhook",
+                "checks.nightly.target",
+                "targets.healthcheck",
+            ]
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_load_interpolates_and_masks_secrets() {
+        let temp_dir = TempDir::new().unwrap();
+        let secret_path = temp_dir.path().join("cronitor_key");
+        std::fs::write(&secret_path, "cr_secret_key\n").unwrap();
+
+        // SAFETY: Tests run serially via #[serial] attribute
+        unsafe { std::env::set_var("PAKYAS_TEST_WEBHOOK_TOKEN", "tok_abcdef") };
+
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            format!(
+                r#"
+[targets.cronitor]
+api_key = "file:{}"
+
+[targets.webhook]
+url = "https://hooks.example.com/${{PAKYAS_TEST_WEBHOOK_TOKEN}}"
+
+[checks.backup-db.targets.cronitor]
+monitor_key = "${{PAKYAS_TEST_UNSET_KEY:-backup}}"
+"#,
+                secret_path.display()
+            ),
+        )
+        .unwrap();
+
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        // SAFETY: Tests run serially via #[serial] attribute
+        unsafe { std::env::remove_var("PAKYAS_TEST_WEBHOOK_TOKEN") };
+
+        let targets = config.build_monitors_for_check("backup-db");
+        match &targets[0] {
+            MonitorTarget::Cronitor {
+                api_key,
+                monitor_key,
+                ..
+            } => {
+                assert_eq!(api_key, "cr_secret_key");
+                assert_eq!(monitor_key, "backup");
+            }
+            _ => panic!("Expected Cronitor target"),
+        }
+        match &targets[1] {
+            MonitorTarget::Webhook { url } => {
+                assert_eq!(url, "https://hooks.example.com/tok_abcdef");
+            }
+            _ => panic!("Expected Webhook target"),
+        }
+        assert_eq!(targets[1].display_url(), "https://hooks.example.com/***");
+        assert_eq!(
+            mask_secrets("request to https://hooks.example.com/tok_abcdef failed"),
+            "request to https://hooks.example.com/*** failed"
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_load_leaves_exec_commands_and_registers_credentials_only() {
+        let temp_dir = TempDir::new().unwrap();
+        // SAFETY: Tests run serially via #[serial] attribute
+        unsafe { std::env::set_var("PAKYAS_TEST_MONITOR_KEY", "nightly-backup") };
+
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.exec]
+command = "notify-send ${PAKYAS_EVENT_TYPE} $PAKYAS_EVENT_CHECK"
+
+[checks.backup-db.targets.cronitor]
+monitor_key = "${PAKYAS_TEST_MONITOR_KEY}"
+"#,
+        )
+        .unwrap();
+
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        // SAFETY: Tests run serially via #[serial] attribute
+        unsafe { std::env::remove_var("PAKYAS_TEST_MONITOR_KEY") };
+
+        let exec = config.file_config.targets.exec.as_ref().unwrap();
+        assert_eq!(
+            exec.command,
+            "notify-send ${PAKYAS_EVENT_TYPE} $PAKYAS_EVENT_CHECK"
+        );
+        // Not a credential field, so not masked
+        assert_eq!(mask_secrets("nightly-backup"), "nightly-backup");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_check_url_masks_secrets() {
+        register_secrets(vec!["tok_check_url".to_string()]);
+        let mut issues = Vec::new();
+        check_url(
+            "targets.webhook",
+            "ftp://hooks.example.com/tok_check_url",
+            &mut issues,
+        );
+        assert_eq!(
+            issues[0].message,
+            "unsupported URL scheme 'ftp' in 'ftp://hooks.example.com/***'"
+        );
+    }
+
+    #[test]
+    fn test_is_credential_location() {
+        assert!(is_credential_location("targets.cronitor.api_key"));
+        assert!(is_credential_location("checks.url.targets.webhook.url"));
+        assert!(is_credential_location("targets.otlp.headers.authorization"));
+        assert!(is_credential_location("rules[0].targets.webhook.url"));
+        assert!(!is_credential_location(
+            "checks.url.targets.cronitor.monitor_key"
+        ));
+        assert!(!is_credential_location("targets.otlp.service_name"));
+    }
+
+    #[test]
+    fn test_load_unresolved_reference_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.cronitor]
+api_key = "${PAKYAS_TEST_DEFINITELY_UNSET}"
+"#,
+        )
+        .unwrap();
+
+        let err = ExternalMonitorConfig::load_from_path(&path).unwrap_err();
+        match &err {
+            CliError::ConfigInterpolation { location, message } => {
+                assert_eq!(location, "targets.cronitor.api_key");
+                assert!(message.contains("PAKYAS_TEST_DEFINITELY_UNSET is not set"));
+            }
+            other => panic!("Expected ConfigInterpolation, got {:?}", other),
+        }
+    }
+
+    fn meta(slug: &str, tags: &[&str]) -> CheckMeta {
+        CheckMeta {
+            key: slug.to_string(),
+            slug: None,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_rules_glob_and_tags_with_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[targets.cronitor]
+api_key = "test-api-key"
+
+[checks.backup-legacy.targets.cronitor]
+monitor_key = "legacy-key"
+
+[[rules]]
+match = "backup-*"
+[rules.targets.cronitor]
+monitor_key = "{slug}"
+
+[[rules]]
+tags = ["db"]
+[rules.targets.healthchecks]
+uuid = "shared-db-uuid"
+[rules.targets.cronitor]
+monitor_key = "db-{slug}"
+"#,
+        )
+        .unwrap();
+
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        assert!(config.has_tag_rules());
+
+        // Glob rule templates the slug
+        let targets = config.build_monitors(&meta("backup-db", &[]));
+        assert_eq!(targets.len(), 1);
+        match &targets[0] {
+            MonitorTarget::Cronitor { monitor_key, .. } => assert_eq!(monitor_key, "backup-db"),
+            _ => panic!("Expected Cronitor target"),
+        }
+
+        // Explicit per-check config wins over rules
+        let targets = config.build_monitors(&meta("backup-legacy", &[]));
+        match &targets[0] {
+            MonitorTarget::Cronitor { monitor_key, .. } => assert_eq!(monitor_key, "legacy-key"),
+            _ => panic!("Expected Cronitor target"),
+        }
+
+        // First matching rule wins per target; later rules fill in the rest
+        let targets = config.build_monitors(&meta("backup-pg", &["db"]));
+        assert_eq!(targets.len(), 2);
+        match (&targets[0], &targets[1]) {
+            (
+                MonitorTarget::Healthchecks { uuid, .. },
+                MonitorTarget::Cronitor { monitor_key, .. },
+            ) => {
+                assert_eq!(uuid, "shared-db-uuid");
+                assert_eq!(monitor_key, "backup-pg");
+            }
+            _ => panic!("Expected Healthchecks + Cronitor targets"),
+        }
+
+        // Tag rule only
+        let targets = config.build_monitors(&meta("orders-pg", &["db", "eu"]));
+        assert_eq!(targets.len(), 2);
+        match &targets[1] {
+            MonitorTarget::Cronitor { monitor_key, .. } => assert_eq!(monitor_key, "db-orders-pg"),
+            _ => panic!("Expected Cronitor target"),
+        }
+
+        // No match
+        assert!(
+            config
+                .build_monitors(&meta("web-health", &["web"]))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_rule_exec_command_not_templated() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(
+            &path,
+            r#"
+[[rules]]
+match = "*"
+[rules.targets.exec]
+command = "notify {slug} \"$PAKYAS_EVENT_CHECK\""
+"#,
+        )
+        .unwrap();
+
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        let targets = config.build_monitors(&meta("x;touch pwned'", &[]));
+        match &targets[0] {
+            MonitorTarget::Exec { command, .. } => {
+                assert_eq!(command, "notify {slug} \"$PAKYAS_EVENT_CHECK\"")
+            }
+            _ => panic!("Expected Exec target"),
+        }
+    }
+
+    #[test]
+    fn test_rule_slug_from_public_id_lookup() {
+        let rule = Rule {
+            pattern: Some("etl-*".to_string()),
+            ..Default::default()
+        };
+        let by_id = CheckMeta {
+            key: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+            slug: Some("etl-nightly".to_string()),
+            tags: vec![],
+        };
+        assert!(rule.matches(&by_id));
+        assert_eq!(apply_template("{slug}-x", &by_id), "etl-nightly-x");
+
+        // A rule with no conditions never applies
+        assert!(!Rule::default().matches(&meta("anything", &[])));
+    }
+
+    #[test]
+    fn test_from_cli_args_empty() {
+        let targets = MonitorTarget::from_cli_args(None, None, None, None, None, &[]);
+        assert!(targets.is_empty());
+    }
+
+    #[test]
+    fn test_from_cli_args_healthchecks_only() {
+        let targets = MonitorTarget::from_cli_args(Some("test-uuid"), None, None, None, None, &[]);
+
+        assert_eq!(targets.len(), 1);
+        match &targets[0] {
+            MonitorTarget::Healthchecks { endpoint, uuid } => {
+                assert_eq!(endpoint, DEFAULT_HEALTHCHECKS_ENDPOINT);
+                assert_eq!(uuid, "test-uuid");
+            }
+            _ => panic!("Expected Healthchecks target"),
+        }
+    }
+
+    #[test]
+    fn test_from_cli_args_healthchecks_custom_endpoint() {
         let targets = MonitorTarget::from_cli_args(
             Some("test-uuid"),
             Some("https://hc.internal.example.com"),
@@ -713,4 +2053,56 @@ monitor_key = "my-job-monitor"
         assert!(names.contains(&"cronitor"));
         assert!(names.contains(&"webhook"));
     }
+
+    #[test]
+    fn test_append_check_ids_keeps_existing_content() {
+        let content = "# migration config\nmigration_mode = true\n\n[checks.backup-db.targets.healthchecks]\nuuid = \"old\"\n";
+        let additions = CheckIdAdditions {
+            service: "healthchecks",
+            id_key: "uuid",
+            ids: vec![
+                ("backup-db".to_string(), "new".to_string()),
+                ("nightly-report".to_string(), "abc-123".to_string()),
+            ],
+            endpoint: Some("https://hc.example.com".to_string()),
+        };
+
+        let (output, added) = append_check_ids(content, &additions).unwrap();
+
+        assert_eq!(added, vec!["nightly-report".to_string()]);
+        assert!(output.starts_with(content));
+        assert!(output.contains("[targets.healthchecks]\nendpoint = \"https://hc.example.com\""));
+        assert!(
+            output.contains("[checks.nightly-report.targets.healthchecks]\nuuid = \"abc-123\"")
+        );
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("external_monitors.toml");
+        std::fs::write(&path, &output).unwrap();
+        let config = ExternalMonitorConfig::load_from_path(&path).unwrap();
+        match &config.build_monitors_for_check("nightly-report")[0] {
+            MonitorTarget::Healthchecks { endpoint, uuid } => {
+                assert_eq!(endpoint, "https://hc.example.com");
+                assert_eq!(uuid, "abc-123");
+            }
+            _ => panic!("Expected Healthchecks target"),
+        }
+    }
+
+    #[test]
+    fn test_append_check_ids_skips_existing_global_table() {
+        let content = "[targets.cronitor]\napi_key = \"key\"";
+        let additions = CheckIdAdditions {
+            service: "cronitor",
+            id_key: "monitor_key",
+            ids: vec![("etl".to_string(), "etl-job".to_string())],
+            endpoint: Some("https://cronitor.link".to_string()),
+        };
+
+        let (output, added) = append_check_ids(content, &additions).unwrap();
+
+        assert_eq!(added, vec!["etl".to_string()]);
+        assert_eq!(output.matches("[targets.cronitor]").count(), 1);
+        assert!(output.ends_with("[checks.etl.targets.cronitor]\nmonitor_key = \"etl-job\"\n"));
+    }
 }
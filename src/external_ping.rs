@@ -1,14 +1,17 @@
 //! External ping dispatcher for sending events to external monitoring services.
 //!
-//! This module handles sending ping events to healthchecks.io, cronitor, and custom webhooks.
+//! This module handles sending ping events to healthchecks.io, cronitor, custom webhooks,
+//! Prometheus Pushgateway, OpenTelemetry collectors, local exec hooks, and syslog/journald.
 //! It supports fire-and-forget dispatch and awaiting any success for migration mode.
 
-use crate::external_monitors::MonitorTarget;
+use crate::external_monitors::{MonitorTarget, mask_secrets};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use reqwest::Client;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::process::Stdio;
 use std::time::Duration;
+use tokio::io::AsyncWriteExt;
 use tokio::sync::mpsc;
 
 /// Maximum output size in bytes (4KB)
@@ -17,8 +20,36 @@ const OUTPUT_MAX_BYTES: usize = 4 * 1024;
 /// Default timeout for external requests in milliseconds
 const DEFAULT_TIMEOUT_MS: u64 = 5000;
 
+/// First retry delay; doubles per attempt up to RETRY_MAX_BACKOFF_MS
+const RETRY_INITIAL_BACKOFF_MS: u64 = 250;
+
+/// Cap on the delay between retries
+const RETRY_MAX_BACKOFF_MS: u64 = 2000;
+
+/// Maximum delivery attempts per target per event
+const MAX_ATTEMPTS: u32 = 4;
+
+/// Extra time callers wait past a dispatch deadline, so targets finishing right at
+/// the deadline still report their outcome
+const WAIT_SLACK_MS: u64 = 1000;
+
+/// Non-success HTTP status from an external service
+#[derive(Debug)]
+struct StatusError {
+    service: &'static str,
+    status: reqwest::StatusCode,
+}
+
+impl std::fmt::Display for StatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} returned status {}", self.service, self.status)
+    }
+}
+
+impl std::error::Error for StatusError {}
+
 /// Event type for ping events
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum EventType {
     Start,
@@ -30,7 +61,7 @@ pub enum EventType {
 ///
 /// The `check_identifier` field contains either a check slug or public_id,
 /// depending on how the CLI was invoked.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PingEvent {
     pub check_identifier: String,
     pub event_type: EventType,
@@ -43,6 +74,8 @@ pub struct PingEvent {
     pub host: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signal: Option<i32>,
 }
 
 impl PingEvent {
@@ -56,6 +89,7 @@ impl PingEvent {
             timestamp: Utc::now(),
             host: hostname(),
             output: None,
+            signal: None,
         }
     }
 
@@ -69,6 +103,7 @@ impl PingEvent {
             timestamp: Utc::now(),
             host: hostname(),
             output: None,
+            signal: None,
         }
     }
 
@@ -82,6 +117,7 @@ impl PingEvent {
             timestamp: Utc::now(),
             host: hostname(),
             output: build_output(stderr),
+            signal: None,
         }
     }
 
@@ -98,6 +134,12 @@ impl PingEvent {
             Self::fail(check_identifier, exit_code, duration_ms, stderr)
         }
     }
+
+    /// Attach the signal that terminated the wrapped command (if any)
+    pub fn with_signal(mut self, signal: Option<i32>) -> Self {
+        self.signal = signal;
+        self
+    }
 }
 
 /// Get hostname for event payload
@@ -132,6 +174,31 @@ async fn send_to_target(client: &Client, target: &MonitorTarget, event: &PingEve
             monitor_key,
         } => send_cronitor(client, endpoint, api_key, monitor_key, event).await,
         MonitorTarget::Webhook { url } => send_webhook(client, url, event).await,
+        MonitorTarget::Pushgateway { endpoint, job } => {
+            send_pushgateway(client, endpoint, job, event).await
+        }
+        MonitorTarget::Otlp {
+            endpoint,
+            service_name,
+            headers,
+        } => send_otlp(client, endpoint, service_name, headers, event).await,
+        MonitorTarget::Exec {
+            command,
+            timeout_ms,
+            events,
+        } => send_exec(command, *timeout_ms, events, event).await,
+        MonitorTarget::Syslog {
+            transport,
+            address,
+            facility,
+            app_name,
+        } => {
+            crate::external_syslog::send_syslog(*transport, address, *facility, app_name, event)
+                .await
+        }
+        MonitorTarget::Journald { socket, identifier } => {
+            crate::external_syslog::send_journald(socket, identifier, event).await
+        }
     }
 }
 
@@ -170,7 +237,11 @@ async fn send_healthchecks(
     if response.status().is_success() {
         Ok(())
     } else {
-        anyhow::bail!("healthchecks.io returned status {}", response.status())
+        Err(StatusError {
+            service: "healthchecks.io",
+            status: response.status(),
+        }
+        .into())
     }
 }
 
@@ -219,7 +290,11 @@ async fn send_cronitor(
     if response.status().is_success() {
         Ok(())
     } else {
-        anyhow::bail!("cronitor returned status {}", response.status())
+        Err(StatusError {
+            service: "cronitor",
+            status: response.status(),
+        }
+        .into())
     }
 }
 
@@ -235,22 +310,494 @@ async fn send_webhook(client: &Client, url: &str, event: &PingEvent) -> Result<(
     if response.status().is_success() {
         Ok(())
     } else {
-        anyhow::bail!("webhook returned status {}", response.status())
+        Err(StatusError {
+            service: "webhook",
+            status: response.status(),
+        }
+        .into())
+    }
+}
+
+/// Send job metrics to a Prometheus Pushgateway
+///
+/// URL pattern: {endpoint}/metrics/job/{job}/check/{check_identifier}/host/{host}
+///
+/// Start events carry no metrics and are skipped. Completion events are pushed with
+/// POST so that `pakyas_job_last_success_timestamp` from an earlier successful run
+/// survives a failed run in the same group.
+async fn send_pushgateway(
+    client: &Client,
+    endpoint: &str,
+    job: &str,
+    event: &PingEvent,
+) -> Result<()> {
+    let Some(body) = build_pushgateway_metrics(event) else {
+        return Ok(());
+    };
+
+    let response = client
+        .post(pushgateway_url(endpoint, job, event))
+        .header("Content-Type", "text/plain; version=0.0.4")
+        .body(body)
+        .send()
+        .await?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(StatusError {
+            service: "pushgateway",
+            status: response.status(),
+        }
+        .into())
+    }
+}
+
+/// Build the Pushgateway grouping URL for an event
+fn pushgateway_url(endpoint: &str, job: &str, event: &PingEvent) -> String {
+    let host = event.host.as_deref().unwrap_or("unknown");
+    format!(
+        "{}/metrics/job/{}/check/{}/host/{}",
+        endpoint.trim_end_matches('/'),
+        urlencoding::encode(job),
+        urlencoding::encode(&event.check_identifier),
+        urlencoding::encode(host)
+    )
+}
+
+/// Build Prometheus text exposition metrics for a completion event
+///
+/// Returns None for start events (nothing to report until the run finishes).
+fn build_pushgateway_metrics(event: &PingEvent) -> Option<String> {
+    if event.event_type == EventType::Start {
+        return None;
+    }
+
+    let timestamp = event.timestamp.timestamp_millis() as f64 / 1000.0;
+    let mut body = String::new();
+
+    let mut push_gauge = |name: &str, help: &str, value: String| {
+        body.push_str(&format!(
+            "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"
+        ));
+    };
+
+    push_gauge(
+        "pakyas_job_last_run_timestamp",
+        "Unix time the job last finished.",
+        timestamp.to_string(),
+    );
+    if let Some(duration_ms) = event.duration_ms {
+        push_gauge(
+            "pakyas_job_duration_seconds",
+            "Duration of the last job run in seconds.",
+            (duration_ms as f64 / 1000.0).to_string(),
+        );
+    }
+    if let Some(exit_code) = event.exit_code {
+        push_gauge(
+            "pakyas_job_exit_code",
+            "Exit code of the last job run.",
+            exit_code.to_string(),
+        );
+    }
+    if event.event_type == EventType::Success {
+        push_gauge(
+            "pakyas_job_last_success_timestamp",
+            "Unix time the job last finished successfully.",
+            timestamp.to_string(),
+        );
+    }
+
+    Some(body)
+}
+
+/// Trace context parsed from a W3C `traceparent` header value
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TraceParent {
+    trace_id: String,
+    parent_span_id: String,
+}
+
+/// Parse a W3C traceparent value (`00-<trace-id>-<parent-id>-<flags>`)
+///
+/// Returns None for malformed values or the all-zero ids the spec marks invalid.
+fn parse_traceparent(value: &str) -> Option<TraceParent> {
+    let parts: Vec<&str> = value.trim().split('-').collect();
+    let [version, trace_id, parent_id, flags] = parts.as_slice() else {
+        return None;
+    };
+
+    let is_hex = |s: &str, len: usize| {
+        s.len() == len
+            && s.chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+    };
+
+    if !is_hex(version, 2) || *version == "ff" || !is_hex(flags, 2) {
+        return None;
+    }
+    if !is_hex(trace_id, 32) || trace_id.chars().all(|c| c == '0') {
+        return None;
+    }
+    if !is_hex(parent_id, 16) || parent_id.chars().all(|c| c == '0') {
+        return None;
+    }
+
+    Some(TraceParent {
+        trace_id: trace_id.to_string(),
+        parent_span_id: parent_id.to_string(),
+    })
+}
+
+/// Generate a random hex id of `bytes` length (max 16)
+fn random_hex_id(bytes: usize) -> String {
+    uuid::Uuid::new_v4().as_bytes()[..bytes]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Build an OTLP/HTTP JSON trace export containing one span for a completed run
+///
+/// Returns None for start events (the span is emitted once the run finishes).
+fn build_otlp_span(
+    event: &PingEvent,
+    service_name: &str,
+    parent: Option<&TraceParent>,
+) -> Option<serde_json::Value> {
+    use serde_json::json;
+
+    if event.event_type == EventType::Start {
+        return None;
+    }
+
+    let end_nanos = event.timestamp.timestamp_nanos_opt().unwrap_or_default();
+    let duration_nanos = event.duration_ms.unwrap_or(0) as i64 * 1_000_000;
+    let start_nanos = end_nanos - duration_nanos;
+
+    let trace_id = parent
+        .map(|p| p.trace_id.clone())
+        .unwrap_or_else(|| random_hex_id(16));
+
+    let mut attributes = vec![
+        json!({"key": "pakyas.check", "value": {"stringValue": event.check_identifier}}),
+        json!({"key": "pakyas.outcome", "value": {"stringValue": event.event_type}}),
+    ];
+    if let Some(exit_code) = event.exit_code {
+        attributes.push(
+            json!({"key": "process.exit_code", "value": {"intValue": exit_code.to_string()}}),
+        );
+    }
+    if let Some(signal) = event.signal {
+        attributes
+            .push(json!({"key": "process.signal", "value": {"intValue": signal.to_string()}}));
+    }
+    if let Some(host) = &event.host {
+        attributes.push(json!({"key": "host.name", "value": {"stringValue": host}}));
+    }
+
+    // STATUS_CODE_OK = 1, STATUS_CODE_ERROR = 2
+    let status = if event.event_type == EventType::Success {
+        json!({"code": 1})
+    } else {
+        json!({"code": 2, "message": format!("exit code {}", event.exit_code.unwrap_or(1))})
+    };
+
+    let mut span = json!({
+        "traceId": trace_id,
+        "spanId": random_hex_id(8),
+        "name": format!("pakyas monitor {}", event.check_identifier),
+        "kind": 1,
+        "startTimeUnixNano": start_nanos.to_string(),
+        "endTimeUnixNano": end_nanos.to_string(),
+        "attributes": attributes,
+        "status": status,
+    });
+    if let Some(parent) = parent {
+        span["parentSpanId"] = json!(parent.parent_span_id);
+    }
+
+    Some(json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [
+                    {"key": "service.name", "value": {"stringValue": service_name}},
+                ]
+            },
+            "scopeSpans": [{
+                "scope": {"name": "pakyas-cli", "version": env!("CARGO_PKG_VERSION")},
+                "spans": [span],
+            }]
+        }]
+    }))
+}
+
+/// Export a span for a completed run to an OTLP/HTTP collector
+///
+/// URL pattern: {endpoint}/v1/traces
+///
+/// Honours `TRACEPARENT` from the environment so that runs triggered by a traced
+/// pipeline join the caller's trace.
+async fn send_otlp(
+    client: &Client,
+    endpoint: &str,
+    service_name: &str,
+    headers: &std::collections::HashMap<String, String>,
+    event: &PingEvent,
+) -> Result<()> {
+    let parent = std::env::var("TRACEPARENT")
+        .ok()
+        .and_then(|v| parse_traceparent(&v));
+
+    let Some(payload) = build_otlp_span(event, service_name, parent.as_ref()) else {
+        return Ok(());
+    };
+
+    let url = format!("{}/v1/traces", endpoint.trim_end_matches('/'));
+    let mut request = client.post(&url).json(&payload);
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+
+    let response = request.send().await?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(StatusError {
+            service: "otlp collector",
+            status: response.status(),
+        }
+        .into())
+    }
+}
+
+/// Build the PAKYAS_EVENT_* environment variables for an exec hook
+fn exec_event_env(event: &PingEvent) -> Vec<(&'static str, String)> {
+    let event_type = match event.event_type {
+        EventType::Start => "start",
+        EventType::Success => "success",
+        EventType::Fail => "fail",
+    };
+
+    let mut env = vec![
+        ("PAKYAS_EVENT_CHECK", event.check_identifier.clone()),
+        ("PAKYAS_EVENT_TYPE", event_type.to_string()),
+        ("PAKYAS_EVENT_TIMESTAMP", event.timestamp.to_rfc3339()),
+    ];
+    if let Some(exit_code) = event.exit_code {
+        env.push(("PAKYAS_EVENT_EXIT_CODE", exit_code.to_string()));
+    }
+    if let Some(duration) = event.duration_ms {
+        env.push(("PAKYAS_EVENT_DURATION_MS", duration.to_string()));
+    }
+    if let Some(signal) = event.signal {
+        env.push(("PAKYAS_EVENT_SIGNAL", signal.to_string()));
+    }
+    if let Some(host) = &event.host {
+        env.push(("PAKYAS_EVENT_HOST", host.clone()));
+    }
+    env
+}
+
+/// Build a platform shell invocation for a hook command
+pub(crate) fn shell_command(command: &str) -> tokio::process::Command {
+    #[cfg(unix)]
+    {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    }
+
+    #[cfg(not(unix))]
+    {
+        let mut cmd = tokio::process::Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    }
+}
+
+/// Run a local exec hook with the event as JSON on stdin and PAKYAS_EVENT_* env vars
+///
+/// Events not listed in `events` are skipped (an empty list matches everything).
+/// The hook is killed if it runs longer than `timeout_ms`.
+async fn send_exec(
+    command: &str,
+    timeout_ms: u64,
+    events: &[EventType],
+    event: &PingEvent,
+) -> Result<()> {
+    if !events.is_empty() && !events.contains(&event.event_type) {
+        return Ok(());
+    }
+
+    let payload = serde_json::to_vec(event)?;
+
+    let mut child = shell_command(command)
+        .envs(exec_event_env(event))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores stdin may exit before reading it; that's not a failure
+        let _ = stdin.write_all(&payload).await;
+    }
+
+    let output = tokio::time::timeout(Duration::from_millis(timeout_ms), child.wait_with_output())
+        .await
+        .map_err(|_| anyhow::anyhow!("exec hook timed out after {}ms", timeout_ms))??;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let detail = stderr.trim().lines().last().unwrap_or_default();
+        if detail.is_empty() {
+            anyhow::bail!("exec hook exited with {}", output.status)
+        } else {
+            anyhow::bail!("exec hook exited with {}: {}", output.status, detail)
+        }
+    }
+}
+
+/// Compute how long callers should wait for a dispatch to finish
+///
+/// HTTP targets share `timeout_ms`, but exec hooks carry their own timeout,
+/// which may be longer. Includes some slack past the latest deadline.
+pub fn wait_budget_ms(monitors: &[MonitorTarget], timeout_ms: u64) -> u64 {
+    monitors
+        .iter()
+        .filter_map(|m| match m {
+            MonitorTarget::Exec { timeout_ms, .. } => Some(*timeout_ms),
+            _ => None,
+        })
+        .fold(timeout_ms, u64::max)
+        + WAIT_SLACK_MS
+}
+
+/// Result of delivering one event to one external target
+#[derive(Debug, Clone, Serialize)]
+pub struct TargetOutcome {
+    pub target: &'static str,
+    pub url: String,
+    pub success: bool,
+    pub timed_out: bool,
+    pub attempts: u32,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl TargetOutcome {
+    /// Build the outcome of a delivery (after retries)
+    fn from_result(
+        target: &MonitorTarget,
+        result: &Result<()>,
+        attempts: u32,
+        latency_ms: u64,
+    ) -> Self {
+        Self {
+            target: target.name(),
+            url: target.display_url(),
+            success: result.is_ok(),
+            timed_out: result.as_ref().err().is_some_and(is_timeout),
+            attempts,
+            latency_ms,
+            error: result.as_ref().err().map(|e| mask_secrets(&e.to_string())),
+        }
     }
 }
 
+/// Whether a failed delivery is worth retrying
+///
+/// Exec hooks are never retried (they are local commands with side effects and their
+/// own timeout). HTTP 4xx responses other than 408/429 won't change on retry.
+fn is_retryable(target: &MonitorTarget, err: &anyhow::Error) -> bool {
+    if matches!(target, MonitorTarget::Exec { .. }) {
+        return false;
+    }
+    if let Some(status) = err.downcast_ref::<StatusError>().map(|e| e.status) {
+        return status.is_server_error()
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || status == reqwest::StatusCode::REQUEST_TIMEOUT;
+    }
+    // Connection failures, timeouts, and socket errors
+    true
+}
+
+/// Delay before retry number `attempt` (1-based)
+fn backoff_delay(attempt: u32) -> Duration {
+    let ms = RETRY_INITIAL_BACKOFF_MS.saturating_mul(1 << (attempt - 1).min(16));
+    Duration::from_millis(ms.min(RETRY_MAX_BACKOFF_MS))
+}
+
+/// Send to a target, retrying transient failures with backoff until `deadline`
+///
+/// Returns the final result and the number of attempts made.
+async fn send_with_retry(
+    client: &Client,
+    target: &MonitorTarget,
+    event: &PingEvent,
+    deadline: tokio::time::Instant,
+) -> (Result<()>, u32) {
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+
+        // Exec hooks enforce their own timeout, which may exceed the HTTP budget
+        let result = if matches!(target, MonitorTarget::Exec { .. }) {
+            send_to_target(client, target, event).await
+        } else {
+            match tokio::time::timeout_at(deadline, send_to_target(client, target, event)).await {
+                Ok(result) => result,
+                Err(_) => Err(anyhow::anyhow!("{} timed out", target.name())),
+            }
+        };
+
+        let err = match result {
+            Ok(()) => return (Ok(()), attempts),
+            Err(e) => e,
+        };
+
+        let delay = backoff_delay(attempts);
+        let out_of_time = tokio::time::Instant::now() + delay >= deadline;
+        if attempts >= MAX_ATTEMPTS || out_of_time || !is_retryable(target, &err) {
+            return (Err(err), attempts);
+        }
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Whether a send error was a timeout (HTTP, socket, or exec hook)
+fn is_timeout(err: &anyhow::Error) -> bool {
+    err.chain().any(|e| {
+        e.downcast_ref::<reqwest::Error>()
+            .is_some_and(|r| r.is_timeout())
+    }) || err.to_string().contains("timed out")
+}
+
+/// Handle for an in-flight dispatch, resolving to one outcome per target
+pub type DispatchHandle = tokio::task::JoinHandle<Vec<TargetOutcome>>;
+
 /// Dispatch external pings - returns a JoinHandle that can be awaited
 ///
-/// Returns None if monitors is empty.
-/// The returned handle completes when all pings have finished (success or failure).
+/// Returns None if no monitor accepts the event; targets that skip it (exec hooks
+/// limited to other events) produce no outcome.
+/// The returned handle completes when all pings have finished (success or failure)
+/// and yields the per-target outcomes.
 /// Individual failures are logged as warnings.
 /// If verbose is true, logs details about each ping.
 pub fn dispatch_external_pings(
-    monitors: Vec<MonitorTarget>,
+    mut monitors: Vec<MonitorTarget>,
     event: PingEvent,
     timeout_ms: u64,
     verbose: bool,
-) -> Option<tokio::task::JoinHandle<()>> {
+) -> Option<DispatchHandle> {
+    monitors.retain(|t| t.accepts(event.event_type));
     if monitors.is_empty() {
         if verbose {
             eprintln!(
@@ -274,6 +821,8 @@ pub fn dispatch_external_pings(
     }
 
     let timeout = Duration::from_millis(timeout_ms);
+    // Retries for every target share the overall timeout budget
+    let deadline = tokio::time::Instant::now() + timeout;
 
     Some(tokio::spawn(async move {
         let client = match Client::builder().timeout(timeout).build() {
@@ -283,7 +832,7 @@ pub fn dispatch_external_pings(
                     "Warning: failed to create HTTP client for external monitors: {}",
                     e
                 );
-                return;
+                return Vec::new();
             }
         };
 
@@ -293,31 +842,47 @@ pub fn dispatch_external_pings(
             .map(|target| {
                 let client = client.clone();
                 let event = event.clone();
-                let target_name = target.name();
-                let target_url = target.display_url();
 
                 tokio::spawn(async move {
-                    match send_to_target(&client, &target, &event).await {
-                        Ok(_) => {
+                    let started = std::time::Instant::now();
+                    let (result, attempts) =
+                        send_with_retry(&client, &target, &event, deadline).await;
+                    let outcome = TargetOutcome::from_result(
+                        &target,
+                        &result,
+                        attempts,
+                        started.elapsed().as_millis() as u64,
+                    );
+
+                    match &outcome.error {
+                        None => {
                             if verbose {
                                 eprintln!(
                                     "[verbose] {} ping succeeded: {}",
-                                    target_name, target_url
+                                    outcome.target, outcome.url
                                 );
                             }
                         }
-                        Err(e) => {
-                            eprintln!("Warning: external ping to {} failed: {}", target_name, e);
+                        Some(message) => {
+                            eprintln!(
+                                "Warning: external ping to {} failed: {}",
+                                outcome.target, message
+                            );
                         }
                     }
+                    outcome
                 })
             })
             .collect();
 
         // Await all pings
+        let mut outcomes = Vec::with_capacity(handles.len());
         for handle in handles {
-            let _ = handle.await;
+            if let Ok(outcome) = handle.await {
+                outcomes.push(outcome);
+            }
         }
+        outcomes
     }))
 }
 
@@ -332,25 +897,44 @@ pub fn dispatch_fire_and_forget(monitors: Vec<MonitorTarget>, event: PingEvent,
 
 /// Await any success within timeout (for migration mode)
 ///
-/// Returns true if at least one external monitor succeeded.
+/// Returns true if at least one external monitor confirmed delivery. Exec hooks and
+/// syslog/journald still run but never count as a success
+/// (see [`MonitorTarget::confirms_delivery`]).
 /// Returns false immediately if monitors is empty.
 pub async fn dispatch_await_any_success(
     monitors: Vec<MonitorTarget>,
     event: PingEvent,
     timeout_ms: u64,
 ) -> bool {
+    dispatch_await_any_success_with_outcomes(monitors, event, timeout_ms)
+        .await
+        .0
+}
+
+/// Like [`dispatch_await_any_success`], also returning the outcomes seen before it returned
+///
+/// Returns as soon as one target succeeds, so targets still in flight at that point
+/// are not included.
+pub async fn dispatch_await_any_success_with_outcomes(
+    mut monitors: Vec<MonitorTarget>,
+    event: PingEvent,
+    timeout_ms: u64,
+) -> (bool, Vec<TargetOutcome>) {
+    monitors.retain(|t| t.accepts(event.event_type));
     if monitors.is_empty() {
-        return false;
+        return (false, Vec::new());
     }
 
     let timeout = Duration::from_millis(timeout_ms);
     let client = match Client::builder().timeout(timeout).build() {
         Ok(c) => c,
-        Err(_) => return false,
+        Err(_) => return (false, Vec::new()),
     };
 
+    let deadline = tokio::time::Instant::now() + timeout;
+
     // Use a channel to receive results
-    let (tx, mut rx) = mpsc::channel::<bool>(monitors.len());
+    let (tx, mut rx) = mpsc::channel::<(bool, TargetOutcome)>(monitors.len());
 
     for target in monitors {
         let client = client.clone();
@@ -358,28 +942,41 @@ pub async fn dispatch_await_any_success(
         let tx = tx.clone();
 
         tokio::spawn(async move {
-            let success = send_to_target(&client, &target, &event).await.is_ok();
-            let _ = tx.send(success).await;
+            let started = std::time::Instant::now();
+            let (result, attempts) = send_with_retry(&client, &target, &event, deadline).await;
+            let outcome = TargetOutcome::from_result(
+                &target,
+                &result,
+                attempts,
+                started.elapsed().as_millis() as u64,
+            );
+            let _ = tx.send((target.confirms_delivery(), outcome)).await;
         });
     }
 
     // Drop our sender so the channel closes when all tasks complete
     drop(tx);
 
-    // Race: return true as soon as any succeeds, or false if all fail/timeout
-    let deadline = tokio::time::Instant::now() + timeout;
+    // Race: return true as soon as a delivery is confirmed, or false if all fail/timeout
+    let mut outcomes = Vec::new();
 
     loop {
         tokio::select! {
             result = rx.recv() => {
                 match result {
-                    Some(true) => return true,  // At least one succeeded
-                    Some(false) => continue,     // This one failed, keep waiting
-                    None => return false,        // Channel closed, all failed
+                    Some((confirms, outcome)) => {
+                        let success = confirms && outcome.success;
+                        outcomes.push(outcome);
+                        if success {
+                            return (true, outcomes);  // At least one succeeded
+                        }
+                        // This one failed (or can't confirm delivery), keep waiting
+                    }
+                    None => return (false, outcomes),  // Channel closed, all failed
                 }
             }
             _ = tokio::time::sleep_until(deadline) => {
-                return false;  // Timeout
+                return (false, outcomes);  // Timeout
             }
         }
     }
@@ -390,7 +987,7 @@ pub fn dispatch_external_pings_default(
     monitors: Vec<MonitorTarget>,
     event: PingEvent,
     verbose: bool,
-) -> Option<tokio::task::JoinHandle<()>> {
+) -> Option<DispatchHandle> {
     dispatch_external_pings(monitors, event, DEFAULT_TIMEOUT_MS, verbose)
 }
 
@@ -467,6 +1064,244 @@ mod tests {
         assert!(output.len() <= OUTPUT_MAX_BYTES + 20); // truncated prefix + some buffer
     }
 
+    #[test]
+    fn test_pushgateway_metrics_skip_start() {
+        assert!(build_pushgateway_metrics(&PingEvent::start("my-check")).is_none());
+    }
+
+    #[test]
+    fn test_pushgateway_metrics_success() {
+        let event = PingEvent::success("my-check", 1500);
+        let body = build_pushgateway_metrics(&event).unwrap();
+
+        assert!(body.contains("pakyas_job_duration_seconds 1.5\n"));
+        assert!(body.contains("pakyas_job_exit_code 0\n"));
+        assert!(body.contains("# TYPE pakyas_job_last_success_timestamp gauge\n"));
+        assert!(body.contains("# TYPE pakyas_job_last_run_timestamp gauge\n"));
+    }
+
+    #[test]
+    fn test_pushgateway_metrics_fail_keeps_last_success() {
+        let event = PingEvent::fail("my-check", 2, 250, "boom");
+        let body = build_pushgateway_metrics(&event).unwrap();
+
+        assert!(body.contains("pakyas_job_exit_code 2\n"));
+        assert!(body.contains("pakyas_job_duration_seconds 0.25\n"));
+        assert!(!body.contains("pakyas_job_last_success_timestamp"));
+    }
+
+    #[test]
+    fn test_pushgateway_url_grouping() {
+        let mut event = PingEvent::success("backup-db", 10);
+        event.host = Some("web 1".to_string());
+
+        assert_eq!(
+            pushgateway_url("http://pgw:9091/", "pakyas", &event),
+            "http://pgw:9091/metrics/job/pakyas/check/backup-db/host/web%201"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_pushgateway_posts_metrics() {
+        use wiremock::matchers::{method, path_regex};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path_regex(r"^/metrics/job/pakyas/check/my-check/host/.+$"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let target = MonitorTarget::Pushgateway {
+            endpoint: mock_server.uri(),
+            job: "pakyas".to_string(),
+        };
+        let client = Client::new();
+
+        send_to_target(&client, &target, &PingEvent::start("my-check"))
+            .await
+            .unwrap();
+        send_to_target(&client, &target, &PingEvent::success("my-check", 10))
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_parse_traceparent_valid() {
+        let parent =
+            parse_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+
+        assert_eq!(parent.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(parent.parent_span_id, "00f067aa0ba902b7");
+    }
+
+    #[test]
+    fn test_parse_traceparent_invalid() {
+        assert!(parse_traceparent("").is_none());
+        assert!(parse_traceparent("garbage").is_none());
+        assert!(
+            parse_traceparent("00-00000000000000000000000000000000-00f067aa0ba902b7-01").is_none()
+        );
+        assert!(
+            parse_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01").is_none()
+        );
+        assert!(
+            parse_traceparent("00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01").is_none()
+        );
+    }
+
+    #[test]
+    fn test_otlp_span_skips_start() {
+        assert!(build_otlp_span(&PingEvent::start("my-check"), "pakyas", None).is_none());
+    }
+
+    #[test]
+    fn test_otlp_span_fail_with_parent() {
+        let parent = TraceParent {
+            trace_id: "4bf92f3577b34da6a3ce929d0e0e4736".to_string(),
+            parent_span_id: "00f067aa0ba902b7".to_string(),
+        };
+        let event = PingEvent::fail("my-check", 137, 2000, "killed").with_signal(Some(9));
+        let payload = build_otlp_span(&event, "batch", Some(&parent)).unwrap();
+
+        let span = &payload["resourceSpans"][0]["scopeSpans"][0]["spans"][0];
+        assert_eq!(span["traceId"], "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(span["parentSpanId"], "00f067aa0ba902b7");
+        assert_eq!(span["status"]["code"], 2);
+
+        let start: i64 = span["startTimeUnixNano"].as_str().unwrap().parse().unwrap();
+        let end: i64 = span["endTimeUnixNano"].as_str().unwrap().parse().unwrap();
+        assert_eq!(end - start, 2_000_000_000);
+
+        let attrs = span["attributes"].as_array().unwrap();
+        assert!(
+            attrs
+                .iter()
+                .any(|a| a["key"] == "process.signal" && a["value"]["intValue"] == "9")
+        );
+        assert!(
+            attrs
+                .iter()
+                .any(|a| a["key"] == "process.exit_code" && a["value"]["intValue"] == "137")
+        );
+        assert_eq!(
+            payload["resourceSpans"][0]["resource"]["attributes"][0]["value"]["stringValue"],
+            "batch"
+        );
+    }
+
+    #[test]
+    fn test_otlp_span_success_new_trace() {
+        let payload = build_otlp_span(&PingEvent::success("my-check", 5), "pakyas", None).unwrap();
+        let span = &payload["resourceSpans"][0]["scopeSpans"][0]["spans"][0];
+
+        assert_eq!(span["traceId"].as_str().unwrap().len(), 32);
+        assert_eq!(span["spanId"].as_str().unwrap().len(), 16);
+        assert!(span.get("parentSpanId").is_none());
+        assert_eq!(span["status"]["code"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_otlp_posts_traces() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/traces"))
+            .and(header("x-collector-token", "abc"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let target = MonitorTarget::Otlp {
+            endpoint: mock_server.uri(),
+            service_name: "pakyas".to_string(),
+            headers: [("x-collector-token".to_string(), "abc".to_string())].into(),
+        };
+        let client = Client::new();
+
+        send_to_target(&client, &target, &PingEvent::start("my-check"))
+            .await
+            .unwrap();
+        send_to_target(&client, &target, &PingEvent::success("my-check", 10))
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_wait_budget_includes_exec_timeout() {
+        let webhook = MonitorTarget::Webhook {
+            url: "https://hook.example.com".to_string(),
+        };
+        let exec = MonitorTarget::Exec {
+            command: "true".to_string(),
+            timeout_ms: 30_000,
+            events: vec![],
+        };
+
+        assert_eq!(
+            wait_budget_ms(std::slice::from_ref(&webhook), 5000),
+            5000 + WAIT_SLACK_MS
+        );
+        assert_eq!(
+            wait_budget_ms(&[webhook, exec], 5000),
+            30_000 + WAIT_SLACK_MS
+        );
+    }
+
+    #[test]
+    fn test_exec_event_env() {
+        let event = PingEvent::fail("my-check", 2, 300, "").with_signal(Some(15));
+        let env = exec_event_env(&event);
+        let get = |key: &str| env.iter().find(|(k, _)| *k == key).map(|(_, v)| v.as_str());
+
+        assert_eq!(get("PAKYAS_EVENT_CHECK"), Some("my-check"));
+        assert_eq!(get("PAKYAS_EVENT_TYPE"), Some("fail"));
+        assert_eq!(get("PAKYAS_EVENT_EXIT_CODE"), Some("2"));
+        assert_eq!(get("PAKYAS_EVENT_DURATION_MS"), Some("300"));
+        assert_eq!(get("PAKYAS_EVENT_SIGNAL"), Some("15"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_send_exec_receives_stdin_and_env() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let out = temp_dir.path().join("event.json");
+        let command = format!(
+            "cat > {} && test \"$PAKYAS_EVENT_TYPE\" = fail",
+            out.display()
+        );
+
+        let event = PingEvent::fail("my-check", 1, 10, "boom");
+        send_exec(&command, 5000, &[], &event).await.unwrap();
+
+        let written = std::fs::read_to_string(&out).unwrap();
+        assert!(written.contains("\"check_identifier\":\"my-check\""));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_send_exec_events_filter_and_errors() {
+        // Filtered out: the failing command never runs
+        send_exec("exit 1", 5000, &[EventType::Fail], &PingEvent::start("c"))
+            .await
+            .unwrap();
+
+        let err = send_exec("echo oops >&2; exit 4", 5000, &[], &PingEvent::start("c"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("oops"));
+
+        let err = send_exec("sleep 5", 50, &[], &PingEvent::start("c"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("timed out"));
+    }
+
     #[test]
     fn test_dispatch_empty_monitors() {
         // Should return None with empty monitors
@@ -474,12 +1309,182 @@ mod tests {
         assert!(handle.is_none());
     }
 
+    #[tokio::test]
+    async fn test_dispatch_reports_outcomes() {
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::method("POST"))
+            .respond_with(wiremock::ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let monitors = vec![
+            MonitorTarget::Webhook {
+                url: format!("{}/ok", server.uri()),
+            },
+            MonitorTarget::Webhook {
+                url: "http://127.0.0.1:1/unreachable".to_string(),
+            },
+        ];
+        let handle =
+            dispatch_external_pings(monitors, PingEvent::success("test", 1), 2000, false).unwrap();
+        let outcomes = handle.await.unwrap();
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes[0].success);
+        assert!(outcomes[0].error.is_none());
+        assert!(!outcomes[1].success);
+        assert!(outcomes[1].error.is_some());
+    }
+
+    #[test]
+    fn test_backoff_delay_doubles_and_caps() {
+        assert_eq!(backoff_delay(1), Duration::from_millis(250));
+        assert_eq!(backoff_delay(2), Duration::from_millis(500));
+        assert_eq!(backoff_delay(3), Duration::from_millis(1000));
+        assert_eq!(
+            backoff_delay(10),
+            Duration::from_millis(RETRY_MAX_BACKOFF_MS)
+        );
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let webhook = MonitorTarget::Webhook {
+            url: "https://example.com".to_string(),
+        };
+        let status = |code: u16| -> anyhow::Error {
+            StatusError {
+                service: "webhook",
+                status: reqwest::StatusCode::from_u16(code).unwrap(),
+            }
+            .into()
+        };
+
+        assert!(is_retryable(&webhook, &status(503)));
+        assert!(is_retryable(&webhook, &status(429)));
+        assert!(!is_retryable(&webhook, &status(404)));
+        assert!(is_retryable(
+            &webhook,
+            &anyhow::anyhow!("connection refused")
+        ));
+
+        let exec = MonitorTarget::Exec {
+            command: "true".to_string(),
+            timeout_ms: 1000,
+            events: Vec::new(),
+        };
+        assert!(!is_retryable(&exec, &anyhow::anyhow!("exit status 1")));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_retries_server_errors() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let monitors = vec![MonitorTarget::Webhook { url: server.uri() }];
+        let handle =
+            dispatch_external_pings(monitors, PingEvent::success("test", 1), 3000, false).unwrap();
+        let outcomes = handle.await.unwrap();
+
+        assert!(outcomes[0].success);
+        assert_eq!(outcomes[0].attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_does_not_retry_client_errors() {
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::method("POST"))
+            .respond_with(wiremock::ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let monitors = vec![MonitorTarget::Webhook { url: server.uri() }];
+        let handle =
+            dispatch_external_pings(monitors, PingEvent::success("test", 1), 3000, false).unwrap();
+        let outcomes = handle.await.unwrap();
+
+        assert!(!outcomes[0].success);
+        assert_eq!(outcomes[0].attempts, 1);
+    }
+
     #[tokio::test]
     async fn test_await_empty_monitors() {
         let result = dispatch_await_any_success(vec![], PingEvent::start("test"), 1000).await;
         assert!(!result);
     }
 
+    #[tokio::test]
+    async fn test_await_ignores_syslog() {
+        // The UDP write succeeds although nothing confirms the event arrived
+        let receiver = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let syslog = MonitorTarget::Syslog {
+            transport: crate::external_monitors::SyslogTransport::Udp,
+            address: receiver.local_addr().unwrap().to_string(),
+            facility: crate::external_monitors::SyslogFacility::User,
+            app_name: "pakyas".to_string(),
+        };
+
+        let (success, outcomes) = dispatch_await_any_success_with_outcomes(
+            vec![syslog],
+            PingEvent::success("test", 1),
+            1000,
+        )
+        .await;
+        assert!(!success);
+        assert!(outcomes[0].success);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_await_ignores_exec_hooks() {
+        let hook = |events: Vec<EventType>| MonitorTarget::Exec {
+            command: "true".to_string(),
+            timeout_ms: 1000,
+            events,
+        };
+
+        // A hook exiting 0 doesn't confirm delivery
+        let (success, outcomes) = dispatch_await_any_success_with_outcomes(
+            vec![hook(vec![])],
+            PingEvent::success("test", 1),
+            1000,
+        )
+        .await;
+        assert!(!success);
+        assert!(outcomes[0].success);
+
+        // A hook limited to other events is skipped without an outcome
+        let (success, outcomes) = dispatch_await_any_success_with_outcomes(
+            vec![hook(vec![EventType::Start])],
+            PingEvent::success("test", 1),
+            1000,
+        )
+        .await;
+        assert!(!success);
+        assert!(outcomes.is_empty());
+        assert!(
+            dispatch_external_pings(
+                vec![hook(vec![EventType::Fail])],
+                PingEvent::start("test"),
+                1000,
+                false
+            )
+            .is_none()
+        );
+    }
+
     #[test]
     fn test_event_serialization() {
         let event = PingEvent::fail("my-check", 1, 1234, "error");
@@ -501,5 +1506,6 @@ mod tests {
         assert!(!json.contains("exit_code"));
         assert!(!json.contains("duration_ms"));
         assert!(!json.contains("output"));
+        assert!(!json.contains("signal"));
     }
 }
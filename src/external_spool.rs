@@ -0,0 +1,227 @@
+//! On-disk spool of external monitor events that could not be delivered.
+//!
+//! With `--external-spool`, events a target still rejected after retries are appended
+//! to `external_spool.jsonl` in the config directory. `pakyas external replay` resends
+//! them and keeps only the entries that fail again.
+//!
+//! Only the check key, target name, and masked destination are stored, never
+//! credentials: replay resolves targets again from external_monitors.toml, so events
+//! for inline targets (`--healthchecks-id`, `--webhook-url`, ...) cannot be replayed.
+//! Exec hooks are never spooled, and neither are start events: replayed hours later,
+//! a start would show a run in progress that has long finished.
+
+use crate::config::Config;
+use crate::error::CliError;
+use crate::external_ping::{EventType, PingEvent, TargetOutcome};
+use crate::lock::GlobalLock;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Entries older than this are dropped on replay (the event is no longer meaningful)
+const MAX_AGE_DAYS: i64 = 7;
+
+const SPOOL_FILE_NAME: &str = "external_spool.jsonl";
+
+/// One undelivered event for one target
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpoolEntry {
+    pub spooled_at: DateTime<Utc>,
+    pub target: String,
+    pub url: String,
+    pub event: PingEvent,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl SpoolEntry {
+    pub fn new(event: &PingEvent, outcome: &TargetOutcome) -> Self {
+        Self {
+            spooled_at: Utc::now(),
+            target: outcome.target.to_string(),
+            url: outcome.url.clone(),
+            event: event.clone(),
+            error: outcome.error.clone(),
+        }
+    }
+
+    /// Whether the entry is too old to be worth replaying
+    pub fn is_expired(&self) -> bool {
+        Utc::now() - self.spooled_at > Duration::days(MAX_AGE_DAYS)
+    }
+}
+
+/// Path to the spool file
+pub fn path() -> Result<PathBuf, CliError> {
+    Ok(Config::config_dir()?.join(SPOOL_FILE_NAME))
+}
+
+/// Entries for the failed, spoolable outcomes of one event
+pub fn entries_for(event: &PingEvent, outcomes: &[TargetOutcome]) -> Vec<SpoolEntry> {
+    if event.event_type == EventType::Start {
+        return Vec::new();
+    }
+    outcomes
+        .iter()
+        .filter(|o| !o.success && o.target != "exec")
+        .map(|o| SpoolEntry::new(event, o))
+        .collect()
+}
+
+/// Spool failed outcomes for one event to the default spool file
+pub fn spool(event: &PingEvent, outcomes: &[TargetOutcome]) -> Result<usize, CliError> {
+    let entries = entries_for(event, outcomes);
+    if entries.is_empty() {
+        return Ok(0);
+    }
+    let _lock = GlobalLock::acquire()?;
+    append_to_path(&path()?, &entries)?;
+    Ok(entries.len())
+}
+
+/// Spool failed outcomes, reporting errors as warnings (never fails the caller)
+pub fn spool_quietly(event: &PingEvent, outcomes: &[TargetOutcome], verbose: bool) {
+    match spool(event, outcomes) {
+        Ok(0) => {}
+        Ok(count) => {
+            if verbose {
+                eprintln!(
+                    "[verbose] Spooled {} undelivered external event(s) for '{}'",
+                    count, event.check_identifier
+                );
+            }
+        }
+        Err(e) => eprintln!("Warning: failed to spool external event: {}", e),
+    }
+}
+
+/// Append entries to a specific spool file
+pub fn append_to_path(path: &Path, entries: &[SpoolEntry]) -> Result<(), CliError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(CliError::ConfigWrite)?;
+    }
+
+    let mut buf = String::new();
+    for entry in entries {
+        buf.push_str(&serde_json::to_string(entry)?);
+        buf.push('\n');
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(CliError::ConfigWrite)?;
+    file.write_all(buf.as_bytes())
+        .map_err(CliError::ConfigWrite)?;
+    Ok(())
+}
+
+/// Load all entries from a spool file (malformed lines are skipped)
+pub fn load_from_path(path: &Path) -> Result<Vec<SpoolEntry>, CliError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path).map_err(CliError::ConfigRead)?;
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<SpoolEntry>(line).ok())
+        .collect())
+}
+
+/// Remove and return all entries from a spool file
+///
+/// Callers re-append whatever still fails, so the file only ever holds pending events.
+pub fn take_from_path(path: &Path) -> Result<Vec<SpoolEntry>, CliError> {
+    let entries = load_from_path(path)?;
+    if path.exists() {
+        std::fs::remove_file(path).map_err(CliError::ConfigWrite)?;
+    }
+    Ok(entries)
+}
+
+/// Remove and return all entries from the default spool file
+pub fn take() -> Result<Vec<SpoolEntry>, CliError> {
+    let _lock = GlobalLock::acquire()?;
+    take_from_path(&path()?)
+}
+
+/// Load all entries from the default spool file without removing them
+pub fn load() -> Result<Vec<SpoolEntry>, CliError> {
+    load_from_path(&path()?)
+}
+
+/// Put entries back into the default spool file
+pub fn restore(entries: &[SpoolEntry]) -> Result<(), CliError> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    let _lock = GlobalLock::acquire()?;
+    append_to_path(&path()?, entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn outcome(target: &'static str, success: bool) -> TargetOutcome {
+        TargetOutcome {
+            target,
+            url: format!("https://{}.example.com", target),
+            success,
+            timed_out: false,
+            attempts: 1,
+            latency_ms: 5,
+            error: (!success).then(|| "boom".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_entries_for_skips_successes_and_exec() {
+        let event = PingEvent::success("backup-db", 100);
+        let outcomes = vec![
+            outcome("cronitor", false),
+            outcome("webhook", true),
+            outcome("exec", false),
+        ];
+        let entries = entries_for(&event, &outcomes);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].target, "cronitor");
+        assert_eq!(entries[0].event.check_identifier, "backup-db");
+        assert_eq!(entries[0].error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn test_entries_for_skips_start_events() {
+        let outcomes = vec![outcome("cronitor", false)];
+        assert!(entries_for(&PingEvent::start("backup-db"), &outcomes).is_empty());
+    }
+
+    #[test]
+    fn test_append_take_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(SPOOL_FILE_NAME);
+
+        let event = PingEvent::fail("backup-db", 1, 100, "oops");
+        let entries = entries_for(&event, &[outcome("healthchecks.io", false)]);
+        append_to_path(&path, &entries).unwrap();
+        append_to_path(&path, &entries).unwrap();
+
+        let taken = take_from_path(&path).unwrap();
+        assert_eq!(taken.len(), 2);
+        assert_eq!(taken[0], entries[0]);
+        assert!(!path.exists());
+        assert!(take_from_path(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let event = PingEvent::start("a");
+        let mut entry = SpoolEntry::new(&event, &outcome("webhook", false));
+        assert!(!entry.is_expired());
+        entry.spooled_at = Utc::now() - Duration::days(MAX_AGE_DAYS + 1);
+        assert!(entry.is_expired());
+    }
+}
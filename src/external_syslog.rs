@@ -0,0 +1,374 @@
+//! Syslog (RFC 5424) and journald (native protocol) senders for external ping events.
+//!
+//! These targets don't confirm delivery beyond the socket write, so they are meant
+//! as a local audit trail rather than a migration source of truth.
+
+use crate::external_monitors::{SyslogFacility, SyslogTransport};
+use crate::external_ping::{EventType, PingEvent};
+use anyhow::Result;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+/// Timeout for connecting to and writing to a syslog/journald socket
+const SOCKET_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Structured data ID (uses the documentation enterprise number from RFC 5424)
+const SD_ID: &str = "pakyas@32473";
+
+/// Syslog severities (RFC 5424 section 6.2.1)
+const SEVERITY_ERROR: u8 = 3;
+const SEVERITY_INFO: u8 = 6;
+
+/// Map an event to a syslog severity - failures are logged at error priority
+fn severity(event: &PingEvent) -> u8 {
+    match event.event_type {
+        EventType::Fail => SEVERITY_ERROR,
+        EventType::Start | EventType::Success => SEVERITY_INFO,
+    }
+}
+
+fn event_name(event_type: EventType) -> &'static str {
+    match event_type {
+        EventType::Start => "start",
+        EventType::Success => "success",
+        EventType::Fail => "fail",
+    }
+}
+
+/// Human-readable summary used as the syslog MSG / journald MESSAGE
+fn summary(event: &PingEvent) -> String {
+    let check = &event.check_identifier;
+    match event.event_type {
+        EventType::Start => format!("check {} started", check),
+        EventType::Success => match event.duration_ms {
+            Some(ms) => format!("check {} succeeded after {}ms", check, ms),
+            None => format!("check {} succeeded", check),
+        },
+        EventType::Fail => {
+            let mut msg = format!("check {} failed", check);
+            if let Some(signal) = event.signal {
+                msg.push_str(&format!(" (signal {})", signal));
+            } else if let Some(code) = event.exit_code {
+                msg.push_str(&format!(" with exit code {}", code));
+            }
+            if let Some(ms) = event.duration_ms {
+                msg.push_str(&format!(" after {}ms", ms));
+            }
+            msg
+        }
+    }
+}
+
+/// Escape an SD-PARAM value (RFC 5424 section 6.3.3)
+fn escape_sd_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Replace empty or non-printable header fields with the NILVALUE
+fn header_field(value: &str, max_len: usize) -> String {
+    let cleaned: String = value
+        .chars()
+        .filter(|c| c.is_ascii_graphic())
+        .take(max_len)
+        .collect();
+    if cleaned.is_empty() {
+        "-".to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// Format an event as an RFC 5424 syslog message (without transport framing)
+pub(crate) fn format_rfc5424(
+    event: &PingEvent,
+    facility: SyslogFacility,
+    app_name: &str,
+    proc_id: u32,
+) -> String {
+    let pri = facility.code() as u16 * 8 + severity(event) as u16;
+    let timestamp = event
+        .timestamp
+        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    let host = header_field(event.host.as_deref().unwrap_or_default(), 255);
+
+    let mut params = vec![
+        ("check", event.check_identifier.clone()),
+        ("event", event_name(event.event_type).to_string()),
+    ];
+    if let Some(code) = event.exit_code {
+        params.push(("exit_code", code.to_string()));
+    }
+    if let Some(ms) = event.duration_ms {
+        params.push(("duration_ms", ms.to_string()));
+    }
+    if let Some(signal) = event.signal {
+        params.push(("signal", signal.to_string()));
+    }
+    let sd: String = params
+        .iter()
+        .map(|(k, v)| format!(" {}=\"{}\"", k, escape_sd_value(v)))
+        .collect();
+
+    format!(
+        "<{}>1 {} {} {} {} {} [{}{}] {}",
+        pri,
+        timestamp,
+        host,
+        header_field(app_name, 48),
+        proc_id,
+        event_name(event.event_type),
+        SD_ID,
+        sd,
+        summary(event)
+    )
+}
+
+/// Send an event as an RFC 5424 message over UDP, TCP (octet-counted), or a unix socket
+pub(crate) async fn send_syslog(
+    transport: SyslogTransport,
+    address: &str,
+    facility: SyslogFacility,
+    app_name: &str,
+    event: &PingEvent,
+) -> Result<()> {
+    let message = format_rfc5424(event, facility, app_name, std::process::id());
+
+    let send = async {
+        match transport {
+            SyslogTransport::Udp => {
+                let socket = tokio::net::UdpSocket::bind(bind_addr_for(address)).await?;
+                socket.send_to(message.as_bytes(), address).await?;
+            }
+            SyslogTransport::Tcp => {
+                // RFC 6587 octet-counting framing
+                let mut stream = tokio::net::TcpStream::connect(address).await?;
+                let framed = format!("{} {}", message.len(), message);
+                stream.write_all(framed.as_bytes()).await?;
+                stream.shutdown().await?;
+            }
+            SyslogTransport::Unix => send_unix_datagram(address, message.as_bytes()).await?,
+        }
+        Ok::<(), anyhow::Error>(())
+    };
+
+    tokio::time::timeout(SOCKET_TIMEOUT, send)
+        .await
+        .map_err(|_| anyhow::anyhow!("syslog send to {} timed out", address))?
+}
+
+/// Pick a local bind address matching the address family of the destination
+fn bind_addr_for(address: &str) -> &'static str {
+    if address.starts_with('[') {
+        "[::]:0"
+    } else {
+        "0.0.0.0:0"
+    }
+}
+
+/// Append a field in journald native protocol format
+///
+/// Values containing newlines use the binary form: name, newline, 64-bit LE length, value.
+fn push_journal_field(buf: &mut Vec<u8>, name: &str, value: &str) {
+    buf.extend_from_slice(name.as_bytes());
+    if value.contains('\n') {
+        buf.push(b'\n');
+        buf.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    } else {
+        buf.push(b'=');
+    }
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(b'\n');
+}
+
+/// Build a journald native protocol datagram for an event
+pub(crate) fn format_journald(event: &PingEvent, identifier: &str) -> Vec<u8> {
+    let mut buf = Vec::new();
+    push_journal_field(&mut buf, "MESSAGE", &summary(event));
+    push_journal_field(&mut buf, "PRIORITY", &severity(event).to_string());
+    push_journal_field(&mut buf, "SYSLOG_IDENTIFIER", identifier);
+    push_journal_field(&mut buf, "PAKYAS_CHECK", &event.check_identifier);
+    push_journal_field(&mut buf, "PAKYAS_EVENT", event_name(event.event_type));
+    if let Some(code) = event.exit_code {
+        push_journal_field(&mut buf, "PAKYAS_EXIT_CODE", &code.to_string());
+    }
+    if let Some(ms) = event.duration_ms {
+        push_journal_field(&mut buf, "PAKYAS_DURATION_MS", &ms.to_string());
+    }
+    if let Some(signal) = event.signal {
+        push_journal_field(&mut buf, "PAKYAS_SIGNAL", &signal.to_string());
+    }
+    if let Some(output) = &event.output {
+        push_journal_field(&mut buf, "PAKYAS_OUTPUT", output);
+    }
+    buf
+}
+
+/// Send an event to journald over its native protocol socket
+pub(crate) async fn send_journald(socket: &str, identifier: &str, event: &PingEvent) -> Result<()> {
+    let datagram = format_journald(event, identifier);
+    tokio::time::timeout(SOCKET_TIMEOUT, send_unix_datagram(socket, &datagram))
+        .await
+        .map_err(|_| anyhow::anyhow!("journald send to {} timed out", socket))?
+}
+
+#[cfg(unix)]
+async fn send_unix_datagram(path: &str, payload: &[u8]) -> Result<()> {
+    let socket = tokio::net::UnixDatagram::unbound()?;
+    socket.send_to(payload, path).await?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn send_unix_datagram(path: &str, _payload: &[u8]) -> Result<()> {
+    anyhow::bail!("unix sockets are not supported on this platform ({})", path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn fixed_event(mut event: PingEvent) -> PingEvent {
+        event.timestamp = chrono::Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        event.host = Some("web-1".to_string());
+        event
+    }
+
+    #[test]
+    fn test_format_rfc5424_success() {
+        let event = fixed_event(PingEvent::success("backup-db", 1500));
+        let msg = format_rfc5424(&event, SyslogFacility::User, "pakyas", 42);
+
+        assert_eq!(
+            msg,
+            "<14>1 2026-03-01T12:00:00.000Z web-1 pakyas 42 success \
+             [pakyas@32473 check=\"backup-db\" event=\"success\" exit_code=\"0\" duration_ms=\"1500\"] \
+             check backup-db succeeded after 1500ms"
+        );
+    }
+
+    #[test]
+    fn test_format_rfc5424_failure_is_error_priority() {
+        let event = fixed_event(PingEvent::fail("backup-db", 2, 300, "boom"));
+        let msg = format_rfc5424(&event, SyslogFacility::Local3, "pakyas", 1);
+
+        // local3 (19) * 8 + err (3)
+        assert!(msg.starts_with("<155>1 "));
+        assert!(msg.contains("exit_code=\"2\""));
+        assert!(msg.ends_with("check backup-db failed with exit code 2 after 300ms"));
+    }
+
+    #[test]
+    fn test_format_rfc5424_escapes_sd_values() {
+        let event = fixed_event(PingEvent::start("odd\"name]"));
+        let msg = format_rfc5424(&event, SyslogFacility::User, "", 1);
+
+        assert!(msg.contains(r#"check="odd\"name\]""#));
+        // Empty app name becomes NILVALUE
+        assert!(msg.contains(" web-1 - 1 start "));
+    }
+
+    #[test]
+    fn test_format_journald_fields() {
+        let event = fixed_event(PingEvent::fail("backup-db", 1, 250, "line1\nline2"));
+        let datagram = format_journald(&event, "pakyas");
+        let text = String::from_utf8_lossy(&datagram);
+
+        assert!(text.contains("PRIORITY=3\n"));
+        assert!(text.contains("SYSLOG_IDENTIFIER=pakyas\n"));
+        assert!(text.contains("PAKYAS_CHECK=backup-db\n"));
+        assert!(text.contains("PAKYAS_EXIT_CODE=1\n"));
+        assert!(text.contains("PAKYAS_DURATION_MS=250\n"));
+
+        // Multi-line output uses the binary length-prefixed form
+        let mut expected = b"PAKYAS_OUTPUT\n".to_vec();
+        expected.extend_from_slice(&11u64.to_le_bytes());
+        expected.extend_from_slice(b"line1\nline2\n");
+        assert!(datagram.ends_with(&expected));
+    }
+
+    #[test]
+    fn test_format_journald_success_is_info() {
+        let event = fixed_event(PingEvent::success("backup-db", 10));
+        let text = String::from_utf8(format_journald(&event, "pakyas")).unwrap();
+        assert!(text.contains("PRIORITY=6\n"));
+        assert!(!text.contains("PAKYAS_OUTPUT"));
+    }
+
+    #[tokio::test]
+    async fn test_send_syslog_udp() {
+        let receiver = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = receiver.local_addr().unwrap().to_string();
+
+        let event = PingEvent::fail("backup-db", 1, 10, "");
+        send_syslog(
+            SyslogTransport::Udp,
+            &addr,
+            SyslogFacility::User,
+            "pakyas",
+            &event,
+        )
+        .await
+        .unwrap();
+
+        let mut buf = [0u8; 2048];
+        let len = receiver.recv(&mut buf).await.unwrap();
+        let msg = String::from_utf8_lossy(&buf[..len]);
+        assert!(msg.starts_with("<11>1 "));
+        assert!(msg.contains("check=\"backup-db\""));
+    }
+
+    #[tokio::test]
+    async fn test_send_syslog_tcp_octet_counted() {
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+
+        let event = PingEvent::success("backup-db", 10);
+        let send = send_syslog(
+            SyslogTransport::Tcp,
+            &addr,
+            SyslogFacility::Daemon,
+            "pakyas",
+            &event,
+        );
+        let recv = async {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut data = String::new();
+            stream.read_to_string(&mut data).await.unwrap();
+            data
+        };
+        let (sent, data) = tokio::join!(send, recv);
+        sent.unwrap();
+
+        let (len, msg) = data.split_once(' ').unwrap();
+        assert_eq!(len.parse::<usize>().unwrap(), msg.len());
+        assert!(msg.starts_with("<30>1 "));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_send_journald_unix_socket() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("journal.sock");
+        let receiver = tokio::net::UnixDatagram::bind(&path).unwrap();
+
+        let event = PingEvent::success("backup-db", 10);
+        send_journald(path.to_str().unwrap(), "pakyas", &event)
+            .await
+            .unwrap();
+
+        let mut buf = [0u8; 2048];
+        let len = receiver.recv(&mut buf).await.unwrap();
+        let text = String::from_utf8_lossy(&buf[..len]);
+        assert!(text.contains("PAKYAS_CHECK=backup-db\n"));
+    }
+}
@@ -0,0 +1,85 @@
+//! Minimal shell-style glob matching for check slugs.
+//!
+//! Supports `*` (any run of characters, including none) and `?` (exactly one character).
+//! Everything else matches literally; there are no character classes.
+
+/// Returns true if `text` matches the glob `pattern` in full
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` seen and the text index it was tried against
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some('?') => {
+                p += 1;
+                t += 1;
+            }
+            Some(&c) if c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` swallow one more character and retry
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns true if the pattern contains glob metacharacters
+pub fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal() {
+        assert!(glob_match("backup-db", "backup-db"));
+        assert!(!glob_match("backup-db", "backup-db2"));
+        assert!(!glob_match("backup-db", "backup"));
+    }
+
+    #[test]
+    fn test_star() {
+        assert!(glob_match("backup-*", "backup-db"));
+        assert!(glob_match("backup-*", "backup-"));
+        assert!(glob_match("*-nightly", "etl-nightly"));
+        assert!(glob_match("*db*", "backup-db-eu"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("backup-*", "restore-db"));
+        assert!(glob_match("a*b*c", "a-x-b-y-b-c"));
+        assert!(!glob_match("a*b*c", "a-x-b-y"));
+    }
+
+    #[test]
+    fn test_question_mark() {
+        assert!(glob_match("job-?", "job-1"));
+        assert!(!glob_match("job-?", "job-12"));
+        assert!(!glob_match("job-?", "job-"));
+    }
+
+    #[test]
+    fn test_is_glob() {
+        assert!(is_glob("backup-*"));
+        assert!(is_glob("job-?"));
+        assert!(!is_glob("backup-db"));
+    }
+}
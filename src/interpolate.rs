@@ -0,0 +1,294 @@
+//! `${VAR}`, `${VAR:-default}` and `file:` interpolation for TOML config values.
+//!
+//! Used by external_monitors.toml so that API keys and tokenized URLs can come from the
+//! environment or mounted secret files instead of being committed in plain text.
+//!
+//! - `${VAR}` - value of VAR; error if unset
+//! - `${VAR:-default}` - value of VAR, or `default` if unset or empty
+//! - `$${` - literal `${`
+//! - `file:/run/secrets/x` - whole value read from the file (trailing newline trimmed)
+//!
+//! Substituted values are returned with their location so callers can decide which
+//! ones to mask in output.
+
+use std::path::Path;
+
+/// An unresolvable reference, with the dotted path of the offending value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterpolationError {
+    pub location: String,
+    pub message: String,
+}
+
+/// A value substituted from the environment or a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Substitution {
+    /// Dotted path of the config value it was substituted into
+    pub location: String,
+    pub value: String,
+    /// Read from a `file:` reference rather than the environment
+    pub from_file: bool,
+}
+
+/// Interpolate every string in a TOML tree in place
+///
+/// `lookup` resolves environment variables (injected for testing). Strings whose
+/// location `skip` accepts are left as they are. Returns the substituted values.
+pub fn interpolate_toml(
+    value: &mut toml::Value,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    skip: &dyn Fn(&str) -> bool,
+) -> Result<Vec<Substitution>, InterpolationError> {
+    let mut substitutions = Vec::new();
+    walk(value, "", lookup, skip, &mut substitutions)?;
+    Ok(substitutions)
+}
+
+fn walk(
+    value: &mut toml::Value,
+    location: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    skip: &dyn Fn(&str) -> bool,
+    substitutions: &mut Vec<Substitution>,
+) -> Result<(), InterpolationError> {
+    match value {
+        toml::Value::String(s) => {
+            if skip(location) {
+                return Ok(());
+            }
+            let from_file = s.starts_with("file:");
+            let mut values = Vec::new();
+            *s = interpolate_str(s, lookup, &mut values).map_err(|message| InterpolationError {
+                location: location.to_string(),
+                message,
+            })?;
+            substitutions.extend(values.into_iter().map(|value| Substitution {
+                location: location.to_string(),
+                value,
+                from_file,
+            }));
+        }
+        toml::Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                let child = format!("{}[{}]", location, i);
+                walk(item, &child, lookup, skip, substitutions)?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (key, item) in table.iter_mut() {
+                let child = if location.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", location, key)
+                };
+                walk(item, &child, lookup, skip, substitutions)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Interpolate a single string value
+fn interpolate_str(
+    input: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    secrets: &mut Vec<String>,
+) -> Result<String, String> {
+    if let Some(path) = input.strip_prefix("file:") {
+        let contents = read_secret_file(Path::new(path))?;
+        secrets.push(contents.clone());
+        return Ok(contents);
+    }
+
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+
+        if let Some(after) = tail.strip_prefix("$${") {
+            out.push_str("${");
+            rest = after;
+        } else if let Some(after) = tail.strip_prefix("${") {
+            let end = after
+                .find('}')
+                .ok_or_else(|| format!("unterminated '${{' in '{}'", input))?;
+            let expr = &after[..end];
+            let (name, default) = match expr.split_once(":-") {
+                Some((name, default)) => (name, Some(default)),
+                None => (expr, None),
+            };
+
+            if !is_valid_var_name(name) {
+                return Err(format!("invalid variable name '{}' in '{}'", name, input));
+            }
+
+            match (lookup(name), default) {
+                (Some(v), Some(default)) if v.is_empty() => out.push_str(default),
+                (Some(v), _) => {
+                    out.push_str(&v);
+                    secrets.push(v);
+                }
+                (None, Some(default)) => out.push_str(default),
+                (None, None) => {
+                    return Err(format!(
+                        "environment variable {} is not set (use ${{{}:-default}} for a fallback)",
+                        name, name
+                    ));
+                }
+            }
+            rest = &after[end + 1..];
+        } else {
+            out.push('$');
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+
+    Ok(out)
+}
+
+fn is_valid_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Read a secret file, trimming the trailing newline most secret mounts include
+fn read_secret_file(path: &Path) -> Result<String, String> {
+    if path.as_os_str().is_empty() {
+        return Err("empty path after 'file:'".to_string());
+    }
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read secret file {}: {}", path.display(), e))?;
+    Ok(contents.trim_end_matches(['\r', '\n']).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "API_KEY" => Some("sk_live_123".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    fn resolve(input: &str) -> Result<(String, Vec<String>), String> {
+        let mut secrets = Vec::new();
+        interpolate_str(input, &env, &mut secrets).map(|s| (s, secrets))
+    }
+
+    #[test]
+    fn test_plain_string_unchanged() {
+        let (out, secrets) = resolve("https://hc-ping.com").unwrap();
+        assert_eq!(out, "https://hc-ping.com");
+        assert!(secrets.is_empty());
+    }
+
+    #[test]
+    fn test_env_reference() {
+        let (out, secrets) = resolve("https://example.com/${API_KEY}/ping").unwrap();
+        assert_eq!(out, "https://example.com/sk_live_123/ping");
+        assert_eq!(secrets, vec!["sk_live_123"]);
+    }
+
+    #[test]
+    fn test_default_used_when_unset_or_empty() {
+        assert_eq!(resolve("${MISSING:-fallback}").unwrap().0, "fallback");
+        assert_eq!(resolve("${EMPTY:-fallback}").unwrap().0, "fallback");
+        // Defaults come from the file itself, so they are not secrets
+        assert!(resolve("${MISSING:-fallback}").unwrap().1.is_empty());
+    }
+
+    #[test]
+    fn test_unset_without_default_errors() {
+        let err = resolve("${MISSING}").unwrap_err();
+        assert!(err.contains("MISSING is not set"));
+    }
+
+    #[test]
+    fn test_escapes_and_bare_dollars() {
+        assert_eq!(resolve("$${API_KEY}").unwrap().0, "${API_KEY}");
+        assert_eq!(resolve("cost $5").unwrap().0, "cost $5");
+    }
+
+    #[test]
+    fn test_malformed_references() {
+        assert!(resolve("${API_KEY").unwrap_err().contains("unterminated"));
+        assert!(
+            resolve("${1BAD}")
+                .unwrap_err()
+                .contains("invalid variable name")
+        );
+    }
+
+    #[test]
+    fn test_file_reference() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("token");
+        std::fs::write(&path, "s3cret\n").unwrap();
+
+        let (out, secrets) = resolve(&format!("file:{}", path.display())).unwrap();
+        assert_eq!(out, "s3cret");
+        assert_eq!(secrets, vec!["s3cret"]);
+
+        let err = resolve("file:/nonexistent/pakyas-secret").unwrap_err();
+        assert!(err.contains("cannot read secret file"));
+    }
+
+    #[test]
+    fn test_interpolate_toml_reports_location() {
+        let mut value: toml::Value = toml::from_str(
+            r#"
+[targets.cronitor]
+api_key = "${API_KEY}"
+
+[targets.exec]
+command = "notify"
+events = ["${MISSING}"]
+"#,
+        )
+        .unwrap();
+
+        let err = interpolate_toml(&mut value, &env, &|_| false).unwrap_err();
+        assert_eq!(err.location, "targets.exec.events[0]");
+
+        let mut value: toml::Value =
+            toml::from_str("[targets.cronitor]\napi_key = \"${API_KEY}\"\n").unwrap();
+        let substitutions = interpolate_toml(&mut value, &env, &|_| false).unwrap();
+        assert_eq!(
+            substitutions,
+            vec![Substitution {
+                location: "targets.cronitor.api_key".to_string(),
+                value: "sk_live_123".to_string(),
+                from_file: false,
+            }]
+        );
+        assert_eq!(
+            value["targets"]["cronitor"]["api_key"].as_str(),
+            Some("sk_live_123")
+        );
+    }
+
+    #[test]
+    fn test_interpolate_toml_skips_locations() {
+        let mut value: toml::Value =
+            toml::from_str("[targets.exec]\ncommand = \"notify ${PAKYAS_EVENT_TYPE}\"\n").unwrap();
+        let skip = |location: &str| location == "targets.exec.command";
+        assert!(
+            interpolate_toml(&mut value, &env, &skip)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            value["targets"]["exec"]["command"].as_str(),
+            Some("notify ${PAKYAS_EVENT_TYPE}")
+        );
+    }
+}
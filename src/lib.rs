@@ -11,8 +11,13 @@ pub mod credentials;
 pub mod cron;
 pub mod error;
 pub mod exit_codes;
+pub mod external_log;
 pub mod external_monitors;
 pub mod external_ping;
+pub mod external_spool;
+pub mod external_syslog;
+pub mod glob;
+pub mod interpolate;
 pub mod lock;
 pub mod output;
 pub mod ua;
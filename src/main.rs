@@ -170,10 +170,18 @@ async fn execute_command(cli: &Cli, ctx: config::Context) -> Result<ExitCode> {
             commands::check::handle(&ctx, command.clone(), verbose).await?;
             Ok(ExitCode::SUCCESS)
         }
-        Commands::Ping(args) => {
-            commands::ping::execute(&ctx, args.clone(), verbose).await?;
+        Commands::Maintenance(command) => {
+            commands::check::handle_maintenance(&ctx, command.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Alert(command) => {
+            commands::alert::handle(&ctx, command.clone(), verbose).await?;
             Ok(ExitCode::SUCCESS)
         }
+        Commands::Ping(args) => {
+            // Ping only fails the exit code for external monitors with --external-strict
+            commands::ping::execute(&ctx, args.clone(), verbose).await
+        }
         Commands::Monitor(args) => {
             // Monitor returns the exit code of the wrapped command
             commands::monitor::execute(&ctx, args.clone(), verbose).await
@@ -186,6 +194,50 @@ async fn execute_command(cli: &Cli, ctx: config::Context) -> Result<ExitCode> {
             commands::completion::generate_completions(*shell)?;
             Ok(ExitCode::SUCCESS)
         }
+        Commands::External(command) => {
+            commands::external::handle(&ctx, command.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Import(command) => {
+            commands::import::handle(&ctx, command.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Crontab(command) => {
+            commands::crontab::handle(&ctx, command.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Systemd(command) => {
+            commands::systemd::handle(&ctx, command.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::K8s(command) => {
+            commands::k8s::handle(&ctx, command.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Drift(args) => {
+            commands::drift::drift(&ctx, args.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Report(args) => {
+            commands::check::report(&ctx, args.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Tail(args) => {
+            commands::check::tail_many(&ctx, args.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Plan(args) => {
+            commands::manifest::plan(&ctx, args.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Apply(args) => {
+            commands::manifest::apply(&ctx, args.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Commands::Export(args) => {
+            commands::manifest::export(&ctx, args.clone(), verbose).await?;
+            Ok(ExitCode::SUCCESS)
+        }
         Commands::Update(args) => {
             commands::update::execute(&ctx, args.clone(), verbose).await?;
             Ok(ExitCode::SUCCESS)
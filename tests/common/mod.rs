@@ -12,6 +12,7 @@ pub fn create_test_client(base_url: &str, api_key: &str) -> ApiClient {
 }
 
 /// Create a test client without authentication (for testing unauthenticated endpoints)
+#[allow(dead_code)]
 pub fn create_test_client_no_auth(base_url: &str) -> ApiClient {
     ApiClient::with_base_url(base_url.to_string(), None).expect("Failed to create test client")
 }